@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+/// Preset bot strengths, see [`BotSettings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+/// How quickly a bot reacts and how far ahead it looks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BotSettings {
+    /// How long the bot waits after making a decision before looking at the board again.
+    pub reaction_delay: Duration,
+    /// How many moves deep the search goes, counting both sides. Always at least 1.
+    pub depth: usize,
+    /// How far ahead each move after the first is looked for. Only pieces that become ready
+    /// within this time can take part.
+    pub horizon: Duration,
+}
+
+impl Difficulty {
+    #[must_use]
+    pub fn settings(self) -> BotSettings {
+        let (reaction_delay, depth) = match self {
+            Difficulty::Easy => (Duration::from_millis(1500), 1),
+            Difficulty::Medium => (Duration::from_millis(600), 2),
+            Difficulty::Hard => (Duration::from_millis(200), 3),
+        };
+        BotSettings {
+            reaction_delay,
+            depth,
+            horizon: Duration::from_millis(300),
+        }
+    }
+}
+
+impl Default for BotSettings {
+    fn default() -> Self {
+        Difficulty::default().settings()
+    }
+}
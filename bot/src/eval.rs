@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use state::{
+    board::Board,
+    piece::{Color, Piece},
+};
+
+/// The score of a won position, far beyond anything material can add up to.
+pub const WIN: i32 = 1_000_000;
+
+/// A piece is worth this much more when it is ready to move than when it has just moved.
+pub const READY_BONUS: i32 = 20;
+
+/// Pieces further than this from being ready get no readiness bonus.
+pub const READINESS_WINDOW: Duration = Duration::from_secs(1);
+
+/**
+How good `board` is for `color`: material, placement and the difference in king HP from the
+board's static evaluation, plus how soon each side's pieces are ready to move, as readiness is
+what lets a side answer a threat in real time.
+
+Mobility is left out, generating moves for every leaf of the search would make it too slow.
+*/
+#[must_use]
+pub fn evaluate(board: &Board, color: Color) -> i32 {
+    if let Some(winner) = board.winner() {
+        return if winner == color { WIN } else { -WIN };
+    }
+
+    let evaluation = board.static_evaluation();
+    let mut score = evaluation.material + evaluation.placement + evaluation.king_hp;
+    for piece in board.pieces.iter().flatten().flatten() {
+        match piece.get_color() {
+            Color::White => score += readiness(piece),
+            Color::Black => score -= readiness(piece),
+        }
+    }
+
+    match color {
+        Color::White => score,
+        Color::Black => -score,
+    }
+}
+
+/// [`READY_BONUS`] for a ready piece, falling off to nothing over [`READINESS_WINDOW`].
+fn readiness(piece: &Piece) -> i32 {
+    let ready = READINESS_WINDOW.saturating_sub(piece.ready_in());
+    let millis = |duration: Duration| i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    READY_BONUS * millis(ready) / millis(READINESS_WINDOW)
+}
@@ -0,0 +1,69 @@
+pub mod difficulty;
+pub mod eval;
+pub mod search;
+mod tests;
+
+use std::sync::Arc;
+
+use chess_client::ChessClient;
+use difficulty::BotSettings;
+use state::{clock::Clock, cooldowns::BOARD_TICK_RATE, piece::Color};
+
+/// Plays one side of a game through a [`ChessClient`].
+#[derive(Debug)]
+pub struct Bot {
+    client: Arc<ChessClient>,
+    color: Color,
+    settings: BotSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl Bot {
+    /// A bot keeping time with the client's clock.
+    #[must_use]
+    pub fn new(client: Arc<ChessClient>, color: Color, settings: BotSettings) -> Self {
+        let clock = client.clock();
+        Self::with_clock(client, color, settings, clock)
+    }
+
+    /// A bot that waits between decisions on `clock`, which should be the one the client
+    /// advances its board by.
+    #[must_use]
+    pub fn with_clock(
+        client: Arc<ChessClient>,
+        color: Color,
+        settings: BotSettings,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            client,
+            color,
+            settings,
+            clock,
+        }
+    }
+
+    /**
+    Ticks the client's board and, every [`BotSettings::reaction_delay`], makes the best move
+    it can find. Returns the winner once the game is over.
+    */
+    #[must_use]
+    pub fn run(&self) -> Color {
+        let mut next_decision = self.clock.now() + self.settings.reaction_delay;
+        loop {
+            self.client.tick();
+            let board = self.client.board();
+            if let Some(winner) = board.winner() {
+                return winner;
+            }
+
+            if self.clock.now() >= next_decision {
+                if let Some(mv) = search::best_move(&board, self.color, &self.settings) {
+                    let _ = self.client.make_move(mv);
+                }
+                next_decision = self.clock.now() + self.settings.reaction_delay;
+            }
+            self.clock.sleep(BOARD_TICK_RATE);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{sync::Arc, thread};
+
+use anyhow::bail;
+use bot::{difficulty::Difficulty, Bot};
+use chess_client::{ChessClient, ServerEvent};
+use clap::{Parser, ValueEnum};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Level {
+    Easy,
+    Medium,
+    Hard,
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[arg(short, long, default_value_t = String::from("8001"))]
+    port: String,
+
+    #[arg(long)]
+    host: String,
+
+    #[arg(short, long)]
+    game_id: String,
+
+    #[arg(short, long, value_enum, default_value_t = Level::Medium)]
+    difficulty: Level,
+
+    /// Overrides the difficulty's reaction delay, in milliseconds.
+    #[arg(long)]
+    reaction_ms: Option<u64>,
+
+    /// Overrides the difficulty's search depth.
+    #[arg(long)]
+    depth: Option<usize>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let difficulty = match args.difficulty {
+        Level::Easy => Difficulty::Easy,
+        Level::Medium => Difficulty::Medium,
+        Level::Hard => Difficulty::Hard,
+    };
+    let mut settings = difficulty.settings();
+    if let Some(reaction_ms) = args.reaction_ms {
+        settings.reaction_delay = std::time::Duration::from_millis(reaction_ms);
+    }
+    if let Some(depth) = args.depth {
+        settings.depth = depth.max(1);
+    }
+    let client = Arc::new(ChessClient::new(&args.port, &args.host)?);
+    let events = client.listen();
+    client.join_game(&args.game_id);
+
+    let color = loop {
+        match events.recv()? {
+            ServerEvent::Seated(Some(color)) => break color,
+            ServerEvent::Seated(None) => bail!("game {} has no free seat", args.game_id),
+            _ => {}
+        }
+    };
+    println!("Playing {color:?} in game {}", args.game_id);
+
+    thread::spawn(move || {
+        for event in events {
+            if let ServerEvent::MoveRejected(rejection) = event {
+                eprintln!("{rejection}");
+            }
+        }
+    });
+
+    let winner = Bot::new(client, color, settings).run();
+    println!("{winner:?} won");
+
+    Ok(())
+}
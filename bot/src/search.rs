@@ -0,0 +1,97 @@
+use std::cmp::Reverse;
+
+use state::{
+    board::Board,
+    eval::piece_value,
+    movegen::MoveGen,
+    perft::uci,
+    piece::{Color, Move},
+};
+
+use crate::{
+    difficulty::BotSettings,
+    eval::{evaluate, WIN},
+};
+
+/// Beyond any score [`evaluate`] returns, so it can be negated safely.
+const INFINITY: i32 = WIN + 1;
+
+/**
+The best move for `color` among the moves its ready pieces can make right now, or `None` if
+no piece is ready.
+
+Every move after the first is made after [`BotSettings::horizon`] has passed, by the pieces
+that are ready by then. Doing nothing is always one of the answers, as in real time neither
+side has to move.
+*/
+#[must_use]
+pub fn best_move(board: &Board, color: Color, settings: &BotSettings) -> Option<Move> {
+    let depth = settings.depth.saturating_sub(1);
+    let mut best = None;
+    let mut alpha = -INFINITY;
+    for mv in ordered_moves(board, color) {
+        let after = after(board, mv);
+        let score = -search(&after, color.opposite(), depth, settings, -INFINITY, -alpha);
+        if best.is_none() || score > alpha {
+            alpha = score;
+            best = Some(mv);
+        }
+    }
+    best
+}
+
+/// Negamax with alpha-beta pruning, scored for `mover`.
+fn search(
+    board: &Board,
+    mover: Color,
+    depth: usize,
+    settings: &BotSettings,
+    mut alpha: i32,
+    beta: i32,
+) -> i32 {
+    if depth == 0 || board.winner().is_some() {
+        return evaluate(board, mover);
+    }
+    let mut board = board.clone();
+    board.decay_cooldowns(settings.horizon);
+
+    let mut best = -search(&board, mover.opposite(), depth - 1, settings, -beta, -alpha);
+    alpha = alpha.max(best);
+    for mv in ordered_moves(&board, mover) {
+        if alpha >= beta {
+            break;
+        }
+        let after = after(&board, mv);
+        let score = -search(&after, mover.opposite(), depth - 1, settings, -beta, -alpha);
+        best = best.max(score);
+        alpha = alpha.max(score);
+    }
+    best
+}
+
+/// The moves `color` can make now, biggest captures first so that pruning kicks in early.
+/// Moves are otherwise sorted by notation, so that the same board always gets the same answer.
+fn ordered_moves(board: &Board, color: Color) -> Vec<Move> {
+    let mut moves = MoveGen::new(board).get_possible_moves_for_color(color);
+    moves.sort_by_cached_key(|mv| (Reverse(capture_value(board, mv)), uci(mv)));
+    moves
+}
+
+fn capture_value(board: &Board, mv: &Move) -> i32 {
+    let (Move::Piece(_, to) | Move::Promotion(_, to, _)) = mv else {
+        return 0;
+    };
+    board.get_piece_at(to).map_or(0, |piece| {
+        if piece.is_king() {
+            WIN
+        } else {
+            piece_value(&piece)
+        }
+    })
+}
+
+fn after(board: &Board, mv: Move) -> Board {
+    let mut board = board.clone();
+    board.process_move(mv);
+    board
+}
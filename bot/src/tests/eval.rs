@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod scoring {
+    use std::time::Duration;
+
+    use state::{board::Board, piece::Color, square::*};
+
+    use crate::eval::{evaluate, READY_BONUS, WIN};
+
+    fn ready(fen: &str) -> Board {
+        let mut board = Board::from(fen);
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn standard_position_is_even() {
+        let board = Board::standard();
+        assert_eq!(evaluate(&board, Color::White), 0);
+        assert_eq!(evaluate(&board, Color::Black), 0);
+    }
+
+    #[test]
+    fn material_counts_for_its_owner() {
+        let board = ready("4k3/8/8/8/8/8/8/3QK3 w - - 0 1");
+        assert!(evaluate(&board, Color::White) > 800);
+        assert_eq!(
+            evaluate(&board, Color::White),
+            -evaluate(&board, Color::Black)
+        );
+    }
+
+    #[test]
+    fn ready_pieces_are_worth_more() {
+        let board = ready("4k3/8/8/8/8/8/8/3RK3 w - - 0 1");
+        let mut moved = board.clone();
+        let mut rook = moved.get_piece_at(&D1).expect("rook on d1");
+        rook.restart_cooldown(&moved.rules.cooldowns);
+        moved.set_piece_at(Some(rook), D1);
+
+        assert_eq!(
+            evaluate(&board, Color::White) - evaluate(&moved, Color::White),
+            READY_BONUS
+        );
+    }
+
+    #[test]
+    fn lost_hp_counts_against_its_side() {
+        let board = ready("4k3/8/8/8/8/8/8/4K3 w - - 0 1 5000 5000");
+        let hurt = ready("4k3/8/8/8/8/8/8/4K3 w - - 0 1 4000 5000");
+        assert!(evaluate(&hurt, Color::White) < evaluate(&board, Color::White));
+        assert!(evaluate(&hurt, Color::Black) > evaluate(&board, Color::Black));
+    }
+
+    #[test]
+    fn won_games_outweigh_everything() {
+        let board = ready("8/8/8/8/8/8/8/QQQQK3 w - - 0 1");
+        assert_eq!(evaluate(&board, Color::White), WIN);
+        assert_eq!(evaluate(&board, Color::Black), -WIN);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod choices {
+    use std::time::Duration;
+
+    use state::{
+        board::Board,
+        piece::{Color, Move},
+        square::*,
+    };
+
+    use crate::{difficulty::BotSettings, search::best_move};
+
+    const DEFENDED_PAWN: &str = "4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1";
+
+    fn ready(fen: &str) -> Board {
+        let mut board = Board::from(fen);
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    fn depth(depth: usize) -> BotSettings {
+        BotSettings {
+            depth,
+            ..BotSettings::default()
+        }
+    }
+
+    #[test]
+    fn nothing_to_do_while_every_piece_cools_down() {
+        assert_eq!(best_move(&Board::standard(), Color::White, &depth(2)), None);
+    }
+
+    #[test]
+    fn takes_a_hanging_queen() {
+        let board = ready("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1");
+        assert_eq!(
+            best_move(&board, Color::White, &depth(1)),
+            Some(Move::Piece(D2, D5))
+        );
+    }
+
+    #[test]
+    fn captures_the_king_when_it_can() {
+        let board = ready("3k4/8/8/8/8/8/8/3RK3 w - - 0 1");
+        assert_eq!(
+            best_move(&board, Color::White, &depth(2)),
+            Some(Move::Piece(D1, D8))
+        );
+    }
+
+    #[test]
+    fn sees_the_recapture_with_more_depth() {
+        let board = ready(DEFENDED_PAWN);
+        let capture = Move::Piece(D1, D5);
+        assert_eq!(best_move(&board, Color::White, &depth(1)), Some(capture));
+        assert_ne!(best_move(&board, Color::White, &depth(2)), Some(capture));
+    }
+
+    #[test]
+    fn defenders_on_cooldown_cannot_recapture() {
+        let mut board = ready(DEFENDED_PAWN);
+        let mut defender = board.get_piece_at(&C6).expect("pawn on c6");
+        defender.set_cooldown(Duration::from_secs(5));
+        board.set_piece_at(Some(defender), C6);
+
+        assert_eq!(
+            best_move(&board, Color::White, &depth(2)),
+            Some(Move::Piece(D1, D5))
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use network::command::StateHash;
+use state::board::Board;
+
+/// The client's confirmed board no longer matched the server's, with both boards as FEN.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Desync {
+    pub local_fen: String,
+    pub server_fen: String,
+}
+
+/**
+Compares the confirmed board with the state hashes the server sends along with moves.
+
+On the first mismatch the local board is remembered until the full snapshot asked for with a
+Resync arrives, so that both can be reported together. Every mismatch asks for a resync again,
+in case the request or the snapshot was lost.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct DesyncDetector {
+    local_fen: Option<String>,
+}
+
+impl DesyncDetector {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `board` differs from the server's board hashing to `hash`, in which case a
+    /// resync should be requested.
+    pub fn check(&mut self, board: &Board, hash: StateHash) -> bool {
+        if board.sync_hash() == hash {
+            return false;
+        }
+        if self.local_fen.is_none() {
+            self.local_fen = Some(board.fen());
+        }
+        true
+    }
+
+    #[must_use]
+    pub fn is_resyncing(&self) -> bool {
+        self.local_fen.is_some()
+    }
+
+    /// Called with every full snapshot. Returns the desync it resolves, if one was detected.
+    pub fn resolve(&mut self, server: &Board) -> Option<Desync> {
+        let local_fen = self.local_fen.take()?;
+        Some(Desync {
+            local_fen,
+            server_fen: server.fen(),
+        })
+    }
+}
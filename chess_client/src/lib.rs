@@ -1,15 +1,66 @@
+pub mod desync;
+pub mod prediction;
+pub mod premoves;
+mod tests;
+
 use std::{
     net::UdpSocket,
-    sync::mpsc::{self, Receiver},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
     thread,
+    time::Duration,
+};
+
+use desync::{Desync, DesyncDetector};
+use network::{
+    cards::{HandUpdate, PlayCard},
+    clock_sync::{ClockSync, Ping},
+    delta::PositionHistory,
+    game_command::GameCmd,
+    marshal::Marshal,
+    message::ServerMessage,
+    rejection::{CardRejected, MoveRejected},
+    unmarshal::Unmarshal,
 };
+use prediction::Prediction;
+use premoves::Premoves;
+use state::{
+    board::Board,
+    cards::{deck::HeldCard, CardId, CardPlay, CardTarget},
+    clock::{Clock, RealClock},
+    piece::{Color, Move},
+};
+
+const MAX_MESSAGE_SIZE: usize = 1024;
 
-use network::{command::Command, game_command::GameCmd, marshal::Marshal, unmarshal::Unmarshal};
-use state::piece::Move;
+/// A message received from the server, after it has been reconciled with the predicted board.
+#[derive(Debug, Clone)]
+pub enum ServerEvent {
+    Move(Move),
+    MoveRejected(MoveRejected),
+    Snapshot,
+    CardDrawn(HeldCard),
+    Hand(HandUpdate),
+    CardPlayed(CardPlay),
+    CardRejected(CardRejected),
+    /// The board had drifted from the server's and has been replaced by a full snapshot.
+    Desync(Desync),
+    /// The color we were seated as when joining, `None` if we are spectating.
+    Seated(Option<Color>),
+}
 
 #[derive(Debug)]
 pub struct ChessClient {
     connection: UdpSocket,
+    prediction: Arc<Mutex<Prediction>>,
+    clock: Arc<Mutex<ClockSync>>,
+    hand: Arc<Mutex<Vec<HeldCard>>>,
+    premoves: Mutex<Premoves>,
+    local_clock: Arc<dyn Clock>,
+    /// The local time the board was last advanced to.
+    advanced_to: Mutex<Duration>,
 }
 
 impl ChessClient {
@@ -17,37 +68,269 @@ impl ChessClient {
     ///
     /// This function will return an error if you cannot bind to the host UDP socket.
     pub fn new(port: &String, host: &String) -> std::io::Result<Self> {
+        Self::with_clock(port, host, Arc::new(RealClock::new()))
+    }
+
+    /// A client that measures time with `clock`, which is what [`ChessClient::tick`] advances the
+    /// board by.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if you cannot bind to the host UDP socket.
+    pub fn with_clock(
+        port: &String,
+        host: &String,
+        clock: Arc<dyn Clock>,
+    ) -> std::io::Result<Self> {
         let connection = UdpSocket::bind(format!("127.0.0.1:{port}"))?;
         connection.connect(host)?;
-        Ok(Self { connection })
+        Ok(Self {
+            connection,
+            prediction: Arc::new(Mutex::new(Prediction::new(Board::standard()))),
+            clock: Arc::new(Mutex::new(ClockSync::new())),
+            hand: Arc::new(Mutex::new(vec![])),
+            premoves: Mutex::new(Premoves::new()),
+            advanced_to: Mutex::new(clock.now()),
+            local_clock: clock,
+        })
+    }
+
+    /// The clock the client measures time with.
+    #[must_use]
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        Arc::clone(&self.local_clock)
     }
 
     /// # Panics
     ///
     /// This function will panic if a listening connnection cannot be reliably be established
     #[must_use]
-    pub fn listen(&self) -> Receiver<Move> {
+    pub fn listen(&self) -> Receiver<ServerEvent> {
         let conn = self.connection.try_clone().expect("cannot clone socket");
+        let prediction = Arc::clone(&self.prediction);
+        let clock = Arc::clone(&self.clock);
+        let hand = Arc::clone(&self.hand);
+        let local_clock = Arc::clone(&self.local_clock);
         let (tx, rx) = mpsc::channel();
         thread::spawn(move || -> std::io::Result<()> {
+            let mut positions = PositionHistory::new();
+            let mut desync = DesyncDetector::new();
             loop {
-                let mut msg = [0; 4];
-                conn.recv_from(&mut msg)?;
-                let cmd = Unmarshal::command(msg);
-                let _ = tx.send(cmd);
+                let mut buf = [0; MAX_MESSAGE_SIZE];
+                let len = conn.recv(&mut buf)?;
+                let received_at = local_clock.now();
+                let Some(message) = Unmarshal::server_message(&buf[..len]) else {
+                    continue;
+                };
+                match &message {
+                    ServerMessage::Pong(pong) => {
+                        if let Ok(mut clock) = clock.lock() {
+                            clock.add_sample(pong, received_at);
+                        }
+                        continue;
+                    }
+                    ServerMessage::DrawCard(card) => {
+                        if let Ok(mut hand) = hand.lock() {
+                            hand.push(*card);
+                        }
+                        let _ = tx.send(ServerEvent::CardDrawn(*card));
+                        continue;
+                    }
+                    ServerMessage::Hand(update) => {
+                        if let Ok(mut hand) = hand.lock() {
+                            hand.clone_from(&update.hand);
+                        }
+                        let _ = tx.send(ServerEvent::Hand(update.clone()));
+                        continue;
+                    }
+                    ServerMessage::Seat(seat) => {
+                        let _ = tx.send(ServerEvent::Seated(*seat));
+                        continue;
+                    }
+                    ServerMessage::CardRejected(rejection) => {
+                        let _ = tx.send(ServerEvent::CardRejected(*rejection));
+                        continue;
+                    }
+                    _ => {}
+                }
+                let Ok(mut prediction) = prediction.lock() else {
+                    continue;
+                };
+                let event = match message {
+                    ServerMessage::Move(mv, sequence, hash) => {
+                        prediction.confirm(mv, sequence);
+                        if hash.is_some_and(|hash| desync.check(prediction.confirmed(), hash)) {
+                            let _ = conn.send(&Marshal::resync());
+                        }
+                        ServerEvent::Move(mv)
+                    }
+                    ServerMessage::MoveRejected(rejection, sequence) => {
+                        if let Some(sequence) = sequence {
+                            prediction.reject(sequence);
+                        }
+                        ServerEvent::MoveRejected(rejection)
+                    }
+                    ServerMessage::Snapshot(board, position) => {
+                        if let Some(resolved) = desync.resolve(&board) {
+                            let _ = tx.send(ServerEvent::Desync(resolved));
+                        }
+                        positions.insert(position, (*board).clone());
+                        prediction.reset(*board);
+                        let _ = conn.send(&Marshal::snapshot_ack(position));
+                        ServerEvent::Snapshot
+                    }
+                    ServerMessage::DeltaSnapshot(snapshot) => {
+                        let Some(mut board) = positions.get(snapshot.base).cloned() else {
+                            let _ = conn.send(&Marshal::resync());
+                            continue;
+                        };
+                        board.apply_delta(&snapshot.delta);
+                        positions.insert(snapshot.position, board.clone());
+                        prediction.reset(board);
+                        let _ = conn.send(&Marshal::snapshot_ack(snapshot.position));
+                        ServerEvent::Snapshot
+                    }
+                    ServerMessage::CardPlayed(play) => {
+                        prediction.confirm_card_play(&play);
+                        ServerEvent::CardPlayed(play)
+                    }
+                    ServerMessage::Pong(_)
+                    | ServerMessage::DrawCard(_)
+                    | ServerMessage::Hand(_)
+                    | ServerMessage::Seat(_)
+                    | ServerMessage::CardRejected(_) => continue,
+                };
+                drop(prediction);
+                let _ = tx.send(event);
             }
         });
 
         rx
     }
 
-    pub fn make_move(&self, mv: Move) {
-        let command: Command = Marshal::command(mv);
-        let _ = self.connection.send(&command);
+    /// Applies the move to the predicted board and sends it to the server.
+    /// Returns `false` without sending anything if the move is not legal on the predicted board.
+    #[must_use]
+    pub fn make_move(&self, mv: Move) -> bool {
+        let Some(command) = Marshal::command(mv) else {
+            return false;
+        };
+        let Ok(mut prediction) = self.prediction.lock() else {
+            return false;
+        };
+        let Some(sequence) = prediction.predict(mv) else {
+            return false;
+        };
+        let _ = self.connection.send(&Marshal::sequenced(command, sequence));
+        true
+    }
+
+    /// Asks the server to play a card from the hand. Cards are not predicted, the board changes
+    /// once the server broadcasts the card being played.
+    pub fn play_card(&self, card_id: CardId, targets: Vec<CardTarget>) {
+        let play = PlayCard { card_id, targets };
+        let _ = self.connection.send(&Marshal::play_card(&play));
+    }
+
+    /// The cards in hand as last reported by the server.
+    #[must_use]
+    pub fn hand(&self) -> Vec<HeldCard> {
+        self.hand
+            .lock()
+            .map(|hand| hand.clone())
+            .unwrap_or_default()
     }
 
     pub fn join_game(&self, game_id: &str) {
         let cmd = Marshal::game_command(GameCmd::Join(game_id.into()));
         let _ = self.connection.send(&cmd);
     }
+
+    /// Sends a ping to refine the round trip and server clock estimates.
+    pub fn ping(&self) {
+        let ping = Ping {
+            client_sent: self.local_clock.now(),
+            rtt: self.rtt().unwrap_or_default(),
+        };
+        let _ = self.connection.send(&Marshal::ping(ping));
+    }
+
+    #[must_use]
+    pub fn rtt(&self) -> Option<Duration> {
+        self.clock.lock().ok()?.rtt()
+    }
+
+    /// The current time on the server clock, if enough pings have been answered to know it.
+    #[must_use]
+    pub fn server_time(&self) -> Option<Duration> {
+        self.clock.lock().ok()?.server_time(self.local_clock.now())
+    }
+
+    #[must_use]
+    pub fn clock_samples(&self) -> usize {
+        self.clock
+            .lock()
+            .map(|clock| clock.sample_count())
+            .unwrap_or_default()
+    }
+
+    /// The predicted board, including moves the server has not confirmed yet.
+    #[must_use]
+    pub fn board(&self) -> Board {
+        self.prediction
+            .lock()
+            .map(|prediction| prediction.board().clone())
+            .unwrap_or_default()
+    }
+
+    /// Queues `mv` to be made as soon as the moving piece is ready, see [`Premoves`].
+    pub fn premove(&self, mv: Move) {
+        if let Ok(mut premoves) = self.premoves.lock() {
+            premoves.queue(mv);
+        }
+    }
+
+    #[must_use]
+    pub fn premoves(&self) -> Vec<Move> {
+        self.premoves
+            .lock()
+            .map(|premoves| premoves.all())
+            .unwrap_or_default()
+    }
+
+    pub fn clear_premoves(&self) {
+        if let Ok(mut premoves) = self.premoves.lock() {
+            premoves.clear();
+        }
+    }
+
+    /// Advances the predicted board by the time that has passed on the client's clock and
+    /// makes every premove whose piece has become ready. Returns the premoves that were sent.
+    pub fn tick(&self) -> Vec<Move> {
+        let now = self.local_clock.now();
+        let elapsed = self
+            .advanced_to
+            .lock()
+            .map(|mut advanced_to| {
+                let elapsed = now.saturating_sub(*advanced_to);
+                *advanced_to = (*advanced_to).max(now);
+                elapsed
+            })
+            .unwrap_or_default();
+        let Ok(mut prediction) = self.prediction.lock() else {
+            return vec![];
+        };
+        prediction.advance(elapsed);
+        let Ok(mut premoves) = self.premoves.lock() else {
+            return vec![];
+        };
+        if premoves.is_empty() {
+            return vec![];
+        }
+        let ready = premoves.take_ready(prediction.board());
+        drop(premoves);
+        drop(prediction);
+
+        ready.into_iter().filter(|mv| self.make_move(*mv)).collect()
+    }
 }
@@ -0,0 +1,104 @@
+use std::{collections::VecDeque, time::Duration};
+
+use network::command::Sequence;
+use state::{board::Board, cards::CardPlay, piece::Move};
+
+/**
+Keeps the board as the server last confirmed it, plus the player's own moves that the server
+has not answered yet. The predicted board is always the confirmed board with the pending moves
+replayed on top, so a confirmation, rejection or snapshot is handled by rewinding to the
+confirmed board and replaying whatever is still pending.
+*/
+#[derive(Debug, Clone)]
+pub struct Prediction {
+    confirmed: Board,
+    predicted: Board,
+    pending: VecDeque<(Sequence, Move)>,
+    next_sequence: Sequence,
+}
+
+impl Prediction {
+    #[must_use]
+    pub fn new(board: Board) -> Self {
+        Self {
+            predicted: board.clone(),
+            confirmed: board,
+            pending: VecDeque::new(),
+            next_sequence: 0,
+        }
+    }
+
+    /// The board as the player should see it.
+    #[must_use]
+    pub fn board(&self) -> &Board {
+        &self.predicted
+    }
+
+    /// The board as the server last confirmed it.
+    #[must_use]
+    pub fn confirmed(&self) -> &Board {
+        &self.confirmed
+    }
+
+    #[must_use]
+    pub fn pending(&self) -> Vec<Move> {
+        self.pending.iter().map(|(_, mv)| *mv).collect()
+    }
+
+    /// Applies the player's own move immediately if it is legal on the predicted board,
+    /// returning the sequence number to send along with it.
+    pub fn predict(&mut self, mv: Move) -> Option<Sequence> {
+        if !self.predicted.is_valid_move(mv) {
+            return None;
+        }
+
+        let sequence = self.next_sequence;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        self.pending.push_back((sequence, mv));
+        self.predicted.process_move(mv);
+
+        Some(sequence)
+    }
+
+    /// Applies a move broadcast by the server. `sequence` is only set when the move is one of
+    /// our own predictions being confirmed.
+    pub fn confirm(&mut self, mv: Move, sequence: Option<Sequence>) {
+        self.confirmed.process_move(mv);
+        if let Some(sequence) = sequence {
+            self.pending.retain(|(pending, _)| *pending != sequence);
+        }
+        self.replay();
+    }
+
+    /// Applies a card played by either player. Cards are never predicted, so this only
+    /// changes the confirmed board before replaying the pending moves.
+    pub fn confirm_card_play(&mut self, play: &CardPlay) {
+        self.confirmed.process_card_play(play);
+        self.replay();
+    }
+
+    pub fn reject(&mut self, sequence: Sequence) {
+        self.pending.retain(|(pending, _)| *pending != sequence);
+        self.replay();
+    }
+
+    /// Replaces the confirmed board with a snapshot from the server.
+    pub fn reset(&mut self, snapshot: Board) {
+        self.confirmed = snapshot;
+        self.replay();
+    }
+
+    /// Moves both boards `elapsed` forward, see [`Board::advance`].
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.confirmed.advance(elapsed);
+        self.predicted.advance(elapsed);
+    }
+
+    fn replay(&mut self) {
+        let mut predicted = self.confirmed.clone();
+        for (_, mv) in &self.pending {
+            predicted.process_move(*mv);
+        }
+        self.predicted = predicted;
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use state::{board::Board, coordinate::Coord, piece::Move};
+
+/**
+Moves queued by the player to be made as soon as the moving piece is ready.
+
+Premoves are queued per piece, keyed by the square the piece will be standing on when the
+next premove fires. Queuing a move from the square a queue ends on extends that queue, so a
+piece can be sent along a path. Once a piece is ready, its next premove is checked against
+the board: a legal move is fired and the rest of the queue follows the piece, an illegal one
+drops the whole queue, since the moves after it start from a square the piece never reaches.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Premoves {
+    queues: Vec<(Coord, VecDeque<Move>)>,
+}
+
+impl Premoves {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn queue(&mut self, mv: Move) {
+        let from = Premoves::origin(mv);
+        let chained = self
+            .queues
+            .iter_mut()
+            .find(|(_, moves)| moves.back().map(|last| Premoves::destination(*last)) == Some(from));
+        match chained {
+            Some((_, moves)) => moves.push_back(mv),
+            None => self.queues.push((from, VecDeque::from([mv]))),
+        }
+    }
+
+    /// Every queued premove, grouped per piece in the order they will be made.
+    #[must_use]
+    pub fn all(&self) -> Vec<Move> {
+        self.queues
+            .iter()
+            .flat_map(|(_, moves)| moves.iter().copied())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queues.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.queues.clear();
+    }
+
+    /// Takes the premoves whose piece is ready on `board` and which are still legal, dropping
+    /// the queues of pieces that are gone or whose next premove has become illegal.
+    pub fn take_ready(&mut self, board: &Board) -> Vec<Move> {
+        let mut ready = vec![];
+        self.queues.retain_mut(|(square, moves)| {
+            let Some(piece) = board.get_piece_at(square) else {
+                return false;
+            };
+            if !piece.can_move() {
+                return true;
+            }
+            let Some(mv) = moves.pop_front() else {
+                return false;
+            };
+            if !board.is_valid_move(mv) {
+                return false;
+            }
+            ready.push(mv);
+            *square = Premoves::destination(mv);
+            !moves.is_empty()
+        });
+        ready
+    }
+
+    /// The square of the piece making `mv`. For castling this is the king.
+    fn origin(mv: Move) -> Coord {
+        match mv {
+            Move::Piece(from, _) | Move::Promotion(from, _, _) => from,
+            Move::KingSideCastle(color) | Move::QueenSideCastle(color) => {
+                Coord(4, Board::castle_rank(color))
+            }
+        }
+    }
+
+    /// Where the piece making `mv` ends up. For castling this is the king.
+    fn destination(mv: Move) -> Coord {
+        match mv {
+            Move::Piece(_, to) | Move::Promotion(_, to, _) => to,
+            Move::KingSideCastle(color) => Coord(6, Board::castle_rank(color)),
+            Move::QueenSideCastle(color) => Coord(2, Board::castle_rank(color)),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod detection {
+    use state::{board::Board, piece::Move, square::*};
+
+    use crate::desync::DesyncDetector;
+
+    #[test]
+    fn matching_hash_is_in_sync() {
+        let board = Board::standard();
+        let mut detector = DesyncDetector::new();
+
+        assert!(!detector.check(&board, board.sync_hash()));
+        assert!(!detector.is_resyncing());
+        assert_eq!(detector.resolve(&board), None);
+    }
+
+    #[test]
+    fn mismatch_is_reported_with_both_boards() {
+        let local = Board::standard();
+        let mut server = Board::standard();
+        server.process_move(Move::Piece(E2, E4));
+        let mut detector = DesyncDetector::new();
+
+        assert!(detector.check(&local, server.sync_hash()));
+        assert!(detector.is_resyncing());
+
+        let desync = detector.resolve(&server).expect("a desync to resolve");
+        assert_eq!(desync.local_fen, local.fen());
+        assert_eq!(desync.server_fen, server.fen());
+        assert!(!detector.is_resyncing());
+    }
+
+    #[test]
+    fn repeated_mismatches_keep_the_first_board() {
+        let first = Board::standard();
+        let mut second = Board::standard();
+        second.process_move(Move::Piece(G1, F3));
+        let mut server = Board::standard();
+        server.process_move(Move::Piece(E2, E4));
+        let mut detector = DesyncDetector::new();
+
+        assert!(detector.check(&first, server.sync_hash()));
+        assert!(detector.check(&second, server.sync_hash()));
+        let desync = detector.resolve(&server).expect("a desync to resolve");
+        assert_eq!(desync.local_fen, first.fen());
+    }
+}
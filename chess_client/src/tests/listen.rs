@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod server_messages {
+    use std::{net::UdpSocket, time::Duration};
+
+    use network::{delta::DeltaSnapshot, marshal::Marshal, unmarshal::Unmarshal};
+    use state::{board::Board, delta::BoardDelta};
+
+    use crate::{ChessClient, ServerEvent};
+
+    /// A client connected to a local socket standing in for the server, and the client's address
+    /// as seen by that socket.
+    fn connect() -> (ChessClient, UdpSocket, std::net::SocketAddr) {
+        let server = UdpSocket::bind("127.0.0.1:0").expect("bind server");
+        server
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .expect("set timeout");
+        let host = server.local_addr().expect("server address").to_string();
+        let client = ChessClient::new(&"0".to_string(), &host).expect("connect client");
+
+        client.join_game("test");
+        let mut buf = [0; 64];
+        let (_, address) = server.recv_from(&mut buf).expect("join");
+        (client, server, address)
+    }
+
+    fn receive(server: &UdpSocket) -> Vec<u8> {
+        let mut buf = [0; 64];
+        let len = server.recv(&mut buf).expect("a reply");
+        buf[..len].to_vec()
+    }
+
+    #[test]
+    fn delta_against_an_unknown_position_asks_for_a_resync() {
+        let (client, server, address) = connect();
+        let events = client.listen();
+
+        let delta = Marshal::delta_snapshot(&DeltaSnapshot {
+            base: 3,
+            position: 4,
+            delta: BoardDelta::default(),
+        });
+        server.send_to(&delta, address).expect("send delta");
+        assert_eq!(receive(&server), Marshal::resync());
+
+        server
+            .send_to(&Marshal::snapshot(&Board::standard(), 5), address)
+            .expect("send snapshot");
+        assert_eq!(Unmarshal::snapshot_ack(&receive(&server)), Some(5));
+        assert!(matches!(
+            events.recv_timeout(Duration::from_secs(2)),
+            Ok(ServerEvent::Snapshot)
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+mod desync;
+mod listen;
+mod prediction;
+mod premoves;
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod reconciliation {
+    use std::time::Duration;
+
+    use state::{
+        board::Board,
+        cards::{CardKind, CardPlay},
+        piece::{Color, Move},
+        square::*,
+    };
+
+    use crate::prediction::Prediction;
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn own_move_applies_immediately() {
+        let mut prediction = Prediction::new(ready_board());
+        let sequence = prediction.predict(Move::Piece(E2, E4));
+
+        assert_eq!(sequence, Some(0));
+        assert!(prediction.board().get_piece_at(&E4).is_some());
+        assert!(prediction.confirmed().get_piece_at(&E4).is_none());
+        assert_eq!(prediction.pending(), vec![Move::Piece(E2, E4)]);
+    }
+
+    #[test]
+    fn illegal_move_is_not_predicted() {
+        let mut prediction = Prediction::new(ready_board());
+
+        assert_eq!(prediction.predict(Move::Piece(E2, E5)), None);
+        assert!(prediction.pending().is_empty());
+    }
+
+    #[test]
+    fn confirmation_clears_pending() {
+        let mut prediction = Prediction::new(ready_board());
+        let sequence = prediction.predict(Move::Piece(E2, E4));
+        prediction.confirm(Move::Piece(E2, E4), sequence);
+
+        assert!(prediction.pending().is_empty());
+        assert!(prediction.confirmed().get_piece_at(&E4).is_some());
+        assert!(prediction.board().get_piece_at(&E4).is_some());
+    }
+
+    #[test]
+    fn rejection_rolls_back() {
+        let mut prediction = Prediction::new(ready_board());
+        let sequence = prediction.predict(Move::Piece(E2, E4)).expect("legal move");
+        prediction.reject(sequence);
+
+        assert!(prediction.pending().is_empty());
+        assert!(prediction.board().get_piece_at(&E4).is_none());
+        assert!(prediction.board().get_piece_at(&E2).is_some());
+    }
+
+    #[test]
+    fn pending_moves_replay_over_opponent_moves() {
+        let mut prediction = Prediction::new(ready_board());
+        prediction.predict(Move::Piece(E2, E4));
+        prediction.confirm(Move::Piece(D7, D5), None);
+
+        assert!(prediction.confirmed().get_piece_at(&D5).is_some());
+        assert!(prediction.confirmed().get_piece_at(&E4).is_none());
+        assert!(prediction.board().get_piece_at(&D5).is_some());
+        assert!(prediction.board().get_piece_at(&E4).is_some());
+    }
+
+    #[test]
+    fn snapshot_replaces_confirmed_board() {
+        let mut prediction = Prediction::new(ready_board());
+        prediction.predict(Move::Piece(E2, E4));
+        prediction.reset(Board::from("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1"));
+
+        assert_eq!(prediction.confirmed().get_piece_count(), 3);
+        assert_eq!(prediction.board().get_piece_count(), 3);
+        assert!(prediction.board().get_piece_at(&E4).is_some());
+    }
+
+    #[test]
+    fn card_play_applies_under_pending_moves() {
+        let mut prediction = Prediction::new(ready_board());
+        prediction.predict(Move::Piece(E2, E4));
+        prediction.confirm_card_play(&CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::Black,
+            targets: vec![D7, D6],
+        });
+
+        assert!(prediction.confirmed().get_piece_at(&D6).is_some());
+        assert!(prediction.board().get_piece_at(&D6).is_some());
+        assert!(prediction.board().get_piece_at(&E4).is_some());
+    }
+
+    #[test]
+    fn advance_runs_both_boards_down() {
+        let mut prediction = Prediction::new(Board::standard());
+        prediction.advance(Duration::from_millis(500));
+
+        let ready = |board: &Board| board.get_piece_at(&E2).is_some_and(|pawn| pawn.can_move());
+        assert!(ready(prediction.confirmed()));
+        assert!(ready(prediction.board()));
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod queue {
+    use std::time::Duration;
+
+    use state::{board::Board, piece::Move, square::*};
+
+    use crate::premoves::Premoves;
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn waits_for_cooldown() {
+        let mut board = Board::standard();
+        let mut premoves = Premoves::new();
+        premoves.queue(Move::Piece(E2, E4));
+
+        assert!(premoves.take_ready(&board).is_empty());
+        assert_eq!(premoves.all(), vec![Move::Piece(E2, E4)]);
+
+        board.decay_cooldowns(Duration::from_secs(10));
+        assert_eq!(premoves.take_ready(&board), vec![Move::Piece(E2, E4)]);
+        assert!(premoves.is_empty());
+    }
+
+    #[test]
+    fn chained_premoves_follow_the_piece() {
+        let mut board = ready_board();
+        let mut premoves = Premoves::new();
+        premoves.queue(Move::Piece(G1, F3));
+        premoves.queue(Move::Piece(B1, C3));
+        premoves.queue(Move::Piece(F3, G5));
+
+        let fired = premoves.take_ready(&board);
+        assert_eq!(fired, vec![Move::Piece(G1, F3), Move::Piece(B1, C3)]);
+        assert_eq!(premoves.all(), vec![Move::Piece(F3, G5)]);
+
+        for mv in fired {
+            board.process_move(mv);
+        }
+        assert!(premoves.take_ready(&board).is_empty());
+
+        board.decay_cooldowns(Duration::from_secs(10));
+        assert_eq!(premoves.take_ready(&board), vec![Move::Piece(F3, G5)]);
+    }
+
+    #[test]
+    fn illegal_premove_drops_the_queue() {
+        let board = ready_board();
+        let mut premoves = Premoves::new();
+        premoves.queue(Move::Piece(E2, E5));
+        premoves.queue(Move::Piece(E5, E6));
+        premoves.queue(Move::Piece(D2, D4));
+
+        assert_eq!(premoves.take_ready(&board), vec![Move::Piece(D2, D4)]);
+        assert!(premoves.is_empty());
+    }
+
+    #[test]
+    fn captured_piece_drops_the_queue() {
+        let mut board = ready_board();
+        let mut premoves = Premoves::new();
+        premoves.queue(Move::Piece(E2, E4));
+        board.set_piece_at(None, E2);
+
+        assert!(premoves.take_ready(&board).is_empty());
+        assert!(premoves.is_empty());
+    }
+}
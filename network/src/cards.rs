@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use state::cards::{deck::HeldCard, CardId, CardTarget};
+
+/// A client asking to play a card from their hand. Targets are resolved against the server's
+/// board when the card is played, so a piece targeted by id is found wherever it has moved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayCard {
+    pub card_id: CardId,
+    pub targets: Vec<CardTarget>,
+}
+
+/// The full contents of a player's hand, sent only to that player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandUpdate {
+    pub hand: Vec<HeldCard>,
+    pub cooldown: Duration,
+    pub until_next_draw: Duration,
+}
@@ -0,0 +1,95 @@
+use std::{collections::VecDeque, time::Duration};
+
+/// The number of samples kept for estimating round trip time and clock offset.
+pub const CLOCK_SYNC_SAMPLES: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ping {
+    pub client_sent: Duration,
+    pub rtt: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pong {
+    pub client_sent: Duration,
+    pub server_received: Duration,
+    pub server_sent: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    rtt: Duration,
+    offset: i128,
+}
+
+/**
+NTP style clock synchronization.
+
+Each `Pong` together with the time it arrived gives one sample of the round trip time, with
+the time the server spent handling the ping subtracted, and of the offset between the server
+clock and the client clock. The round trip estimate is the median of the recent samples. The
+offset is taken from the sample with the shortest round trip, since that sample had the least
+room for asymmetric delays.
+*/
+#[derive(Debug, Default)]
+pub struct ClockSync {
+    samples: VecDeque<Sample>,
+}
+
+impl ClockSync {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_sample(&mut self, pong: &Pong, client_received: Duration) {
+        let t0 = micros(pong.client_sent);
+        let t1 = micros(pong.server_received);
+        let t2 = micros(pong.server_sent);
+        let t3 = micros(client_received);
+
+        let rtt = ((t3 - t0) - (t2 - t1)).max(0);
+        let offset = i128::midpoint(t1 - t0, t2 - t3);
+
+        if self.samples.len() == CLOCK_SYNC_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample {
+            rtt: Duration::from_micros(u64::try_from(rtt).unwrap_or(u64::MAX)),
+            offset,
+        });
+    }
+
+    #[must_use]
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+
+    #[must_use]
+    pub fn rtt(&self) -> Option<Duration> {
+        let mut rtts: Vec<Duration> = self.samples.iter().map(|sample| sample.rtt).collect();
+        rtts.sort();
+        rtts.get(rtts.len() / 2).copied()
+    }
+
+    /// How far the server clock is ahead of the client clock, in microseconds.
+    #[must_use]
+    pub fn offset(&self) -> Option<i128> {
+        self.samples
+            .iter()
+            .min_by_key(|sample| sample.rtt)
+            .map(|sample| sample.offset)
+    }
+
+    /// Translates a time on the client clock to the server clock.
+    #[must_use]
+    pub fn server_time(&self, client_time: Duration) -> Option<Duration> {
+        let server_time = micros(client_time) + self.offset()?;
+        let server_time = u64::try_from(server_time.max(0)).unwrap_or(u64::MAX);
+        Some(Duration::from_micros(server_time))
+    }
+}
+
+fn micros(duration: Duration) -> i128 {
+    i128::try_from(duration.as_micros()).unwrap_or(i128::MAX)
+}
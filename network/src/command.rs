@@ -12,6 +12,22 @@ The first byte indicates what kind of move it is.
 16. Join
 32. Leave
 48. Resign
+----------
+64. Move Rejected
+65. Card Rejected
+80. Snapshot
+96. Ping
+112. Pong
+----------
+128. Draw Card
+144. Play Card
+160. Hand
+176. Card Played
+----------
+192. Delta Snapshot
+208. Snapshot Ack
+224. Resync
+240. Seat
 
 ### In the case of (0) Piece.
 The second byte is the "from" `Coord` in the format u8 0..64 translated to (file, rank)
@@ -51,7 +67,7 @@ The third byte is an EMPTY buffer byte.
 
 The fourth byte is an EMPTY buffer byte.
 
-### In the case of (2) Queen Side Castle
+### In the case of (3) Queen Side Castle
 
 The second byte is color, signified by the least important bit:
 ```markdown
@@ -62,5 +78,188 @@ The third byte is an EMPTY buffer byte.
 
 The fourth byte is an EMPTY buffer byte.
 
+### In the case of (64) Move Rejected
+
+Sent from the server to the player whose move was refused.
+
+The second byte is the reason:
+```markdown
+0 = Cooldown
+1 = Illegal
+2 = Wrong color
+3 = Game over
+4 = Not enough energy
+```
+For a cooldown rejection the third and fourth bytes are the remaining cooldown in
+milliseconds as a big endian u16, saturating at `u16::MAX`. For an energy rejection they are the
+energy the move costs as a big endian u16. Otherwise they are EMPTY buffer bytes.
+
+### In the case of (65) Card Rejected
+
+Sent from the server to the player whose card play was refused. The message is five bytes long.
+
+The second byte is the reason:
+```markdown
+0 = Cooldown
+1 = Bad target
+2 = Not in hand
+3 = Game over
+4 = Not enough energy
+```
+The third and fourth bytes are as in a Move Rejected: the remaining card cooldown or the energy
+the card costs as a big endian u16, and EMPTY buffer bytes otherwise. The fifth byte is the id
+of the card that was played.
+
+### In the case of (80) Snapshot
+
+A snapshot is the full board and is the only message longer than a `SequencedCommand`.
+
+The second byte holds the castling rights, one bit each:
+```markdown
+0b0001 = White king side
+0b0010 = White queen side
+0b0100 = Black king side
+0b1000 = Black queen side
+```
+The third byte is 1 if the game has an energy economy and 0 otherwise.
+The fourth byte is an EMPTY buffer byte.
+
+They are followed by White HP and Black HP as big endian u32s, and then the 64 squares
+ordered a1, b1 .. h8. Each square is at least five bytes: a piece byte as in promotions (or
+`0xFF` for an empty square), the remaining cooldown in milliseconds as a big endian u16 and the
+piece's id as a big endian u16. Empty squares have a zero cooldown and id.
+
+The unused bits of the piece byte flag the status effects on the piece:
+```markdown
+0b0010 = Freeze
+0b0100 = Shield
+0b1000 = Haste
+```
+The time left on each flagged effect follows the id in that order, in milliseconds as a big
+endian u16, so a frozen and hasted piece takes nine bytes.
+
+The snapshot ends with White energy and Black energy as big endian u32s, followed by the energy
+rules as big endian u16s: starting energy, maximum energy, move cost and the regeneration
+interval in milliseconds. Without an energy economy these are all 0. After that come the id the
+next new piece will get as a big endian u16, and the cooldown rules as big endian u16s in
+milliseconds: the cooldown of a pawn, knight, bishop, rook, queen and king after it moves, and
+of a freshly promoted piece. The snapshot's position number, a big endian u16, comes last.
+
+The other rules only matter to the server, which deals the cards and keeps the square history.
+
+### In the case of (96) Ping
+
+Sent by a client to measure latency and the offset between its clock and the server clock.
+
+The second and third bytes are the client's current round trip estimate in milliseconds as a
+big endian u16, which the server uses to compensate for the client's latency.
+The fourth byte is an EMPTY buffer byte.
+
+It is followed by the client's send time in microseconds as a big endian u64.
+
+### In the case of (112) Pong
+
+The server's reply to a Ping. The second, third and fourth bytes are EMPTY buffer bytes.
+
+It is followed by three big endian u64 timestamps in microseconds: the client's send time
+copied from the Ping, the server's receive time and the server's send time.
+
+### Cards
+
+Cards are sent as a card id, which is unique within the owner's deck, and a card kind:
+```markdown
+0 = Rewind
+1 = Duplicate
+2 = Transform
+```
+Hands are secret, so Draw Card and Hand are only ever sent to the owner of the hand.
+
+### In the case of (128) Draw Card
+
+Sent by the server when a card is drawn. The second byte is the card id, the third byte is the
+card kind and the fourth byte is an EMPTY buffer byte.
+
+### In the case of (144) Play Card
+
+Sent by a client to play a card from their hand. The second byte is the card id, the third byte
+is the number of targets (at most 8) and the fourth byte is an EMPTY buffer byte.
+It is followed by three bytes per target, the first of which says what is targeted:
+```markdown
+0 = Square, followed by a `Coord` byte and an EMPTY buffer byte
+1 = Piece, followed by the piece id as a big endian u16
+```
+The server resolves piece targets to the square the piece stands on when the card is played.
+
+### In the case of (160) Hand
+
+The owner's full hand. The second byte is the number of cards and the third and fourth bytes are
+EMPTY buffer bytes. It is followed by the card cooldown and the time until the next draw, both in
+milliseconds as big endian u32s, and then two bytes per card: the card id and the card kind.
+
+### In the case of (176) Card Played
+
+Broadcast by the server once a card has been played. The second byte is the card kind, the
+third byte is the color of the player and the fourth byte is the number of targets.
+It is followed by one `Coord` byte per target.
+
+It is always followed by a Snapshot or a Delta Snapshot, as cards such as Rewind depend on the
+history of a square, which a client that joined late does not have.
+
+### In the case of (192) Delta Snapshot
+
+The changes between a position the client acknowledged and a new position, sent instead of a
+Snapshot once the client has acknowledged one. The second byte says which optional fields follow,
+one bit each:
+```markdown
+0b0001 = HP
+0b0010 = Energy
+0b0100 = Castling rights
+0b1000 = Next piece id
+```
+The third byte holds the castling rights as in a Snapshot and the fourth byte is the number of
+changed squares.
+
+They are followed by the base position and the new position as big endian u16s, and then the
+fields flagged in the second byte: White and Black HP as big endian u32s, White and Black energy
+as big endian u32s and the next piece id as a big endian u16.
+
+Next come the changed squares, each a `Coord` byte followed by the square as in a Snapshot,
+status effects included. The delta ends with a byte counting the squares where only the
+cooldown changed, each a `Coord` byte followed by the cooldown in milliseconds as a big endian
+u16.
+
+### In the case of (208) Snapshot Ack
+
+Sent by a client for every Snapshot and Delta Snapshot it has applied. The second and third bytes
+are the position as a big endian u16 and the fourth byte is an EMPTY buffer byte. The server
+sends later snapshots as deltas against the last acknowledged position.
+
+### In the case of (240) Seat
+
+Sent by the server to a player that joins a game, before the first Snapshot. The second byte is
+the color the player is seated as, signified by the least important bit as in castling, or
+`0xFF` for a spectator. The third and fourth bytes are EMPTY buffer bytes.
+
+## Sequencing
+
+Moves sent by a client are followed by a big endian u16 sequence number, making the message
+6 bytes long. The server echoes the sequence number back to that client only, on both the
+accepted move and a Move Rejected, so that the client can reconcile its predicted board.
+
+## State hashes
+
+Moves broadcast by the server end with the state hash of the server's board after the move, a
+big endian u64 placed after the sequence number if there is one. It covers piece placement,
+castling rights and each side's HP rounded down to a bucket, see `Board::sync_hash`. A client
+whose own board hashes differently after applying the move sends a Resync.
+
 */
 pub type Command = [u8; 4];
+
+pub type Sequence = u16;
+
+/// See `Board::sync_hash`.
+pub type StateHash = u64;
+
+/// A `Command` followed by a `Sequence`.
+pub type SequencedCommand = [u8; 6];
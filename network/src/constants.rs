@@ -15,11 +15,80 @@ pub(crate) const PIECE_QUEEN: u8 = 0b0100_0000;
 pub(crate) const PIECE_KING: u8 = 0b0101_0000;
 
 // Leading commands
-pub(crate) const MOVE_PIECE: u8 = 0b0000_0000;
-pub(crate) const MOVE_PROMOTION: u8 = 0b0000_0001;
-pub(crate) const MOVE_KING_SIDE_CASTLE: u8 = 0b0000_0010;
-pub(crate) const MOVE_QUEEN_SIDE_CASTLE: u8 = 0b0000_0011;
-
-pub(crate) const GAME_JOIN: u8 = 0b0001_0000;
-pub(crate) const GAME_LEAVE: u8 = 0b0010_0000;
-pub(crate) const GAME_RESIGN: u8 = 0b0011_0000;
+pub const MOVE_PIECE: u8 = 0b0000_0000;
+pub const MOVE_PROMOTION: u8 = 0b0000_0001;
+pub const MOVE_KING_SIDE_CASTLE: u8 = 0b0000_0010;
+pub const MOVE_QUEEN_SIDE_CASTLE: u8 = 0b0000_0011;
+
+pub const GAME_JOIN: u8 = 0b0001_0000;
+pub const GAME_LEAVE: u8 = 0b0010_0000;
+pub const GAME_RESIGN: u8 = 0b0011_0000;
+
+pub const MOVE_REJECTED: u8 = 0b0100_0000;
+
+pub(crate) const REJECT_COOLDOWN: u8 = 0b0000_0000;
+pub(crate) const REJECT_ILLEGAL: u8 = 0b0000_0001;
+pub(crate) const REJECT_WRONG_COLOR: u8 = 0b0000_0010;
+pub(crate) const REJECT_GAME_OVER: u8 = 0b0000_0011;
+pub(crate) const REJECT_ENERGY: u8 = 0b0000_0100;
+
+pub const CARD_REJECTED: u8 = 0b0100_0001;
+
+pub(crate) const CARD_REJECT_COOLDOWN: u8 = 0b0000_0000;
+pub(crate) const CARD_REJECT_TARGET: u8 = 0b0000_0001;
+pub(crate) const CARD_REJECT_NOT_IN_HAND: u8 = 0b0000_0010;
+pub(crate) const CARD_REJECT_GAME_OVER: u8 = 0b0000_0011;
+pub(crate) const CARD_REJECT_ENERGY: u8 = 0b0000_0100;
+
+pub const SNAPSHOT: u8 = 0b0101_0000;
+pub const PING: u8 = 0b0110_0000;
+pub const PONG: u8 = 0b0111_0000;
+pub const DRAW_CARD: u8 = 0b1000_0000;
+pub const PLAY_CARD: u8 = 0b1001_0000;
+pub const HAND: u8 = 0b1010_0000;
+pub const CARD_PLAYED: u8 = 0b1011_0000;
+pub const DELTA_SNAPSHOT: u8 = 0b1100_0000;
+pub const SNAPSHOT_ACK: u8 = 0b1101_0000;
+pub const RESYNC: u8 = 0b1110_0000;
+pub const SEAT: u8 = 0b1111_0000;
+
+pub(crate) const SEAT_SPECTATOR: u8 = 0b1111_1111;
+
+pub(crate) const CARD_REWIND: u8 = 0b0000_0000;
+pub(crate) const CARD_DUPLICATE: u8 = 0b0000_0001;
+pub(crate) const CARD_TRANSFORM: u8 = 0b0000_0010;
+
+pub(crate) const TARGET_SQUARE: u8 = 0b0000_0000;
+pub(crate) const TARGET_PIECE: u8 = 0b0000_0001;
+
+/// Kind byte and square or piece id.
+pub(crate) const CARD_TARGET_LEN: usize = 3;
+
+/// The most targets a single card play can have.
+pub(crate) const MAX_CARD_TARGETS: usize = 8;
+
+pub(crate) const EMPTY_SQUARE: u8 = 0b1111_1111;
+
+/// Piece byte, cooldown and piece id, before the time left on any status effects.
+pub(crate) const SNAPSHOT_SQUARE_LEN: usize = 5;
+
+/// Bits of a square's piece byte flagging which status effects follow the piece id.
+pub(crate) const EFFECT_FREEZE: u8 = 0b0000_0010;
+pub(crate) const EFFECT_SHIELD: u8 = 0b0000_0100;
+pub(crate) const EFFECT_HASTE: u8 = 0b0000_1000;
+
+/// Energy of both players, the energy rules and the next piece id.
+pub(crate) const SNAPSHOT_ENERGY_LEN: usize = 18;
+
+/// The cooldown of each kind of piece and of promoted pieces.
+pub(crate) const SNAPSHOT_COOLDOWNS_LEN: usize = 14;
+
+pub(crate) const DELTA_HP: u8 = 0b0000_0001;
+pub(crate) const DELTA_ENERGY: u8 = 0b0000_0010;
+pub(crate) const DELTA_CASTLING: u8 = 0b0000_0100;
+pub(crate) const DELTA_NEXT_PIECE_ID: u8 = 0b0000_1000;
+
+pub(crate) const CASTLE_WHITE_KINGSIDE: u8 = 0b0000_0001;
+pub(crate) const CASTLE_WHITE_QUEENSIDE: u8 = 0b0000_0010;
+pub(crate) const CASTLE_BLACK_KINGSIDE: u8 = 0b0000_0100;
+pub(crate) const CASTLE_BLACK_QUEENSIDE: u8 = 0b0000_1000;
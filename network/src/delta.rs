@@ -0,0 +1,58 @@
+use std::collections::VecDeque;
+
+use state::{board::Board, delta::BoardDelta};
+
+/// Numbers the boards the server sends, so that clients can acknowledge them.
+pub type Position = u16;
+
+/// How many sent positions are kept around to send deltas against.
+pub const POSITION_HISTORY_LEN: usize = 16;
+
+/// The changes since a position the client acknowledged, sent instead of a full snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaSnapshot {
+    /// The acknowledged position the delta applies to.
+    pub base: Position,
+    /// The position the delta results in.
+    pub position: Position,
+    pub delta: BoardDelta,
+}
+
+/// The last [`POSITION_HISTORY_LEN`] boards sent or received, by position.
+#[derive(Debug, Clone, Default)]
+pub struct PositionHistory {
+    positions: VecDeque<(Position, Board)>,
+    next: Position,
+}
+
+impl PositionHistory {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Numbers `board` with the next position and remembers it.
+    pub fn push(&mut self, board: Board) -> Position {
+        let position = self.next;
+        self.next = self.next.wrapping_add(1);
+        self.insert(position, board);
+        position
+    }
+
+    /// Remembers `board` as `position`, as numbered by the other side.
+    pub fn insert(&mut self, position: Position, board: Board) {
+        self.positions.retain(|(existing, _)| *existing != position);
+        self.positions.push_back((position, board));
+        while self.positions.len() > POSITION_HISTORY_LEN {
+            self.positions.pop_front();
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&Board> {
+        self.positions
+            .iter()
+            .find(|(existing, _)| *existing == position)
+            .map(|(_, board)| board)
+    }
+}
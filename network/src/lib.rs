@@ -1,7 +1,12 @@
+pub mod cards;
+pub mod clock_sync;
 pub mod command;
+pub mod constants;
+pub mod delta;
 pub mod game_command;
 pub mod marshal;
+pub mod message;
+pub mod rejection;
 pub mod unmarshal;
 
-mod constants;
 mod tests;
@@ -1,25 +1,52 @@
 use crate::{
-    command::Command,
+    cards::{HandUpdate, PlayCard},
+    clock_sync::{Ping, Pong},
+    command::{Command, Sequence, SequencedCommand, StateHash},
     constants::{
-        BUFFER_BYTE, COLOR_BLACK, COLOR_WHITE, MOVE_KING_SIDE_CASTLE, MOVE_PIECE, MOVE_PROMOTION,
-        MOVE_QUEEN_SIDE_CASTLE, PIECE_BISHOP, PIECE_KING, PIECE_KNIGHT, PIECE_PAWN, PIECE_QUEEN,
-        PIECE_ROOK,
+        BUFFER_BYTE, CARD_DUPLICATE, CARD_PLAYED, CARD_REWIND, CARD_TARGET_LEN, CARD_TRANSFORM,
+        CASTLE_BLACK_KINGSIDE, CASTLE_BLACK_QUEENSIDE, CASTLE_WHITE_KINGSIDE,
+        CASTLE_WHITE_QUEENSIDE, COLOR_BLACK, COLOR_WHITE, DRAW_CARD, EFFECT_FREEZE, EFFECT_HASTE,
+        EFFECT_SHIELD, EMPTY_SQUARE, HAND, MAX_CARD_TARGETS, MOVE_KING_SIDE_CASTLE, MOVE_PIECE,
+        MOVE_PROMOTION, MOVE_QUEEN_SIDE_CASTLE, PIECE_BISHOP, PIECE_KING, PIECE_KNIGHT, PIECE_PAWN,
+        PIECE_QUEEN, PIECE_ROOK, PING, PLAY_CARD, PONG, SEAT, SEAT_SPECTATOR, SNAPSHOT,
     },
+    delta::{DeltaSnapshot, Position},
 };
+use std::time::Duration;
+
 use state::{
+    board::Board,
+    cards::{deck::HeldCard, CardKind, CardPlay, CardTarget},
     coordinate::Coord,
+    energy::EnergyRules,
     piece::{Color, Move, Piece},
+    piece_state::StatusEffect,
+    rules::Rules,
 };
 
 use super::{
-    constants::{GAME_JOIN, GAME_LEAVE, GAME_RESIGN},
+    constants::{
+        CARD_REJECTED, CARD_REJECT_COOLDOWN, CARD_REJECT_ENERGY, CARD_REJECT_GAME_OVER,
+        CARD_REJECT_NOT_IN_HAND, CARD_REJECT_TARGET, DELTA_CASTLING, DELTA_ENERGY, DELTA_HP,
+        DELTA_NEXT_PIECE_ID, DELTA_SNAPSHOT, GAME_JOIN, GAME_LEAVE, GAME_RESIGN, MOVE_REJECTED,
+        REJECT_COOLDOWN, REJECT_ENERGY, REJECT_GAME_OVER, REJECT_ILLEGAL, REJECT_WRONG_COLOR,
+        RESYNC, SNAPSHOT_ACK, SNAPSHOT_SQUARE_LEN, TARGET_PIECE, TARGET_SQUARE,
+    },
     game_command::GameCmd,
+    rejection::{CardRejectReason, CardRejected, MoveRejected, RejectReason},
 };
 
 #[derive(Debug)]
 pub struct Marshal;
 
 impl Marshal {
+    /// The status effects in the order their times follow a square, with their flag bits.
+    pub(crate) const EFFECTS: [(StatusEffect, u8); 3] = [
+        (StatusEffect::Freeze, EFFECT_FREEZE),
+        (StatusEffect::Shield, EFFECT_SHIELD),
+        (StatusEffect::Haste, EFFECT_HASTE),
+    ];
+
     #[must_use]
     pub fn color(value: Color) -> u8 {
         match value {
@@ -52,15 +79,18 @@ impl Marshal {
         rank * 8 + file
     }
 
+    /// Encodes a move, returning `None` for a promotion to a king or a pawn, which the other
+    /// side would refuse to decode.
     #[must_use]
-    pub fn command(value: Move) -> Command {
-        match value {
+    pub fn command(value: Move) -> Option<Command> {
+        let command = match value {
             Move::Piece(from, to) => [
                 MOVE_PIECE,
                 Marshal::coord(from),
                 Marshal::coord(to),
                 BUFFER_BYTE,
             ],
+            Move::Promotion(_, _, piece) if piece.is_king() || piece.is_pawn() => return None,
             Move::Promotion(from, to, piece) => [
                 MOVE_PROMOTION,
                 Marshal::coord(from),
@@ -79,7 +109,8 @@ impl Marshal {
                 BUFFER_BYTE,
                 BUFFER_BYTE,
             ],
-        }
+        };
+        Some(command)
     }
 
     #[must_use]
@@ -98,4 +129,356 @@ impl Marshal {
             GameCmd::Resign => [GAME_RESIGN, 0, 0, 0],
         }
     }
+
+    #[must_use]
+    pub fn move_rejected(value: MoveRejected) -> Command {
+        match value.reason {
+            RejectReason::Cooldown(remaining) => {
+                let millis = u16::try_from(remaining.as_millis()).unwrap_or(u16::MAX);
+                let [hi, lo] = millis.to_be_bytes();
+                [MOVE_REJECTED, REJECT_COOLDOWN, hi, lo]
+            }
+            RejectReason::Illegal => [MOVE_REJECTED, REJECT_ILLEGAL, BUFFER_BYTE, BUFFER_BYTE],
+            RejectReason::WrongColor => {
+                [MOVE_REJECTED, REJECT_WRONG_COLOR, BUFFER_BYTE, BUFFER_BYTE]
+            }
+            RejectReason::GameOver => [MOVE_REJECTED, REJECT_GAME_OVER, BUFFER_BYTE, BUFFER_BYTE],
+            RejectReason::NotEnoughEnergy(required) => {
+                let [hi, lo] = u16::try_from(required).unwrap_or(u16::MAX).to_be_bytes();
+                [MOVE_REJECTED, REJECT_ENERGY, hi, lo]
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn card_rejected(value: CardRejected) -> [u8; 5] {
+        let (reason, amount) = match value.reason {
+            CardRejectReason::Cooldown(remaining) => {
+                (CARD_REJECT_COOLDOWN, Marshal::cooldown(remaining))
+            }
+            CardRejectReason::BadTarget => (CARD_REJECT_TARGET, [BUFFER_BYTE; 2]),
+            CardRejectReason::NotInHand => (CARD_REJECT_NOT_IN_HAND, [BUFFER_BYTE; 2]),
+            CardRejectReason::GameOver => (CARD_REJECT_GAME_OVER, [BUFFER_BYTE; 2]),
+            CardRejectReason::NotEnoughEnergy(required) => (
+                CARD_REJECT_ENERGY,
+                u16::try_from(required).unwrap_or(u16::MAX).to_be_bytes(),
+            ),
+        };
+        let [hi, lo] = amount;
+        [CARD_REJECTED, reason, hi, lo, value.card_id]
+    }
+
+    #[must_use]
+    pub fn sequenced(command: Command, sequence: Sequence) -> SequencedCommand {
+        let [c0, c1, c2, c3] = command;
+        let [s0, s1] = sequence.to_be_bytes();
+        [c0, c1, c2, c3, s0, s1]
+    }
+
+    /// A move as broadcast by the server, with the sequence number for the player who made it and
+    /// the state hash of the board after the move.
+    #[must_use]
+    pub fn move_broadcast(
+        command: Command,
+        sequence: Option<Sequence>,
+        hash: StateHash,
+    ) -> Vec<u8> {
+        let mut bytes = command.to_vec();
+        if let Some(sequence) = sequence {
+            bytes.extend(sequence.to_be_bytes());
+        }
+        bytes.extend(hash.to_be_bytes());
+        bytes
+    }
+
+    #[must_use]
+    pub fn resync() -> Command {
+        [RESYNC, BUFFER_BYTE, BUFFER_BYTE, BUFFER_BYTE]
+    }
+
+    #[must_use]
+    pub fn snapshot(board: &Board, position: Position) -> Vec<u8> {
+        let energy = board.rules.energy.unwrap_or(EnergyRules {
+            starting: 0,
+            max: 0,
+            regen_interval: Duration::ZERO,
+            move_cost: 0,
+        });
+        let has_energy = u8::from(board.rules.energy.is_some());
+
+        let castling = Marshal::castling(board.castling_rights());
+        let mut bytes = vec![SNAPSHOT, castling, has_energy, BUFFER_BYTE];
+        bytes.extend(Marshal::hp(board.white_hp));
+        bytes.extend(Marshal::hp(board.black_hp));
+
+        for rank in board.pieces {
+            for square in rank {
+                bytes.extend(Marshal::square(square));
+            }
+        }
+
+        bytes.extend(Marshal::hp(board.white_energy));
+        bytes.extend(Marshal::hp(board.black_energy));
+        for value in [energy.starting, energy.max, energy.move_cost] {
+            bytes.extend(u16::try_from(value).unwrap_or(u16::MAX).to_be_bytes());
+        }
+        let regen_millis = u16::try_from(energy.regen_interval.as_millis()).unwrap_or(u16::MAX);
+        bytes.extend(regen_millis.to_be_bytes());
+        bytes.extend(board.next_piece_id.to_be_bytes());
+        bytes.extend(Marshal::cooldown_rules(&board.rules));
+        bytes.extend(position.to_be_bytes());
+
+        bytes
+    }
+
+    #[must_use]
+    pub fn delta_snapshot(value: &DeltaSnapshot) -> Vec<u8> {
+        let delta = &value.delta;
+        let flags = [
+            (delta.hp.is_some(), DELTA_HP),
+            (delta.energy.is_some(), DELTA_ENERGY),
+            (delta.castling.is_some(), DELTA_CASTLING),
+            (delta.next_piece_id.is_some(), DELTA_NEXT_PIECE_ID),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .fold(0, |flags, (_, flag)| flags | flag);
+        let castling = delta.castling.map_or(BUFFER_BYTE, Marshal::castling);
+
+        let mut bytes = vec![
+            DELTA_SNAPSHOT,
+            flags,
+            castling,
+            Marshal::square_count(delta.squares.len()),
+        ];
+        bytes.extend(value.base.to_be_bytes());
+        bytes.extend(value.position.to_be_bytes());
+        if let Some((white, black)) = delta.hp {
+            bytes.extend(Marshal::hp(white));
+            bytes.extend(Marshal::hp(black));
+        }
+        if let Some((white, black)) = delta.energy {
+            bytes.extend(Marshal::hp(white));
+            bytes.extend(Marshal::hp(black));
+        }
+        if let Some(next_piece_id) = delta.next_piece_id {
+            bytes.extend(next_piece_id.to_be_bytes());
+        }
+
+        for (coord, square) in &delta.squares {
+            bytes.push(Marshal::coord(*coord));
+            bytes.extend(Marshal::square(*square));
+        }
+        bytes.push(Marshal::square_count(delta.cooldowns.len()));
+        for (coord, cooldown) in &delta.cooldowns {
+            bytes.push(Marshal::coord(*coord));
+            bytes.extend(Marshal::cooldown(*cooldown));
+        }
+
+        bytes
+    }
+
+    /// The color a joining player was seated as, `None` for a spectator.
+    #[must_use]
+    pub fn seat(seat: Option<Color>) -> Command {
+        let color = seat.map_or(SEAT_SPECTATOR, Marshal::color);
+        [SEAT, color, BUFFER_BYTE, BUFFER_BYTE]
+    }
+
+    #[must_use]
+    pub fn snapshot_ack(position: Position) -> Command {
+        let [hi, lo] = position.to_be_bytes();
+        [SNAPSHOT_ACK, hi, lo, BUFFER_BYTE]
+    }
+
+    #[must_use]
+    pub fn ping(value: Ping) -> [u8; 12] {
+        let rtt = u16::try_from(value.rtt.as_millis()).unwrap_or(u16::MAX);
+        let [r0, r1] = rtt.to_be_bytes();
+        let mut bytes = [0; 12];
+        bytes[..4].copy_from_slice(&[PING, r0, r1, BUFFER_BYTE]);
+        bytes[4..].copy_from_slice(&Marshal::timestamp(value.client_sent));
+        bytes
+    }
+
+    #[must_use]
+    pub fn pong(value: Pong) -> [u8; 28] {
+        let mut bytes = [0; 28];
+        bytes[..4].copy_from_slice(&[PONG, BUFFER_BYTE, BUFFER_BYTE, BUFFER_BYTE]);
+        bytes[4..12].copy_from_slice(&Marshal::timestamp(value.client_sent));
+        bytes[12..20].copy_from_slice(&Marshal::timestamp(value.server_received));
+        bytes[20..].copy_from_slice(&Marshal::timestamp(value.server_sent));
+        bytes
+    }
+
+    #[must_use]
+    pub fn card_kind(value: CardKind) -> u8 {
+        match value {
+            CardKind::Rewind => CARD_REWIND,
+            CardKind::Duplicate => CARD_DUPLICATE,
+            CardKind::Transform => CARD_TRANSFORM,
+        }
+    }
+
+    #[must_use]
+    pub fn draw_card(value: HeldCard) -> Command {
+        [
+            DRAW_CARD,
+            value.id,
+            Marshal::card_kind(value.kind),
+            BUFFER_BYTE,
+        ]
+    }
+
+    /**
+    # Panics
+    The function will panic if the card has more than 8 targets.
+     */
+    #[must_use]
+    pub fn play_card(value: &PlayCard) -> Vec<u8> {
+        let mut bytes = vec![
+            PLAY_CARD,
+            value.card_id,
+            Marshal::target_count(value.targets.len()),
+            BUFFER_BYTE,
+        ];
+        for target in &value.targets {
+            bytes.extend(Marshal::card_target(*target));
+        }
+        bytes
+    }
+
+    /**
+    # Panics
+    The function will panic if the hand holds more than 255 cards.
+     */
+    #[must_use]
+    pub fn hand(value: &HandUpdate) -> Vec<u8> {
+        let count = u8::try_from(value.hand.len()).expect("hand is too large to send");
+        let mut bytes = vec![HAND, count, BUFFER_BYTE, BUFFER_BYTE];
+        bytes.extend(Marshal::millis(value.cooldown));
+        bytes.extend(Marshal::millis(value.until_next_draw));
+        for card in &value.hand {
+            bytes.extend([card.id, Marshal::card_kind(card.kind)]);
+        }
+        bytes
+    }
+
+    /**
+    # Panics
+    The function will panic if the card has more than 8 targets.
+     */
+    #[must_use]
+    pub fn card_played(value: &CardPlay) -> Vec<u8> {
+        let mut bytes = vec![
+            CARD_PLAYED,
+            Marshal::card_kind(value.kind),
+            Marshal::color(value.color),
+            Marshal::target_count(value.targets.len()),
+        ];
+        bytes.extend(value.targets.iter().map(|target| Marshal::coord(*target)));
+        bytes
+    }
+
+    fn castling(rights: [bool; 4]) -> u8 {
+        rights
+            .into_iter()
+            .zip([
+                CASTLE_WHITE_KINGSIDE,
+                CASTLE_WHITE_QUEENSIDE,
+                CASTLE_BLACK_KINGSIDE,
+                CASTLE_BLACK_QUEENSIDE,
+            ])
+            .filter(|(allowed, _)| *allowed)
+            .fold(0, |flags, (_, flag)| flags | flag)
+    }
+
+    /// A piece byte flagging its status effects, its cooldown, its id and the time left on each
+    /// flagged effect, or an empty square.
+    fn square(square: Option<Piece>) -> Vec<u8> {
+        let Some(piece) = square else {
+            return vec![
+                EMPTY_SQUARE,
+                BUFFER_BYTE,
+                BUFFER_BYTE,
+                BUFFER_BYTE,
+                BUFFER_BYTE,
+            ];
+        };
+        let effects: Vec<_> = Marshal::EFFECTS
+            .into_iter()
+            .filter_map(|(effect, flag)| Some((flag, piece.get_state().remaining(effect)?)))
+            .collect();
+        let flags = effects.iter().fold(0, |flags, (flag, _)| flags | flag);
+
+        let mut bytes = Vec::with_capacity(SNAPSHOT_SQUARE_LEN + 2 * effects.len());
+        bytes.push(Marshal::piece(piece) | flags);
+        bytes.extend(Marshal::cooldown(piece.get_cooldown()));
+        bytes.extend(piece.get_id().to_be_bytes());
+        for (_, remaining) in effects {
+            bytes.extend(Marshal::cooldown(remaining));
+        }
+        bytes
+    }
+
+    fn square_count(count: usize) -> u8 {
+        u8::try_from(count).expect("a board has at most 64 squares")
+    }
+
+    fn cooldown(cooldown: Duration) -> [u8; 2] {
+        u16::try_from(cooldown.as_millis())
+            .unwrap_or(u16::MAX)
+            .to_be_bytes()
+    }
+
+    fn cooldown_rules(rules: &Rules) -> Vec<u8> {
+        let cooldowns = rules.cooldowns;
+        [
+            cooldowns.pawn,
+            cooldowns.knight,
+            cooldowns.bishop,
+            cooldowns.rook,
+            cooldowns.queen,
+            cooldowns.king,
+            rules.promotion_cooldown,
+        ]
+        .into_iter()
+        .flat_map(Marshal::cooldown)
+        .collect()
+    }
+
+    fn target_count(count: usize) -> u8 {
+        assert!(
+            count <= MAX_CARD_TARGETS,
+            "cannot send {count} card targets"
+        );
+        u8::try_from(count).unwrap_or(u8::MAX)
+    }
+
+    /// A square as a `Coord` byte, or a piece as its id.
+    fn card_target(target: CardTarget) -> [u8; CARD_TARGET_LEN] {
+        match target {
+            CardTarget::Square(coord) => [TARGET_SQUARE, Marshal::coord(coord), BUFFER_BYTE],
+            CardTarget::Piece(id) => {
+                let [i0, i1] = id.to_be_bytes();
+                [TARGET_PIECE, i0, i1]
+            }
+        }
+    }
+
+    fn millis(time: Duration) -> [u8; 4] {
+        u32::try_from(time.as_millis())
+            .unwrap_or(u32::MAX)
+            .to_be_bytes()
+    }
+
+    fn timestamp(time: Duration) -> [u8; 8] {
+        u64::try_from(time.as_micros())
+            .unwrap_or(u64::MAX)
+            .to_be_bytes()
+    }
+
+    fn hp(hp: usize) -> [u8; 4] {
+        u32::try_from(hp).unwrap_or(u32::MAX).to_be_bytes()
+    }
 }
@@ -0,0 +1,30 @@
+use state::{
+    board::Board,
+    cards::{deck::HeldCard, CardPlay},
+    piece::{Color, Move},
+};
+
+use crate::{
+    cards::HandUpdate,
+    clock_sync::Pong,
+    command::{Sequence, StateHash},
+    delta::{DeltaSnapshot, Position},
+    rejection::{CardRejected, MoveRejected},
+};
+
+/// Anything the server sends to a client.
+#[derive(Debug, Clone)]
+pub enum ServerMessage {
+    /// A move, the sequence number if it was our own, and the state hash after the move.
+    Move(Move, Option<Sequence>, Option<StateHash>),
+    MoveRejected(MoveRejected, Option<Sequence>),
+    Snapshot(Box<Board>, Position),
+    DeltaSnapshot(DeltaSnapshot),
+    Pong(Pong),
+    DrawCard(HeldCard),
+    Hand(HandUpdate),
+    CardPlayed(CardPlay),
+    CardRejected(CardRejected),
+    /// The color we were seated as when joining, `None` if we are spectating.
+    Seat(Option<Color>),
+}
@@ -0,0 +1,100 @@
+use std::{fmt::Display, time::Duration};
+
+use state::cards::{CardError, CardId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReason {
+    /// How long until the piece can move, counting the time it is still frozen.
+    Cooldown(Duration),
+    Illegal,
+    WrongColor,
+    GameOver,
+    NotEnoughEnergy(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveRejected {
+    pub reason: RejectReason,
+}
+
+impl Display for RejectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RejectReason::Cooldown(remaining) => {
+                write!(f, "piece is on cooldown for {}ms", remaining.as_millis())
+            }
+            RejectReason::Illegal => write!(f, "illegal move"),
+            RejectReason::WrongColor => write!(f, "not your piece"),
+            RejectReason::GameOver => write!(f, "game is over"),
+            RejectReason::NotEnoughEnergy(required) => {
+                write!(f, "move needs {required} energy")
+            }
+        }
+    }
+}
+
+impl Display for MoveRejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move rejected: {}", self.reason)
+    }
+}
+
+/// Why the server refused to play a card, coarse enough to send over the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardRejectReason {
+    /// Cards are on cooldown for this much longer.
+    Cooldown(Duration),
+    /// The targets do not fit the card, or are gone from the board.
+    BadTarget,
+    NotInHand,
+    GameOver,
+    /// The card costs this much energy.
+    NotEnoughEnergy(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardRejected {
+    pub card_id: CardId,
+    pub reason: CardRejectReason,
+}
+
+impl From<CardError> for CardRejectReason {
+    fn from(value: CardError) -> Self {
+        match value {
+            CardError::Cooldown(remaining) => CardRejectReason::Cooldown(remaining),
+            CardError::NotEnoughEnergy { required, .. } => {
+                CardRejectReason::NotEnoughEnergy(required)
+            }
+            CardError::NotInHand(_) => CardRejectReason::NotInHand,
+            CardError::WrongTargetCount { .. }
+            | CardError::EmptySquare(_)
+            | CardError::OccupiedSquare(_)
+            | CardError::NotYourPiece(_)
+            | CardError::InvalidTarget(_)
+            | CardError::Rewind(_)
+            | CardError::UnknownPiece(_) => CardRejectReason::BadTarget,
+        }
+    }
+}
+
+impl Display for CardRejectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardRejectReason::Cooldown(remaining) => {
+                write!(f, "cards are on cooldown for {}ms", remaining.as_millis())
+            }
+            CardRejectReason::BadTarget => write!(f, "invalid targets"),
+            CardRejectReason::NotInHand => write!(f, "card is not in hand"),
+            CardRejectReason::GameOver => write!(f, "game is over"),
+            CardRejectReason::NotEnoughEnergy(required) => {
+                write!(f, "card needs {required} energy")
+            }
+        }
+    }
+}
+
+impl Display for CardRejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "card {} rejected: {}", self.card_id, self.reason)
+    }
+}
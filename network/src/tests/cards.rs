@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        cards::{HandUpdate, PlayCard},
+        marshal::Marshal,
+        message::ServerMessage,
+        rejection::{CardRejectReason, CardRejected},
+        unmarshal::Unmarshal,
+    };
+    use state::{
+        cards::{deck::HeldCard, CardError, CardKind, CardPlay, CardTarget},
+        piece::Color,
+        square::*,
+    };
+
+    #[test]
+    fn draw_card_round_trip() {
+        let card = HeldCard {
+            id: 7,
+            kind: CardKind::Transform,
+        };
+        let bytes = Marshal::draw_card(card);
+
+        assert_eq!(bytes, [128, 7, 2, 0]);
+        assert!(matches!(
+            Unmarshal::server_message(&bytes),
+            Some(ServerMessage::DrawCard(drawn)) if drawn == card
+        ));
+    }
+
+    #[test]
+    fn play_card_round_trip() {
+        let play = PlayCard {
+            card_id: 3,
+            targets: vec![CardTarget::Square(E2), CardTarget::Piece(300)],
+        };
+        let bytes = Marshal::play_card(&play);
+
+        assert_eq!(bytes, vec![144, 3, 2, 0, 0, 12, 0, 1, 1, 44]);
+        assert_eq!(Unmarshal::play_card(&bytes), Some(play));
+    }
+
+    #[test]
+    fn malformed_play_card_is_refused() {
+        // Missing target.
+        assert_eq!(Unmarshal::play_card(&[144, 3, 2, 0, 0, 12, 0]), None);
+        // Target off the board.
+        assert_eq!(Unmarshal::play_card(&[144, 3, 1, 0, 0, 64, 0]), None);
+        // Unknown target kind and a piece without an id.
+        assert_eq!(Unmarshal::play_card(&[144, 3, 1, 0, 2, 0, 1]), None);
+        assert_eq!(Unmarshal::play_card(&[144, 3, 1, 0, 1, 0, 0]), None);
+        // Too many targets.
+        let mut too_many = vec![144, 3, 9, 0];
+        too_many.extend([0; 9 * 3]);
+        assert_eq!(Unmarshal::play_card(&too_many), None);
+        // Truncated header and wrong lead byte.
+        assert_eq!(Unmarshal::play_card(&[144, 3]), None);
+        assert_eq!(Unmarshal::play_card(&[160, 3, 0, 0]), None);
+    }
+
+    #[test]
+    fn hand_round_trip() {
+        let update = HandUpdate {
+            hand: vec![
+                HeldCard {
+                    id: 0,
+                    kind: CardKind::Rewind,
+                },
+                HeldCard {
+                    id: 4,
+                    kind: CardKind::Duplicate,
+                },
+            ],
+            cooldown: Duration::from_millis(1500),
+            until_next_draw: Duration::from_secs(10),
+        };
+        let bytes = Marshal::hand(&update);
+
+        assert_eq!(bytes.len(), 12 + 4);
+        assert!(matches!(
+            Unmarshal::server_message(&bytes),
+            Some(ServerMessage::Hand(decoded)) if decoded == update
+        ));
+    }
+
+    #[test]
+    fn card_played_round_trip() {
+        let play = CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::Black,
+            targets: vec![D7, D6],
+        };
+        let bytes = Marshal::card_played(&play);
+
+        assert_eq!(bytes, vec![176, 1, 1, 2, 51, 43]);
+        assert!(matches!(
+            Unmarshal::server_message(&bytes),
+            Some(ServerMessage::CardPlayed(decoded)) if decoded == play
+        ));
+    }
+
+    #[test]
+    fn card_rejected_round_trip() {
+        for reason in [
+            CardRejectReason::Cooldown(Duration::from_millis(1500)),
+            CardRejectReason::BadTarget,
+            CardRejectReason::NotInHand,
+            CardRejectReason::GameOver,
+            CardRejectReason::NotEnoughEnergy(4),
+        ] {
+            let rejection = CardRejected { card_id: 9, reason };
+            let bytes = Marshal::card_rejected(rejection);
+            assert!(matches!(
+                Unmarshal::server_message(&bytes),
+                Some(ServerMessage::CardRejected(decoded)) if decoded == rejection
+            ));
+        }
+    }
+
+    #[test]
+    fn card_errors_keep_cost_cooldown_and_target_apart() {
+        let reason = |error: CardError| CardRejectReason::from(error);
+        assert_eq!(
+            reason(CardError::Cooldown(Duration::from_secs(2))),
+            CardRejectReason::Cooldown(Duration::from_secs(2))
+        );
+        assert_eq!(
+            reason(CardError::NotEnoughEnergy {
+                required: 5,
+                available: 1
+            }),
+            CardRejectReason::NotEnoughEnergy(5)
+        );
+        assert_eq!(
+            reason(CardError::EmptySquare(E4)),
+            CardRejectReason::BadTarget
+        );
+        assert_eq!(
+            reason(CardError::UnknownPiece(12)),
+            CardRejectReason::BadTarget
+        );
+        assert_eq!(reason(CardError::NotInHand(3)), CardRejectReason::NotInHand);
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod estimates {
+    use std::time::Duration;
+
+    use crate::clock_sync::{ClockSync, Pong, CLOCK_SYNC_SAMPLES};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn no_samples() {
+        let sync = ClockSync::new();
+        assert_eq!(sync.rtt(), None);
+        assert_eq!(sync.server_time(ms(100)), None);
+    }
+
+    #[test]
+    fn symmetric_delay() {
+        let mut sync = ClockSync::new();
+        // The server clock is 1000ms ahead, 20ms each way and 2ms spent on the server.
+        let pong = Pong {
+            client_sent: ms(100),
+            server_received: ms(1120),
+            server_sent: ms(1122),
+        };
+        sync.add_sample(&pong, ms(142));
+
+        assert_eq!(sync.rtt(), Some(ms(40)));
+        assert_eq!(sync.offset(), Some(1_000_000));
+        assert_eq!(sync.server_time(ms(200)), Some(ms(1200)));
+    }
+
+    #[test]
+    fn offset_from_fastest_sample() {
+        let mut sync = ClockSync::new();
+        let slow = Pong {
+            client_sent: ms(0),
+            server_received: ms(590),
+            server_sent: ms(590),
+        };
+        let fast = Pong {
+            client_sent: ms(200),
+            server_received: ms(710),
+            server_sent: ms(710),
+        };
+        sync.add_sample(&slow, ms(100));
+        sync.add_sample(&fast, ms(220));
+        sync.add_sample(&fast, ms(220));
+
+        assert_eq!(sync.rtt(), Some(ms(20)));
+        assert_eq!(sync.offset(), Some(500_000));
+    }
+
+    #[test]
+    fn keeps_bounded_samples() {
+        let mut sync = ClockSync::new();
+        let pong = Pong {
+            client_sent: ms(0),
+            server_received: ms(10),
+            server_sent: ms(10),
+        };
+        for _ in 0..CLOCK_SYNC_SAMPLES * 2 {
+            sync.add_sample(&pong, ms(20));
+        }
+
+        assert_eq!(sync.sample_count(), CLOCK_SYNC_SAMPLES);
+    }
+}
+
+#[cfg(test)]
+mod wire {
+    use std::time::Duration;
+
+    use crate::{
+        clock_sync::{Ping, Pong},
+        marshal::Marshal,
+        message::ServerMessage,
+        unmarshal::Unmarshal,
+    };
+
+    #[test]
+    fn ping_roundtrip() {
+        let ping = Ping {
+            client_sent: Duration::from_micros(123_456_789),
+            rtt: Duration::from_millis(48),
+        };
+        let bytes = Marshal::ping(ping);
+
+        assert_eq!(bytes[..4], [96, 0, 48, 0]);
+        assert_eq!(Unmarshal::ping(&bytes), Some(ping));
+    }
+
+    #[test]
+    fn pong_roundtrip() {
+        let pong = Pong {
+            client_sent: Duration::from_micros(1),
+            server_received: Duration::from_micros(2),
+            server_sent: Duration::from_micros(3),
+        };
+        let message = Unmarshal::server_message(&Marshal::pong(pong));
+
+        assert!(matches!(message, Some(ServerMessage::Pong(decoded)) if decoded == pong));
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod snapshots {
+    use std::time::Duration;
+
+    use crate::{
+        delta::{DeltaSnapshot, PositionHistory, POSITION_HISTORY_LEN},
+        marshal::Marshal,
+        message::ServerMessage,
+        unmarshal::Unmarshal,
+    };
+    use state::{
+        board::Board,
+        energy::EnergyRules,
+        piece::{Color, Move, Piece},
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    fn round_trip(base: &Board, board: &Board) -> Board {
+        let snapshot = DeltaSnapshot {
+            base: 4,
+            position: 5,
+            delta: base.diff(board),
+        };
+        let Some(ServerMessage::DeltaSnapshot(decoded)) =
+            Unmarshal::server_message(&Marshal::delta_snapshot(&snapshot))
+        else {
+            panic!("expected a delta snapshot");
+        };
+        assert_eq!((decoded.base, decoded.position), (4, 5));
+
+        let mut applied = base.clone();
+        applied.apply_delta(&decoded.delta);
+        applied
+    }
+
+    #[test]
+    fn delta_round_trip() {
+        let mut base = Board::standard();
+        base.enable_energy(EnergyRules::default());
+        base.decay_cooldowns(Duration::from_secs(10));
+        let mut board = base.clone();
+        board.process_move(Move::Piece(E2, E4));
+        board.process_move(Move::Piece(H1, H3));
+        board.set_piece_at(Some(Piece::Queen(Color::White, Duration::ZERO.into())), D4);
+        board.spend_energy(Color::Black, 3);
+        board.black_hp -= 7;
+
+        let applied = round_trip(&base, &board);
+        assert!(applied.diff(&board).is_empty());
+        assert!(!applied.white_can_castle_kingside);
+    }
+
+    #[test]
+    fn delta_keeps_status_effects() {
+        let base = Board::standard();
+        let mut board = base.clone();
+        board.add_effect(G1, StatusEffect::Freeze, Duration::from_millis(900));
+        board.add_effect(E8, StatusEffect::Shield, Duration::from_secs(5));
+        board.add_effect(E8, StatusEffect::Haste, Duration::from_secs(3));
+
+        let applied = round_trip(&base, &board);
+        assert!(applied.diff(&board).is_empty());
+        for coord in [G1, E8] {
+            assert_eq!(
+                applied.get_piece_at(&coord).map(|piece| *piece.get_state()),
+                board.get_piece_at(&coord).map(|piece| *piece.get_state())
+            );
+        }
+
+        let mut cleared = board.clone();
+        cleared.decay_effects(Duration::from_secs(10));
+        let applied = round_trip(&board, &cleared);
+        assert!(!applied
+            .get_piece_at(&E8)
+            .expect("king on e8")
+            .get_state()
+            .has_effect(StatusEffect::Shield));
+    }
+
+    #[test]
+    fn cooldown_only_delta_is_small() {
+        let base = Board::standard();
+        let mut board = base.clone();
+        board.decay_cooldowns(Duration::from_millis(100));
+
+        let delta = base.diff(&board);
+        let bytes = Marshal::delta_snapshot(&DeltaSnapshot {
+            base: 0,
+            position: 1,
+            delta,
+        });
+        assert_eq!(bytes.len(), 8 + 1 + 32 * 3);
+        assert!(round_trip(&base, &board).diff(&board).is_empty());
+    }
+
+    #[test]
+    fn ack_round_trip() {
+        let bytes = Marshal::snapshot_ack(300);
+        assert_eq!(bytes, [208, 1, 44, 0]);
+        assert_eq!(Unmarshal::snapshot_ack(&bytes), Some(300));
+    }
+
+    #[test]
+    fn position_history_forgets_old_positions() {
+        let mut positions = PositionHistory::new();
+        let first = positions.push(Board::standard());
+        for _ in 0..POSITION_HISTORY_LEN {
+            positions.push(Board::empty());
+        }
+
+        assert!(positions.get(first).is_none());
+        assert!(positions.get(first + 1).is_some());
+    }
+}
@@ -1,6 +1,13 @@
 #[cfg(test)]
 mod test {
-    use crate::{command::Command, marshal::Marshal, unmarshal::Unmarshal};
+    use std::time::Duration;
+
+    use crate::{
+        command::Command,
+        marshal::Marshal,
+        rejection::{MoveRejected, RejectReason},
+        unmarshal::Unmarshal,
+    };
     use state::{
         cooldowns::{COOLDOWN_QUEEN, COOLDOWN_ROOK},
         coordinate::Coord,
@@ -27,39 +34,65 @@ mod test {
     #[test]
     fn serialize_move() {
         let mv = Move::Piece(A5, C5);
-        let command: Command = Marshal::command(mv);
-        assert_eq!(command, [0, 32, 34, 0]);
+        let command = Marshal::command(mv);
+        assert_eq!(command, Some([0, 32, 34, 0]));
     }
 
     #[test]
     fn serialize_promotion_white_rook() {
-        let mv = Move::Promotion(D7, D8, Piece::Rook(Color::White, COOLDOWN_ROOK));
-        let command: Command = Marshal::command(mv);
+        let mv = Move::Promotion(D7, D8, Piece::Rook(Color::White, COOLDOWN_ROOK.into()));
+        let command = Marshal::command(mv);
 
-        assert_eq!(command, [1, 51, 59, 48]);
+        assert_eq!(command, Some([1, 51, 59, 48]));
     }
 
     #[test]
     fn serialize_promotion_black_queen() {
-        let mv = Move::Promotion(A2, A1, Piece::Queen(Color::Black, COOLDOWN_QUEEN));
-        let command: Command = Marshal::command(mv);
+        let mv = Move::Promotion(A2, A1, Piece::Queen(Color::Black, COOLDOWN_QUEEN.into()));
+        let command = Marshal::command(mv);
 
-        assert_eq!(command, [1, 8, 0, 65]);
+        assert_eq!(command, Some([1, 8, 0, 65]));
     }
 
     #[test]
     fn serialize_king_side_castle_for_black() {
         let mv = Move::KingSideCastle(Color::Black);
-        let command: Command = Marshal::command(mv);
+        let command = Marshal::command(mv);
 
-        assert_eq!(command, [2, 1, 0, 0]);
+        assert_eq!(command, Some([2, 1, 0, 0]));
     }
 
     #[test]
     fn serialize_queen_side_castle_for_white() {
         let mv = Move::QueenSideCastle(Color::White);
-        let command: Command = Marshal::command(mv);
+        let command = Marshal::command(mv);
+
+        assert_eq!(command, Some([3, 0, 0, 0]));
+    }
 
-        assert_eq!(command, [3, 0, 0, 0]);
+    #[test]
+    fn serialize_cooldown_rejection() {
+        let rejection = MoveRejected {
+            reason: RejectReason::Cooldown(Duration::from_millis(1500)),
+        };
+        let command: Command = Marshal::move_rejected(rejection);
+
+        assert_eq!(command, [64, 0, 5, 220]);
+    }
+
+    #[test]
+    fn serialize_illegal_rejection() {
+        let rejection = MoveRejected {
+            reason: RejectReason::Illegal,
+        };
+        let command: Command = Marshal::move_rejected(rejection);
+
+        assert_eq!(command, [64, 1, 0, 0]);
+    }
+
+    #[test]
+    fn serialize_promotion_to_king() {
+        let mv = Move::Promotion(D7, D8, Piece::King(Color::White, COOLDOWN_ROOK.into()));
+        assert_eq!(Marshal::command(mv), None);
     }
 }
@@ -1,2 +1,5 @@
+mod cards;
+mod clock_sync;
+mod delta;
 mod marshal;
 mod unmarshal;
@@ -13,16 +13,16 @@ mod get_piece_information {
 
     #[test]
     fn get_piece() {
-        let piece = Unmarshal::piece(0b0101_0000);
-        assert!(matches!(piece, Piece::King(_, _)));
+        let piece = Unmarshal::try_piece(0b0101_0000);
+        assert!(matches!(piece, Some(Piece::King(_, _))));
     }
 
     #[test]
     fn get_piece_with_color() {
-        let black_king = Unmarshal::piece(0b0101_0001);
-        let white_bishop = Unmarshal::piece(0b0010_0000);
-        assert!(matches!(black_king, Piece::King(Color::Black, _)));
-        assert!(matches!(white_bishop, Piece::Bishop(Color::White, _)));
+        let black_king = Unmarshal::try_piece(0b0101_0001);
+        let white_bishop = Unmarshal::try_piece(0b0010_0000);
+        assert!(matches!(black_king, Some(Piece::King(Color::Black, _))));
+        assert!(matches!(white_bishop, Some(Piece::Bishop(Color::White, _))));
     }
 }
 
@@ -39,28 +39,353 @@ mod moves {
 
     #[test]
     fn move_piece() {
-        let mv: Move = Unmarshal::command([0u8, 0, 5, 0]);
-        assert_eq!(mv, Move::Piece(A1, F1));
+        let mv = Unmarshal::try_command([0u8, 0, 5, 0]);
+        assert_eq!(mv, Some(Move::Piece(A1, F1)));
     }
 
     #[test]
     fn get_promote() {
-        let mv: Move = Unmarshal::command([1, 8, 0, 0b0001_0001]);
+        let mv = Unmarshal::try_command([1, 8, 0, 0b0001_0001]);
         assert_eq!(
             mv,
-            Move::Promotion(A2, A1, Piece::Knight(Color::Black, COOLDOWN_BISHOP))
+            Some(Move::Promotion(
+                A2,
+                A1,
+                Piece::Knight(Color::Black, COOLDOWN_BISHOP.into())
+            ))
         );
     }
 
     #[test]
     fn castle_king_side() {
-        let mv: Move = Unmarshal::command([2u8, 0, 0, 0]);
-        assert_eq!(mv, Move::KingSideCastle(Color::White));
+        let mv = Unmarshal::try_command([2u8, 0, 0, 0]);
+        assert_eq!(mv, Some(Move::KingSideCastle(Color::White)));
     }
 
     #[test]
     fn castle_queen_side() {
-        let mv: Move = Unmarshal::command([3u8, 1, 0, 0]);
-        assert_eq!(mv, Move::QueenSideCastle(Color::Black));
+        let mv = Unmarshal::try_command([3u8, 1, 0, 0]);
+        assert_eq!(mv, Some(Move::QueenSideCastle(Color::Black)));
+    }
+}
+
+#[cfg(test)]
+mod rejections {
+    use std::time::Duration;
+
+    use crate::{
+        marshal::Marshal,
+        rejection::{MoveRejected, RejectReason},
+        unmarshal::Unmarshal,
+    };
+
+    #[test]
+    fn cooldown_rejection() {
+        let rejection = Unmarshal::move_rejected([64, 0, 5, 220]);
+        assert_eq!(
+            rejection.map(|rejection| rejection.reason),
+            Some(RejectReason::Cooldown(Duration::from_millis(1500)))
+        );
+    }
+
+    #[test]
+    fn rejection_roundtrip() {
+        for reason in [
+            RejectReason::Illegal,
+            RejectReason::WrongColor,
+            RejectReason::GameOver,
+            RejectReason::NotEnoughEnergy(3),
+        ] {
+            let rejection = MoveRejected { reason };
+            let decoded = Unmarshal::move_rejected(Marshal::move_rejected(rejection));
+            assert_eq!(decoded, Some(rejection));
+        }
+    }
+}
+
+#[cfg(test)]
+mod seats {
+    use state::piece::Color;
+
+    use crate::{marshal::Marshal, message::ServerMessage, unmarshal::Unmarshal};
+
+    #[test]
+    fn seat_roundtrip() {
+        for seat in [Some(Color::White), Some(Color::Black), None] {
+            let decoded = Unmarshal::server_message(&Marshal::seat(seat));
+            assert!(matches!(decoded, Some(ServerMessage::Seat(decoded)) if decoded == seat));
+        }
+    }
+}
+
+#[cfg(test)]
+mod sequencing {
+    use state::{piece::Move, square::*};
+
+    use crate::{marshal::Marshal, message::ServerMessage, unmarshal::Unmarshal};
+
+    #[test]
+    fn sequenced_move() {
+        let command = Marshal::command(Move::Piece(E2, E4)).expect("a plain move");
+        let bytes = Marshal::sequenced(command, 513);
+        assert_eq!(bytes, [0, 12, 28, 0, 2, 1]);
+        assert_eq!(Unmarshal::sequenced(&bytes), Some((command, Some(513))));
+    }
+
+    #[test]
+    fn unsequenced_move() {
+        let command = Marshal::command(Move::Piece(E2, E4)).expect("a plain move");
+        assert_eq!(Unmarshal::sequenced(&command), Some((command, None)));
+        assert!(matches!(
+            Unmarshal::server_message(&command),
+            Some(ServerMessage::Move(Move::Piece(E2, E4), None, None))
+        ));
+    }
+
+    #[test]
+    fn broadcast_carries_state_hash() {
+        let command = Marshal::command(Move::Piece(E2, E4)).expect("a plain move");
+        let hash = 0x0102_0304_0506_0708;
+
+        let own = Marshal::move_broadcast(command, Some(513), hash);
+        assert_eq!(own, [0, 12, 28, 0, 2, 1, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assert!(matches!(
+            Unmarshal::server_message(&own),
+            Some(ServerMessage::Move(Move::Piece(E2, E4), Some(513), Some(h))) if h == hash
+        ));
+
+        let other = Marshal::move_broadcast(command, None, hash);
+        assert_eq!(
+            Unmarshal::move_broadcast(&other),
+            Some((command, None, Some(hash)))
+        );
+    }
+}
+
+#[cfg(test)]
+mod snapshots {
+    use std::time::Duration;
+
+    use state::{
+        board::Board,
+        energy::EnergyRules,
+        piece::{Color, Move, Piece},
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    use crate::{marshal::Marshal, message::ServerMessage, unmarshal::Unmarshal};
+
+    #[test]
+    fn standard_roundtrip() {
+        let board = Board::standard();
+        let decoded = Unmarshal::snapshot(&Marshal::snapshot(&board, 0)).expect("a snapshot");
+
+        assert_eq!(decoded.pieces, board.pieces);
+        assert_eq!(decoded.white_hp, board.white_hp);
+        assert_eq!(decoded.black_hp, board.black_hp);
+        assert!(decoded.white_can_castle_kingside && decoded.black_can_castle_queenside);
+        assert_eq!(decoded.zobrist, board.zobrist);
+        assert_eq!(decoded.cooldown_hash(), board.cooldown_hash());
+    }
+
+    #[test]
+    fn keeps_cooldowns_and_rights() {
+        let mut board = Board::standard();
+        board.process_move(Move::Piece(G1, F3));
+        board.decay_cooldowns(Duration::from_millis(300));
+        board.white_can_castle_kingside = false;
+
+        let message = Unmarshal::server_message(&Marshal::snapshot(&board, 513));
+        let Some(ServerMessage::Snapshot(decoded, position)) = message else {
+            panic!("expected a snapshot");
+        };
+        assert_eq!(position, 513);
+
+        let knight = decoded.get_piece_at(&F3).expect("knight on f3");
+        assert_eq!(knight, Piece::Knight(Color::White, Duration::ZERO.into()));
+        assert_eq!(knight.get_cooldown(), Duration::from_millis(1200));
+        assert!(decoded.get_piece_at(&G1).is_none());
+        assert!(!decoded.white_can_castle_kingside);
+        assert!(decoded.white_can_castle_queenside);
+    }
+
+    #[test]
+    fn keeps_energy() {
+        let mut board = Board::standard();
+        assert_eq!(
+            Unmarshal::snapshot(&Marshal::snapshot(&board, 0)).map(|board| board.rules.energy),
+            Some(None)
+        );
+
+        let energy = EnergyRules {
+            move_cost: 1,
+            ..EnergyRules::default()
+        };
+        board.enable_energy(energy);
+        board.spend_energy(Color::Black, 2);
+
+        let decoded = Unmarshal::snapshot(&Marshal::snapshot(&board, 0)).expect("a snapshot");
+        assert_eq!(decoded.rules.energy, Some(energy));
+        assert_eq!(decoded.white_energy, energy.starting);
+        assert_eq!(decoded.black_energy, energy.starting - 2);
+    }
+
+    #[test]
+    fn keeps_cooldown_rules() {
+        let mut board = Board::standard();
+        board.rules.cooldowns.knight = Duration::from_millis(1_500);
+        board.rules.cooldowns.king = Duration::from_millis(700);
+        board.rules.promotion_cooldown = Duration::from_secs(3);
+
+        let snapshot = Marshal::snapshot(&board, 7);
+        let decoded = Unmarshal::snapshot(&snapshot).expect("a snapshot");
+        assert_eq!(decoded.rules.cooldowns, board.rules.cooldowns);
+        assert_eq!(
+            decoded.rules.promotion_cooldown,
+            board.rules.promotion_cooldown
+        );
+        assert_eq!(Unmarshal::snapshot_position(&snapshot), Some(7));
+    }
+
+    #[test]
+    fn keeps_piece_ids() {
+        let mut board = Board::standard();
+        board.process_move(Move::Piece(G1, F3));
+        board.set_piece_at(Some(Piece::Queen(Color::White, Duration::ZERO.into())), D4);
+
+        let decoded = Unmarshal::snapshot(&Marshal::snapshot(&board, 0)).expect("a snapshot");
+        for coord in [A1, F3, D4, H8] {
+            assert_eq!(
+                decoded.get_piece_at(&coord).map(|piece| piece.get_id()),
+                board.get_piece_at(&coord).map(|piece| piece.get_id())
+            );
+        }
+        assert_eq!(decoded.next_piece_id, board.next_piece_id);
+    }
+
+    #[test]
+    fn keeps_status_effects() {
+        let mut board = Board::standard();
+        board.add_effect(E1, StatusEffect::Shield, Duration::from_secs(4));
+        board.add_effect(D8, StatusEffect::Freeze, Duration::from_millis(1_500));
+        board.add_effect(D8, StatusEffect::Haste, Duration::from_millis(2_500));
+
+        let bytes = Marshal::snapshot(&board, 7);
+        let Some(ServerMessage::Snapshot(decoded, position)) = Unmarshal::server_message(&bytes)
+        else {
+            panic!("expected a snapshot");
+        };
+        assert_eq!(position, 7);
+        for coord in [E1, D8, A1] {
+            assert_eq!(
+                decoded.get_piece_at(&coord).map(|piece| *piece.get_state()),
+                board.get_piece_at(&coord).map(|piece| *piece.get_state())
+            );
+        }
+        assert_eq!(decoded.zobrist, board.zobrist);
+    }
+}
+
+#[cfg(test)]
+mod promotions {
+    use state::{
+        cooldowns::COOLDOWN_QUEEN,
+        piece::{Color, Move, Piece},
+        square::*,
+    };
+
+    use crate::unmarshal::Unmarshal;
+
+    #[test]
+    fn valid_promotion() {
+        let mv = Unmarshal::try_command([1, 51, 59, 0b0100_0000]);
+        assert_eq!(
+            mv,
+            Some(Move::Promotion(
+                D7,
+                D8,
+                Piece::Queen(Color::White, COOLDOWN_QUEEN.into())
+            ))
+        );
+    }
+
+    #[test]
+    fn promotion_to_king_or_pawn() {
+        assert_eq!(Unmarshal::try_command([1, 51, 59, 0b0101_0000]), None);
+        assert_eq!(Unmarshal::try_command([1, 51, 59, 0b0000_0000]), None);
+    }
+
+    #[test]
+    fn promotion_to_opponent_color() {
+        assert_eq!(Unmarshal::try_command([1, 51, 59, 0b0100_0001]), None);
+        assert_eq!(Unmarshal::try_command([1, 8, 0, 0b0100_0000]), None);
+    }
+
+    #[test]
+    fn promotion_off_the_last_rank() {
+        assert_eq!(Unmarshal::try_command([1, 43, 51, 0b0100_0000]), None);
+    }
+
+    #[test]
+    fn unknown_piece_or_lead_byte() {
+        assert_eq!(Unmarshal::try_command([1, 51, 59, 0b0111_0000]), None);
+        assert_eq!(Unmarshal::try_command([9, 0, 0, 0]), None);
+    }
+}
+
+#[cfg(test)]
+mod untrusted {
+    use std::time::Duration;
+
+    use state::{board::Board, piece::Move, square::*};
+
+    use crate::{
+        cards::HandUpdate, delta::DeltaSnapshot, game_command::GameCmd, marshal::Marshal,
+        unmarshal::Unmarshal,
+    };
+
+    #[test]
+    fn truncated_server_messages_decode_to_none() {
+        let command = Marshal::command(Move::Piece(E2, E4)).expect("a plain move");
+        let snapshot = Marshal::snapshot(&Board::standard(), 3);
+        let delta = Marshal::delta_snapshot(&DeltaSnapshot {
+            base: 1,
+            position: 2,
+            delta: Board::standard().diff(&Board::empty()),
+        });
+        let hand = Marshal::hand(&HandUpdate {
+            hand: vec![],
+            cooldown: Duration::ZERO,
+            until_next_draw: Duration::ZERO,
+        });
+        for message in [&command[..], &snapshot, &delta, &hand] {
+            assert!(Unmarshal::server_message(message).is_some());
+            for len in 0..message.len() {
+                assert!(Unmarshal::server_message(&message[..len]).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn garbage_decodes_to_none() {
+        assert!(Unmarshal::server_message(&[0b0000_1111, 1, 2, 3]).is_none());
+        let mut snapshot = vec![0b0111_0000; 400];
+        snapshot[0] = 0b0101_0000;
+        assert!(Unmarshal::server_message(&snapshot).is_none());
+        assert!(Unmarshal::card_rejected(&[0b0100_0001, 9, 0, 0, 1]).is_none());
+        assert!(Unmarshal::card_played(&[0b1011_0000, 7, 0, 0]).is_none());
+        assert!(Unmarshal::hand(&[0b1010_0000, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 9]).is_none());
+        assert!(Unmarshal::delta_snapshot(&[0b1100_0000, 0, 0, 1, 0, 1, 0, 2, 64]).is_none());
+    }
+
+    #[test]
+    fn game_commands_reject_unknown_lead_bytes() {
+        assert_eq!(
+            Unmarshal::game_command([0b0001_0000, b'a', b'b', b'c']),
+            Some(GameCmd::Join("abc".into()))
+        );
+        assert!(Unmarshal::game_command([0b0001_0000, 0xff, 0, 0]).is_none());
+        assert!(Unmarshal::game_command([0b0101_0000, 0, 0, 0]).is_none());
     }
 }
@@ -1,20 +1,41 @@
 use crate::{
-    command::Command,
+    cards::{HandUpdate, PlayCard},
+    clock_sync::{Ping, Pong},
+    command::{Command, Sequence, StateHash},
     constants::{
-        COLOR_BLACK, GAME_JOIN, GAME_LEAVE, GAME_RESIGN, MOVE_KING_SIDE_CASTLE, MOVE_PIECE,
-        MOVE_PROMOTION, MOVE_QUEEN_SIDE_CASTLE, PIECE_BISHOP, PIECE_KING, PIECE_KNIGHT, PIECE_PAWN,
-        PIECE_QUEEN, PIECE_ROOK,
+        CARD_DUPLICATE, CARD_PLAYED, CARD_REJECTED, CARD_REJECT_COOLDOWN, CARD_REJECT_ENERGY,
+        CARD_REJECT_GAME_OVER, CARD_REJECT_NOT_IN_HAND, CARD_REJECT_TARGET, CARD_REWIND,
+        CARD_TARGET_LEN, CARD_TRANSFORM, CASTLE_BLACK_KINGSIDE, CASTLE_BLACK_QUEENSIDE,
+        CASTLE_WHITE_KINGSIDE, CASTLE_WHITE_QUEENSIDE, COLOR_BLACK, DELTA_CASTLING, DELTA_ENERGY,
+        DELTA_HP, DELTA_NEXT_PIECE_ID, DELTA_SNAPSHOT, DRAW_CARD, EMPTY_SQUARE, GAME_JOIN,
+        GAME_LEAVE, GAME_RESIGN, HAND, MAX_CARD_TARGETS, MOVE_KING_SIDE_CASTLE, MOVE_PIECE,
+        MOVE_PROMOTION, MOVE_QUEEN_SIDE_CASTLE, MOVE_REJECTED, PIECE_BISHOP, PIECE_KING,
+        PIECE_KNIGHT, PIECE_PAWN, PIECE_QUEEN, PIECE_ROOK, PING, PLAY_CARD, PONG, REJECT_COOLDOWN,
+        REJECT_ENERGY, REJECT_GAME_OVER, REJECT_ILLEGAL, REJECT_WRONG_COLOR, SEAT, SEAT_SPECTATOR,
+        SNAPSHOT, SNAPSHOT_ACK, SNAPSHOT_COOLDOWNS_LEN, SNAPSHOT_ENERGY_LEN, SNAPSHOT_SQUARE_LEN,
+        TARGET_PIECE, TARGET_SQUARE,
     },
+    delta::{DeltaSnapshot, Position},
     game_command::GameCmd,
+    marshal::Marshal,
+    message::ServerMessage,
+    rejection::{CardRejectReason, CardRejected, MoveRejected, RejectReason},
 };
 use state::{
+    board::Board,
+    cards::{deck::HeldCard, CardKind, CardPlay, CardTarget},
     cooldowns::{
         COOLDOWN_BISHOP, COOLDOWN_KING, COOLDOWN_KNIGHT, COOLDOWN_PAWN, COOLDOWN_QUEEN,
         COOLDOWN_ROOK,
     },
     coordinate::Coord,
+    delta::BoardDelta,
+    energy::EnergyRules,
     piece::{Color, Move, Piece},
+    piece_state::{PieceId, NO_PIECE_ID},
+    rules::{PieceCooldowns, Rules},
 };
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct Unmarshal;
@@ -37,48 +58,497 @@ impl Unmarshal {
         Coord(file as i8, rank as i8)
     }
 
-    /**
-    # Panics
-    The function will panic if the byte is not a valid piece.
-     */
     #[must_use]
-    pub fn piece(value: u8) -> Piece {
+    pub fn try_piece(value: u8) -> Option<Piece> {
         let color: Color = Unmarshal::color(value & 1);
 
         let v = value >> 4;
         let pieces = [
-            (PIECE_PAWN, Piece::Pawn(color, COOLDOWN_PAWN)),
-            (PIECE_KNIGHT, Piece::Knight(color, COOLDOWN_KNIGHT)),
-            (PIECE_BISHOP, Piece::Bishop(color, COOLDOWN_BISHOP)),
-            (PIECE_ROOK, Piece::Rook(color, COOLDOWN_ROOK)),
-            (PIECE_QUEEN, Piece::Queen(color, COOLDOWN_QUEEN)),
-            (PIECE_KING, Piece::King(color, COOLDOWN_KING)),
+            (PIECE_PAWN, Piece::Pawn(color, COOLDOWN_PAWN.into())),
+            (PIECE_KNIGHT, Piece::Knight(color, COOLDOWN_KNIGHT.into())),
+            (PIECE_BISHOP, Piece::Bishop(color, COOLDOWN_BISHOP.into())),
+            (PIECE_ROOK, Piece::Rook(color, COOLDOWN_ROOK.into())),
+            (PIECE_QUEEN, Piece::Queen(color, COOLDOWN_QUEEN.into())),
+            (PIECE_KING, Piece::King(color, COOLDOWN_KING.into())),
         ];
 
-        for (comp, piece) in pieces {
-            if comp >> 4 == v {
-                return piece;
+        pieces
+            .into_iter()
+            .find(|(comp, _)| comp >> 4 == v)
+            .map(|(_, piece)| piece)
+    }
+
+    /// Decodes a move, returning `None` for unknown lead bytes and for promotions to a king,
+    /// a pawn or a piece of the wrong color for the promotion rank.
+    #[must_use]
+    pub fn try_command(value: Command) -> Option<Move> {
+        match value[0] {
+            MOVE_PIECE => Some(Unmarshal::decode_move(&value)),
+            MOVE_PROMOTION => Unmarshal::decode_promotion(&value),
+            MOVE_KING_SIDE_CASTLE => Some(Unmarshal::decode_ksc(&value)),
+            MOVE_QUEEN_SIDE_CASTLE => Some(Unmarshal::decode_qsc(&value)),
+            _ => None,
+        }
+    }
+
+    /// Splits a message into its command and the optional trailing sequence number, returning
+    /// `None` if the message is shorter than a command.
+    #[must_use]
+    pub fn sequenced(bytes: &[u8]) -> Option<(Command, Option<Sequence>)> {
+        let (&command, rest) = bytes.split_first_chunk::<4>()?;
+        let sequence = rest
+            .first_chunk::<2>()
+            .map(|sequence| Sequence::from_be_bytes(*sequence));
+
+        Some((command, sequence))
+    }
+
+    /// A move as broadcast by the server. The sequence number and the state hash are told apart
+    /// by the length of the message.
+    #[must_use]
+    pub fn move_broadcast(bytes: &[u8]) -> Option<(Command, Option<Sequence>, Option<StateHash>)> {
+        let (&command, rest) = bytes.split_first_chunk::<4>()?;
+        let sequence = match rest.len() {
+            2 | 10 => rest
+                .first_chunk::<2>()
+                .map(|sequence| Sequence::from_be_bytes(*sequence)),
+            _ => None,
+        };
+        let hash = match rest.len() {
+            8 | 10 => rest
+                .last_chunk::<8>()
+                .map(|hash| StateHash::from_be_bytes(*hash)),
+            _ => None,
+        };
+
+        Some((command, sequence, hash))
+    }
+
+    /// Decodes a snapshot, returning `None` if it is truncated or contains an invalid piece.
+    #[must_use]
+    pub fn snapshot(bytes: &[u8]) -> Option<Board> {
+        let (&[SNAPSHOT, castling, has_energy, _], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        let (hp, mut rest) = rest.split_first_chunk::<8>()?;
+        let [white_kingside, white_queenside, black_kingside, black_queenside] =
+            Unmarshal::castling(castling);
+        let mut board = Board {
+            white_can_castle_kingside: white_kingside,
+            white_can_castle_queenside: white_queenside,
+            black_can_castle_kingside: black_kingside,
+            black_can_castle_queenside: black_queenside,
+            white_hp: Unmarshal::hp(&hp[..4]),
+            black_hp: Unmarshal::hp(&hp[4..]),
+            ..Board::empty()
+        };
+
+        for index in 0..64 {
+            let (square, len) = Unmarshal::square(rest)?;
+            board.pieces[index / 8][index % 8] = square;
+            rest = &rest[len..];
+        }
+
+        let energy = rest.get(..SNAPSHOT_ENERGY_LEN + SNAPSHOT_COOLDOWNS_LEN)?;
+        board.white_energy = Unmarshal::hp(&energy[0..4]);
+        board.black_energy = Unmarshal::hp(&energy[4..8]);
+        if has_energy != 0 {
+            let value = |offset: usize| u16::from_be_bytes([energy[offset], energy[offset + 1]]);
+            board.rules.energy = Some(EnergyRules {
+                starting: value(8).into(),
+                max: value(10).into(),
+                move_cost: value(12).into(),
+                regen_interval: Duration::from_millis(value(14).into()),
+            });
+        }
+        board.next_piece_id = PieceId::from_be_bytes([energy[16], energy[17]]);
+        Unmarshal::cooldown_rules(&energy[SNAPSHOT_ENERGY_LEN..], &mut board.rules);
+        board.rehash();
+
+        Some(board)
+    }
+
+    /// The position a snapshot was numbered with, see [`Unmarshal::snapshot`], or `None` if the
+    /// snapshot is truncated.
+    #[must_use]
+    pub fn snapshot_position(bytes: &[u8]) -> Option<Position> {
+        let squares = (0..64).try_fold(12, |offset, _| {
+            Some(offset + Unmarshal::square_len(*bytes.get(offset)?))
+        })?;
+        let offset = squares + SNAPSHOT_ENERGY_LEN + SNAPSHOT_COOLDOWNS_LEN;
+        let position = bytes.get(offset..)?.first_chunk::<2>()?;
+        Some(Position::from_be_bytes(*position))
+    }
+
+    /// Decodes a delta snapshot, returning `None` if it is truncated or contains an invalid
+    /// piece or square.
+    #[must_use]
+    pub fn delta_snapshot(bytes: &[u8]) -> Option<DeltaSnapshot> {
+        let (&[DELTA_SNAPSHOT, flags, castling, squares, b0, b1, p0, p1], mut rest) =
+            bytes.split_first_chunk::<8>()?
+        else {
+            return None;
+        };
+        let mut delta = BoardDelta::default();
+        if flags & DELTA_CASTLING != 0 {
+            delta.castling = Some(Unmarshal::castling(castling));
+        }
+
+        if flags & DELTA_HP != 0 {
+            let (hp, tail) = rest.split_first_chunk::<8>()?;
+            delta.hp = Some((Unmarshal::hp(&hp[..4]), Unmarshal::hp(&hp[4..])));
+            rest = tail;
+        }
+        if flags & DELTA_ENERGY != 0 {
+            let (energy, tail) = rest.split_first_chunk::<8>()?;
+            delta.energy = Some((Unmarshal::hp(&energy[..4]), Unmarshal::hp(&energy[4..])));
+            rest = tail;
+        }
+        if flags & DELTA_NEXT_PIECE_ID != 0 {
+            let (id, tail) = rest.split_first_chunk::<2>()?;
+            delta.next_piece_id = Some(PieceId::from_be_bytes(*id));
+            rest = tail;
+        }
+
+        for _ in 0..squares {
+            let (&coord, tail) = rest.split_first()?;
+            let (square, len) = Unmarshal::square(tail)?;
+            delta
+                .squares
+                .push((Unmarshal::square_coord(coord)?, square));
+            rest = &tail[len..];
+        }
+
+        let (&count, rest) = rest.split_first()?;
+        delta.cooldowns = rest
+            .get(..usize::from(count) * 3)?
+            .chunks_exact(3)
+            .map(|cooldown| {
+                let coord = Unmarshal::square_coord(cooldown[0])?;
+                Some((coord, Unmarshal::cooldown(&cooldown[1..])))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(DeltaSnapshot {
+            base: Position::from_be_bytes([b0, b1]),
+            position: Position::from_be_bytes([p0, p1]),
+            delta,
+        })
+    }
+
+    /// The position a client acknowledged, or `None` if the message is not a complete
+    /// acknowledgement.
+    #[must_use]
+    pub fn snapshot_ack(bytes: &[u8]) -> Option<Position> {
+        let (&[SNAPSHOT_ACK, hi, lo], _) = bytes.split_first_chunk::<3>()? else {
+            return None;
+        };
+        Some(Position::from_be_bytes([hi, lo]))
+    }
+
+    /// Decodes a game command, returning `None` for an unknown lead byte or a game id that is not
+    /// UTF-8.
+    #[must_use]
+    pub fn game_command(value: Command) -> Option<GameCmd> {
+        match value {
+            [GAME_JOIN, p1, p2, p3] => String::from_utf8(vec![p1, p2, p3]).ok().map(GameCmd::Join),
+            [GAME_LEAVE, ..] => Some(GameCmd::Leave),
+            [GAME_RESIGN, ..] => Some(GameCmd::Resign),
+            _ => None,
+        }
+    }
+
+    /// Decodes a move rejection, returning `None` for an unknown reason.
+    #[must_use]
+    pub fn move_rejected(value: Command) -> Option<MoveRejected> {
+        let reason = match value {
+            [MOVE_REJECTED, REJECT_COOLDOWN, hi, lo] => {
+                RejectReason::Cooldown(Duration::from_millis(u16::from_be_bytes([hi, lo]).into()))
+            }
+            [MOVE_REJECTED, REJECT_ILLEGAL, ..] => RejectReason::Illegal,
+            [MOVE_REJECTED, REJECT_WRONG_COLOR, ..] => RejectReason::WrongColor,
+            [MOVE_REJECTED, REJECT_GAME_OVER, ..] => RejectReason::GameOver,
+            [MOVE_REJECTED, REJECT_ENERGY, hi, lo] => {
+                RejectReason::NotEnoughEnergy(u16::from_be_bytes([hi, lo]).into())
+            }
+            _ => return None,
+        };
+
+        Some(MoveRejected { reason })
+    }
+
+    /// Decodes a card rejection, returning `None` if the message is truncated or the reason is
+    /// unknown.
+    #[must_use]
+    pub fn card_rejected(bytes: &[u8]) -> Option<CardRejected> {
+        let (&[CARD_REJECTED, reason, hi, lo, card_id], _) = bytes.split_first_chunk::<5>()? else {
+            return None;
+        };
+        let amount = u16::from_be_bytes([hi, lo]);
+        let reason = match reason {
+            CARD_REJECT_COOLDOWN => {
+                CardRejectReason::Cooldown(Duration::from_millis(amount.into()))
             }
+            CARD_REJECT_TARGET => CardRejectReason::BadTarget,
+            CARD_REJECT_NOT_IN_HAND => CardRejectReason::NotInHand,
+            CARD_REJECT_GAME_OVER => CardRejectReason::GameOver,
+            CARD_REJECT_ENERGY => CardRejectReason::NotEnoughEnergy(amount.into()),
+            _ => return None,
+        };
+        Some(CardRejected { card_id, reason })
+    }
+
+    /// Decodes a message from the server, returning `None` if it is truncated or cannot be
+    /// decoded.
+    #[must_use]
+    pub fn server_message(bytes: &[u8]) -> Option<ServerMessage> {
+        let message = match *bytes.first()? {
+            MOVE_PIECE | MOVE_PROMOTION | MOVE_KING_SIDE_CASTLE | MOVE_QUEEN_SIDE_CASTLE => {
+                let (command, sequence, hash) = Unmarshal::move_broadcast(bytes)?;
+                ServerMessage::Move(Unmarshal::try_command(command)?, sequence, hash)
+            }
+            MOVE_REJECTED => {
+                let (command, sequence) = Unmarshal::sequenced(bytes)?;
+                ServerMessage::MoveRejected(Unmarshal::move_rejected(command)?, sequence)
+            }
+            SNAPSHOT => ServerMessage::Snapshot(
+                Box::new(Unmarshal::snapshot(bytes)?),
+                Unmarshal::snapshot_position(bytes)?,
+            ),
+            DELTA_SNAPSHOT => ServerMessage::DeltaSnapshot(Unmarshal::delta_snapshot(bytes)?),
+            PONG => ServerMessage::Pong(Unmarshal::pong(bytes)?),
+            DRAW_CARD => ServerMessage::DrawCard(Unmarshal::draw_card(bytes)?),
+            HAND => ServerMessage::Hand(Unmarshal::hand(bytes)?),
+            CARD_PLAYED => ServerMessage::CardPlayed(Unmarshal::card_played(bytes)?),
+            SEAT => ServerMessage::Seat(match *bytes.get(1)? {
+                SEAT_SPECTATOR => None,
+                color => Some(Unmarshal::color(color)),
+            }),
+            CARD_REJECTED => ServerMessage::CardRejected(Unmarshal::card_rejected(bytes)?),
+            _ => return None,
+        };
+        Some(message)
+    }
+
+    /// Decodes a ping, returning `None` if the message is not a complete ping.
+    #[must_use]
+    pub fn ping(bytes: &[u8]) -> Option<Ping> {
+        let (&[PING, r0, r1, _], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        let rtt = u16::from_be_bytes([r0, r1]);
+        Some(Ping {
+            rtt: Duration::from_millis(rtt.into()),
+            client_sent: Unmarshal::timestamp(*rest.first_chunk::<8>()?),
+        })
+    }
+
+    /// Decodes a pong, returning `None` if the message is not a complete pong.
+    #[must_use]
+    pub fn pong(bytes: &[u8]) -> Option<Pong> {
+        let (&[PONG, ..], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        let (client_sent, rest) = rest.split_first_chunk::<8>()?;
+        let (server_received, rest) = rest.split_first_chunk::<8>()?;
+        Some(Pong {
+            client_sent: Unmarshal::timestamp(*client_sent),
+            server_received: Unmarshal::timestamp(*server_received),
+            server_sent: Unmarshal::timestamp(*rest.first_chunk::<8>()?),
+        })
+    }
+
+    #[must_use]
+    pub fn try_card_kind(value: u8) -> Option<CardKind> {
+        match value {
+            CARD_REWIND => Some(CardKind::Rewind),
+            CARD_DUPLICATE => Some(CardKind::Duplicate),
+            CARD_TRANSFORM => Some(CardKind::Transform),
+            _ => None,
         }
+    }
 
-        panic!("could not decode {value}");
+    /// Decodes a drawn card, returning `None` if the message is truncated or the card kind is
+    /// unknown.
+    #[must_use]
+    pub fn draw_card(bytes: &[u8]) -> Option<HeldCard> {
+        let (&[DRAW_CARD, id, kind], _) = bytes.split_first_chunk::<3>()? else {
+            return None;
+        };
+        Some(HeldCard {
+            id,
+            kind: Unmarshal::try_card_kind(kind)?,
+        })
     }
 
-    /**
-    # Panics
-    The function will panic if the first byte in the command cannot be decoded.
-     */
+    /// Decodes a client's request to play a card, returning `None` if the message is truncated,
+    /// has too many targets or any target is off the board.
     #[must_use]
-    pub fn command(value: Command) -> Move {
-        match value[0] {
-            MOVE_PIECE => Unmarshal::decode_move(&value),
-            MOVE_PROMOTION => Unmarshal::decode_promotion(&value),
-            MOVE_KING_SIDE_CASTLE => Unmarshal::decode_ksc(&value),
-            MOVE_QUEEN_SIDE_CASTLE => Unmarshal::decode_qsc(&value),
-            cmd => panic!("invalid lead byte {cmd}"),
+    pub fn play_card(bytes: &[u8]) -> Option<PlayCard> {
+        let (&[PLAY_CARD, card_id, count, _], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        let count = usize::from(count);
+        if count > MAX_CARD_TARGETS {
+            return None;
+        }
+        let targets = rest
+            .get(..count * CARD_TARGET_LEN)?
+            .chunks_exact(CARD_TARGET_LEN)
+            .map(Unmarshal::card_target)
+            .collect::<Option<_>>()?;
+        Some(PlayCard { card_id, targets })
+    }
+
+    /// Decodes a hand, returning `None` if the message is truncated or holds an unknown card.
+    #[must_use]
+    pub fn hand(bytes: &[u8]) -> Option<HandUpdate> {
+        let (&[HAND, count, _, _], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        let (timers, rest) = rest.split_first_chunk::<8>()?;
+        let hand = rest
+            .get(..usize::from(count) * 2)?
+            .chunks_exact(2)
+            .map(|card| {
+                Some(HeldCard {
+                    id: card[0],
+                    kind: Unmarshal::try_card_kind(card[1])?,
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(HandUpdate {
+            hand,
+            cooldown: Unmarshal::millis(&timers[..4]),
+            until_next_draw: Unmarshal::millis(&timers[4..]),
+        })
+    }
+
+    /// Decodes a card played by either player, returning `None` if the message is truncated or
+    /// the card kind or any target is invalid.
+    #[must_use]
+    pub fn card_played(bytes: &[u8]) -> Option<CardPlay> {
+        let (&[CARD_PLAYED, kind, color, count], rest) = bytes.split_first_chunk::<4>()? else {
+            return None;
+        };
+        Some(CardPlay {
+            kind: Unmarshal::try_card_kind(kind)?,
+            color: Unmarshal::color(color),
+            targets: Unmarshal::targets(count, rest)?,
+        })
+    }
+
+    fn castling(flags: u8) -> [bool; 4] {
+        [
+            flags & CASTLE_WHITE_KINGSIDE != 0,
+            flags & CASTLE_WHITE_QUEENSIDE != 0,
+            flags & CASTLE_BLACK_KINGSIDE != 0,
+            flags & CASTLE_BLACK_QUEENSIDE != 0,
+        ]
+    }
+
+    /// A square as encoded by `Marshal::square` and how many bytes it took up, or `None` if it
+    /// is truncated or holds an invalid piece.
+    fn square(bytes: &[u8]) -> Option<(Option<Piece>, usize)> {
+        let lead = *bytes.first()?;
+        let len = Unmarshal::square_len(lead);
+        let bytes = bytes.get(..len)?;
+        if lead == EMPTY_SQUARE {
+            return Some((None, len));
+        }
+        let mut piece = Unmarshal::try_piece(lead)?;
+        piece.set_cooldown(Unmarshal::cooldown(&bytes[1..3]));
+        let state = piece.get_state_mut();
+        state.id = PieceId::from_be_bytes([bytes[3], bytes[4]]);
+
+        let mut offset = SNAPSHOT_SQUARE_LEN;
+        for (effect, flag) in Marshal::EFFECTS {
+            if lead & flag != 0 {
+                state.add_effect(effect, Unmarshal::cooldown(&bytes[offset..offset + 2]));
+                offset += 2;
+            }
+        }
+        Some((Some(piece), len))
+    }
+
+    /// A square index, or `None` if it is off the board.
+    fn square_coord(value: u8) -> Option<Coord> {
+        (value < 64).then(|| Unmarshal::coord(value))
+    }
+
+    /// The cooldowns of a rule set as encoded by `Marshal::cooldown_rules`.
+    fn cooldown_rules(bytes: &[u8], rules: &mut Rules) {
+        let cooldown = |index: usize| Unmarshal::cooldown(&bytes[index * 2..index * 2 + 2]);
+        rules.cooldowns = PieceCooldowns {
+            pawn: cooldown(0),
+            knight: cooldown(1),
+            bishop: cooldown(2),
+            rook: cooldown(3),
+            queen: cooldown(4),
+            king: cooldown(5),
+        };
+        rules.promotion_cooldown = cooldown(6);
+    }
+
+    /// The length of a square starting with the piece byte `lead`.
+    fn square_len(lead: u8) -> usize {
+        if lead == EMPTY_SQUARE {
+            return SNAPSHOT_SQUARE_LEN;
+        }
+        let effects = Marshal::EFFECTS
+            .into_iter()
+            .filter(|(_, flag)| lead & flag != 0)
+            .count();
+        SNAPSHOT_SQUARE_LEN + 2 * effects
+    }
+
+    fn cooldown(bytes: &[u8]) -> Duration {
+        Duration::from_millis(u16::from_be_bytes([bytes[0], bytes[1]]).into())
+    }
+
+    /// A target as encoded by `Marshal::card_target`, or `None` if it is not a valid one.
+    fn card_target(bytes: &[u8]) -> Option<CardTarget> {
+        match bytes[0] {
+            TARGET_SQUARE if bytes[1] < 64 => Some(CardTarget::Square(Unmarshal::coord(bytes[1]))),
+            TARGET_PIECE => match PieceId::from_be_bytes([bytes[1], bytes[2]]) {
+                NO_PIECE_ID => None,
+                id => Some(CardTarget::Piece(id)),
+            },
+            _ => None,
         }
     }
 
+    fn targets(count: u8, bytes: &[u8]) -> Option<Vec<Coord>> {
+        let count = usize::from(count);
+        if count > MAX_CARD_TARGETS {
+            return None;
+        }
+        let targets = bytes.get(..count)?;
+        if targets.iter().any(|target| *target >= 64) {
+            return None;
+        }
+        Some(
+            targets
+                .iter()
+                .map(|target| Unmarshal::coord(*target))
+                .collect(),
+        )
+    }
+
+    fn millis(bytes: &[u8]) -> Duration {
+        let millis = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        Duration::from_millis(millis.into())
+    }
+
+    fn timestamp(micros: [u8; 8]) -> Duration {
+        Duration::from_micros(u64::from_be_bytes(micros))
+    }
+
+    fn hp(bytes: &[u8]) -> usize {
+        let hp = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        usize::try_from(hp).unwrap_or(usize::MAX)
+    }
+
     fn decode_move(bytes: &[u8]) -> Move {
         let from = bytes[1];
         let to = bytes[2];
@@ -86,16 +556,21 @@ impl Unmarshal {
         Move::Piece(Unmarshal::coord(from), Unmarshal::coord(to))
     }
 
-    fn decode_promotion(bytes: &[u8]) -> Move {
-        let from = bytes[1];
-        let to = bytes[2];
-        let piece = bytes[3];
+    fn decode_promotion(bytes: &[u8]) -> Option<Move> {
+        let from = Unmarshal::coord(bytes[1]);
+        let to = Unmarshal::coord(bytes[2]);
+        let piece = Unmarshal::try_piece(bytes[3])?;
 
-        Move::Promotion(
-            Unmarshal::coord(from),
-            Unmarshal::coord(to),
-            Unmarshal::piece(piece),
-        )
+        let color = match to.1 {
+            7 => Color::White,
+            0 => Color::Black,
+            _ => return None,
+        };
+        if !Piece::Pawn(color, COOLDOWN_PAWN.into()).can_promote_to(&piece) {
+            return None;
+        }
+
+        Some(Move::Promotion(from, to, piece))
     }
 
     fn decode_ksc(bytes: &[u8]) -> Move {
@@ -106,20 +581,3 @@ impl Unmarshal {
         Move::QueenSideCastle(Unmarshal::color(bytes[1]))
     }
 }
-
-impl From<Command> for GameCmd {
-    fn from(value: Command) -> Self {
-        match value {
-            [GAME_JOIN, p1, p2, p3] => {
-                if let Ok(name) = String::from_utf8(vec![p1, p2, p3]) {
-                    GameCmd::Join(name)
-                } else {
-                    panic!("invalid game name")
-                }
-            }
-            [GAME_LEAVE, ..] => GameCmd::Leave,
-            [GAME_RESIGN, ..] => GameCmd::Resign,
-            [cmd, ..] => panic!("invalid lead byte {cmd}"),
-        }
-    }
-}
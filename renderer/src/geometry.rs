@@ -0,0 +1,41 @@
+use scene::{
+    mesh::{Material, Shape},
+    SceneObject,
+};
+use state::piece::Color;
+
+use crate::{image::Rgb, mesh::Mesh};
+
+pub const WHITE_PIECE: Rgb = [235, 225, 200];
+pub const BLACK_PIECE: Rgb = [60, 52, 48];
+pub const LIGHT_SQUARE: Rgb = [240, 217, 181];
+pub const DARK_SQUARE: Rgb = [181, 136, 99];
+const FRAME: Rgb = [92, 64, 51];
+
+/// How many sides the round pieces are made of.
+const SEGMENTS: usize = 16;
+
+#[must_use]
+pub fn material_color(material: Material) -> Rgb {
+    match material {
+        Material::Piece(Color::White) => WHITE_PIECE,
+        Material::Piece(Color::Black) => BLACK_PIECE,
+        Material::LightSquare => LIGHT_SQUARE,
+        Material::DarkSquare => DARK_SQUARE,
+        Material::Frame => FRAME,
+    }
+}
+
+/// The triangles of a scene object, in world space.
+#[must_use]
+pub fn object_mesh(object: &SceneObject) -> Mesh {
+    let color = material_color(object.mesh.material);
+    let mut mesh = Mesh::new(color);
+    for shape in &object.mesh.shapes {
+        mesh.merge(&match shape {
+            Shape::Lathe(profile) => Mesh::lathe(profile, SEGMENTS, color),
+            Shape::Cuboid(min, max) => Mesh::cuboid(*min, *max, color),
+        });
+    }
+    mesh.transformed(&object.transform)
+}
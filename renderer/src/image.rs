@@ -0,0 +1,72 @@
+use std::{fmt::Write as _, io::Write};
+
+/// An 8-bit red, green and blue color.
+pub type Rgb = [u8; 3];
+
+/// An RGB image, stored row by row from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Encodes the image as a PNG.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the image is larger than a PNG can hold or `writer` fails.
+    pub fn write_png<W: Write>(&self, writer: W) -> Result<(), png::EncodingError> {
+        let width = u32::try_from(self.width).map_err(|_| png::EncodingError::LimitsExceeded)?;
+        let height = u32::try_from(self.height).map_err(|_| png::EncodingError::LimitsExceeded)?;
+
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        writer.finish()
+    }
+
+    /**
+    The image as lines of `▀` in 24-bit ANSI color, each character showing two pixels: the
+    top one as its foreground and the bottom one as its background. An odd last row is drawn
+    over black.
+    */
+    #[must_use]
+    pub fn to_half_blocks(&self) -> String {
+        let mut text = String::new();
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let [tr, tg, tb] = self.get(x, y);
+                let [br, bg, bb] = if y + 1 < self.height {
+                    self.get(x, y + 1)
+                } else {
+                    [0, 0, 0]
+                };
+                let _ = write!(text, "\x1b[38;2;{tr};{tg};{tb}m\x1b[48;2;{br};{bg};{bb}m▀");
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+}
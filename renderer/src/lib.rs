@@ -0,0 +1,75 @@
+pub mod geometry;
+pub mod image;
+pub mod light;
+pub mod mesh;
+pub mod raster;
+mod tests;
+
+use scene::{camera::OrbitCamera, math::Vec3, Scene};
+use state::board::Board;
+
+use crate::{
+    geometry::object_mesh,
+    image::{Image, Rgb},
+    light::Light,
+    raster::Framebuffer,
+};
+
+const BACKGROUND: Rgb = [30, 30, 36];
+
+/// Draws boards in 3D on the CPU, see [`Renderer::render_scene`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Renderer {
+    pub width: usize,
+    pub height: usize,
+    pub camera: OrbitCamera,
+    pub light: Light,
+    pub background: Rgb,
+}
+
+impl Renderer {
+    /// A renderer for `width` by `height` pixel images, from the default camera.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            camera: OrbitCamera::default(),
+            light: Light::default(),
+            background: BACKGROUND,
+        }
+    }
+
+    /// Draws the board and its pieces with nothing moving.
+    #[must_use]
+    pub fn render(&self, board: &Board) -> Image {
+        self.render_scene(&Scene::still(board))
+    }
+
+    /// Draws every object in `scene`.
+    #[must_use]
+    pub fn render_scene(&self, scene: &Scene) -> Image {
+        let mut framebuffer = Framebuffer::new(self.width, self.height, self.background);
+        if self.width == 0 || self.height == 0 {
+            return framebuffer.into_image();
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let view_projection = self
+            .camera
+            .view_projection(self.width as f32 / self.height as f32);
+        let eye = self.camera.eye();
+        for object in &scene.objects {
+            framebuffer.draw_mesh(&object_mesh(object), &view_projection, eye, &self.light);
+        }
+        framebuffer.into_image()
+    }
+
+    /// Where `point` lands in the image in pixels from the top left, or `None` if it is behind
+    /// the camera.
+    #[must_use]
+    pub fn project(&self, point: Vec3) -> Option<(f32, f32)> {
+        #[allow(clippy::cast_precision_loss)]
+        self.camera
+            .project(point, self.width as f32, self.height as f32)
+    }
+}
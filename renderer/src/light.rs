@@ -0,0 +1,28 @@
+use scene::math::Vec3;
+
+/// A directional light, like the sun, with some ambient light so nothing is fully black.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Light {
+    /// The direction the light travels in.
+    pub direction: Vec3,
+    /// How bright a surface facing away from the light is, from 0 to 1.
+    pub ambient: f32,
+}
+
+impl Light {
+    /// How brightly a surface facing `normal` is lit, from `ambient` to 1.
+    #[must_use]
+    pub fn intensity(&self, normal: Vec3) -> f32 {
+        let diffuse = normal.dot(-self.direction.normalized()).max(0.0);
+        self.ambient + (1.0 - self.ambient) * diffuse
+    }
+}
+
+impl Default for Light {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::new(-0.4, -1.0, -0.6),
+            ambient: 0.35,
+        }
+    }
+}
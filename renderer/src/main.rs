@@ -0,0 +1,65 @@
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+use clap::Parser;
+use renderer::Renderer;
+use scene::camera::{OrbitCamera, DEFAULT_DISTANCE, DEFAULT_PITCH};
+use state::board::Board;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The position to draw, the starting position if left out.
+    fen: Option<String>,
+
+    /// Write a PNG here instead of drawing to the terminal.
+    #[arg(long)]
+    png: Option<PathBuf>,
+
+    /// Image width in pixels. Defaults to 800, or 96 in the terminal.
+    #[arg(long)]
+    width: Option<usize>,
+
+    /// Image height in pixels. Defaults to 600, or 64 in the terminal, where each character
+    /// is two pixels high.
+    #[arg(long)]
+    height: Option<usize>,
+
+    /// Degrees to turn the camera around the board, from White's side toward the h-file.
+    #[arg(long, default_value_t = 0.0)]
+    yaw: f32,
+
+    /// Degrees above the board the camera looks down from.
+    #[arg(long, default_value_t = DEFAULT_PITCH)]
+    pitch: f32,
+
+    #[arg(long, default_value_t = DEFAULT_DISTANCE)]
+    distance: f32,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let board = args
+        .fen
+        .as_deref()
+        .map_or_else(Board::standard, Board::from);
+    let (width, height) = match args.png {
+        Some(_) => (args.width.unwrap_or(800), args.height.unwrap_or(600)),
+        None => (args.width.unwrap_or(96), args.height.unwrap_or(64)),
+    };
+
+    let mut renderer = Renderer::new(width, height);
+    renderer.camera = OrbitCamera::new(
+        args.yaw.to_radians(),
+        args.pitch.to_radians(),
+        args.distance,
+    );
+    let image = renderer.render(&board);
+
+    match args.png {
+        Some(path) => image.write_png(BufWriter::new(File::create(path)?))?,
+        None => print!("{}", image.to_half_blocks()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::f32::consts::TAU;
+
+use scene::math::{Mat4, Vec3};
+
+use crate::image::Rgb;
+
+/// A single-colored triangle mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesh {
+    pub vertices: Vec<Vec3>,
+    /// Indices into `vertices`, three per triangle.
+    pub triangles: Vec<[usize; 3]>,
+    pub color: Rgb,
+}
+
+impl Mesh {
+    /// A mesh with no triangles, to [`Mesh::merge`] others into.
+    #[must_use]
+    pub fn new(color: Rgb) -> Self {
+        Self {
+            vertices: vec![],
+            triangles: vec![],
+            color,
+        }
+    }
+
+    /// An axis-aligned box between two opposite corners.
+    #[must_use]
+    pub fn cuboid(min: Vec3, max: Vec3, color: Rgb) -> Self {
+        let vertices = (0..8)
+            .map(|corner| {
+                Vec3::new(
+                    if corner & 1 == 0 { min.x } else { max.x },
+                    if corner & 2 == 0 { min.y } else { max.y },
+                    if corner & 4 == 0 { min.z } else { max.z },
+                )
+            })
+            .collect();
+        let faces = [
+            [0, 1, 3, 2],
+            [4, 6, 7, 5],
+            [0, 4, 5, 1],
+            [2, 3, 7, 6],
+            [0, 2, 6, 4],
+            [1, 5, 7, 3],
+        ];
+        let triangles = faces
+            .iter()
+            .flat_map(|[a, b, c, d]| [[*a, *b, *c], [*a, *c, *d]])
+            .collect();
+        Self {
+            vertices,
+            triangles,
+            color,
+        }
+    }
+
+    /**
+    A surface of revolution about the Y axis, like a piece turned on a lathe. `profile` is the
+    outline as `(radius, height)` pairs from the bottom up, and the ends are closed off with a
+    flat cap wherever the radius is not zero.
+    */
+    #[must_use]
+    pub fn lathe(profile: &[(f32, f32)], segments: usize, color: Rgb) -> Self {
+        let mut vertices = vec![];
+        for &(radius, height) in profile {
+            for segment in 0..segments {
+                #[allow(clippy::cast_precision_loss)]
+                let angle = TAU * segment as f32 / segments as f32;
+                vertices.push(Vec3::new(
+                    radius * angle.cos(),
+                    height,
+                    radius * angle.sin(),
+                ));
+            }
+        }
+
+        let ring = |ring: usize, segment: usize| ring * segments + segment % segments;
+        let mut triangles = vec![];
+        for level in 0..profile.len().saturating_sub(1) {
+            for segment in 0..segments {
+                let (a, b) = (ring(level, segment), ring(level, segment + 1));
+                let (c, d) = (ring(level + 1, segment + 1), ring(level + 1, segment));
+                triangles.push([a, b, c]);
+                triangles.push([a, c, d]);
+            }
+        }
+
+        let mut mesh = Self {
+            vertices,
+            triangles,
+            color,
+        };
+        if let (Some(&(bottom, _)), Some(&(top, height))) = (profile.first(), profile.last()) {
+            if bottom > 0.0 {
+                mesh.cap(0, segments, profile[0].1);
+            }
+            if top > 0.0 {
+                mesh.cap(profile.len() - 1, segments, height);
+            }
+        }
+        mesh
+    }
+
+    /// Closes off a lathe ring with a fan around its center.
+    fn cap(&mut self, ring: usize, segments: usize, height: f32) {
+        let center = self.vertices.len();
+        self.vertices.push(Vec3::new(0.0, height, 0.0));
+        for segment in 0..segments {
+            let a = ring * segments + segment;
+            let b = ring * segments + (segment + 1) % segments;
+            self.triangles.push([center, a, b]);
+        }
+    }
+
+    /// A copy of the mesh with every vertex transformed by `matrix`.
+    #[must_use]
+    pub fn transformed(&self, matrix: &Mat4) -> Self {
+        Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|vertex| matrix.transform_point(*vertex))
+                .collect(),
+            triangles: self.triangles.clone(),
+            color: self.color,
+        }
+    }
+
+    /// Adds the triangles of `other` to this mesh, in this mesh's color.
+    pub fn merge(&mut self, other: &Mesh) {
+        let offset = self.vertices.len();
+        self.vertices.extend_from_slice(&other.vertices);
+        self.triangles.extend(
+            other
+                .triangles
+                .iter()
+                .map(|[a, b, c]| [a + offset, b + offset, c + offset]),
+        );
+    }
+
+    /// The corners of each triangle.
+    pub fn faces(&self) -> impl Iterator<Item = [Vec3; 3]> + '_ {
+        self.triangles
+            .iter()
+            .map(|[a, b, c]| [self.vertices[*a], self.vertices[*b], self.vertices[*c]])
+    }
+}
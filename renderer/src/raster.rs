@@ -0,0 +1,115 @@
+use scene::{
+    camera::to_screen,
+    math::{Mat4, Vec3},
+};
+
+use crate::{
+    image::{Image, Rgb},
+    light::Light,
+    mesh::Mesh,
+};
+
+/// Clip space w below which a vertex counts as behind the camera.
+const MIN_W: f32 = 1e-4;
+
+/// An image being drawn, with the depth of the nearest surface drawn so far at each pixel.
+#[derive(Debug, Clone)]
+pub struct Framebuffer {
+    image: Image,
+    depth: Vec<f32>,
+}
+
+impl Framebuffer {
+    #[must_use]
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            image: Image::new(width, height, background),
+            depth: vec![f32::INFINITY; width * height],
+        }
+    }
+
+    /**
+    Draws every triangle of `mesh` flat shaded by `light`. Triangles are lit from whichever
+    side faces `eye`, so the winding of a mesh does not matter.
+    */
+    pub fn draw_mesh(&mut self, mesh: &Mesh, view_projection: &Mat4, eye: Vec3, light: &Light) {
+        for [a, b, c] in mesh.faces() {
+            let mut normal = (b - a).cross(c - a).normalized();
+            if normal.dot(eye - a) < 0.0 {
+                normal = -normal;
+            }
+            let color = shade(mesh.color, light.intensity(normal));
+            let clip = [a, b, c].map(|vertex| view_projection.transform(vertex));
+            self.draw_triangle(clip, color);
+        }
+    }
+
+    /**
+    Fills a triangle given in clip space, keeping only the pixels nearer than what is already
+    there. Triangles reaching behind the camera are skipped rather than clipped, which is fine
+    as long as the camera stays outside the scene.
+    */
+    pub fn draw_triangle(&mut self, clip: [[f32; 4]; 3], color: Rgb) {
+        if clip.iter().any(|[_, _, _, w]| *w < MIN_W) {
+            return;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let (width, height) = (self.image.width as f32, self.image.height as f32);
+        let [a, b, c] = clip.map(|point| to_screen(point, width, height));
+        let area = edge(a, b, c);
+        if area.abs() < f32::EPSILON {
+            return;
+        }
+
+        let min_x = a.x.min(b.x).min(c.x).floor().max(0.0);
+        let max_x = a.x.max(b.x).max(c.x).ceil().min(width);
+        let min_y = a.y.min(b.y).min(c.y).floor().max(0.0);
+        let max_y = a.y.max(b.y).max(c.y).ceil().min(height);
+        if min_x >= max_x || min_y >= max_y {
+            return;
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (min_x, max_x, min_y, max_y) = (
+            min_x as usize,
+            max_x as usize,
+            min_y as usize,
+            max_y as usize,
+        );
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                #[allow(clippy::cast_precision_loss)]
+                let point = Vec3::new(x as f32 + 0.5, y as f32 + 0.5, 0.0);
+                let weights = [edge(b, c, point), edge(c, a, point), edge(a, b, point)]
+                    .map(|weight| weight / area);
+                if weights.iter().any(|weight| *weight < 0.0) {
+                    continue;
+                }
+                let depth = weights[0] * a.z + weights[1] * b.z + weights[2] * c.z;
+                if !(-1.0..=1.0).contains(&depth) {
+                    continue;
+                }
+                let index = y * self.image.width + x;
+                if depth < self.depth[index] {
+                    self.depth[index] = depth;
+                    self.image.pixels[index] = color;
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn into_image(self) -> Image {
+        self.image
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `point` on screen.
+fn edge(a: Vec3, b: Vec3, point: Vec3) -> f32 {
+    (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x)
+}
+
+fn shade(color: Rgb, intensity: f32) -> Rgb {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    color.map(|channel| (f32::from(channel) * intensity).round().clamp(0.0, 255.0) as u8)
+}
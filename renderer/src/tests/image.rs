@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod output {
+    use crate::image::Image;
+
+    fn gradient() -> Image {
+        let mut image = Image::new(2, 3, [0, 0, 0]);
+        for y in 0..3 {
+            for x in 0..2 {
+                image.set(
+                    x,
+                    y,
+                    [u8::try_from(x * 100 + y * 10).unwrap_or_default(), 7, 9],
+                );
+            }
+        }
+        image
+    }
+
+    #[test]
+    fn half_blocks_pair_up_rows() {
+        let text = gradient().to_half_blocks();
+        let lines = text.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.matches('▀').count() == 2));
+        assert!(lines[0].starts_with("\x1b[38;2;0;7;9m\x1b[48;2;10;7;9m▀"));
+        // The odd row out is drawn over black.
+        assert!(lines[1].starts_with("\x1b[38;2;20;7;9m\x1b[48;2;0;0;0m▀"));
+        assert!(lines.iter().all(|line| line.ends_with("\x1b[0m")));
+    }
+
+    #[test]
+    fn png_round_trips() {
+        let image = gradient();
+        let mut bytes = vec![];
+        image.write_png(&mut bytes).expect("encodes");
+
+        let decoder = png::Decoder::new(bytes.as_slice());
+        let mut reader = decoder.read_info().expect("decodes");
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).expect("has a frame");
+
+        assert_eq!((info.width, info.height), (2, 3));
+        assert_eq!(pixels, image.pixels.concat());
+    }
+}
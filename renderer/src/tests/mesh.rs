@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod meshes {
+    use std::time::Duration;
+
+    use scene::{
+        math::{Mat4, Vec3},
+        mesh::piece_mesh,
+        piece_transform, Scene, SceneObject,
+    };
+    use state::{
+        board::Board,
+        piece::{Color, Piece},
+    };
+
+    use crate::{
+        geometry::{object_mesh, BLACK_PIECE, DARK_SQUARE, LIGHT_SQUARE},
+        mesh::Mesh,
+    };
+
+    fn all_pieces(color: Color) -> [Piece; 6] {
+        let state = Duration::ZERO.into();
+        [
+            Piece::Pawn(color, state),
+            Piece::Knight(color, state),
+            Piece::Bishop(color, state),
+            Piece::Rook(color, state),
+            Piece::Queen(color, state),
+            Piece::King(color, state),
+        ]
+    }
+
+    #[test]
+    fn cuboid_has_twelve_triangles() {
+        let cuboid = Mesh::cuboid(Vec3::ZERO, Vec3::new(1.0, 1.0, 1.0), [0, 0, 0]);
+
+        assert_eq!(cuboid.vertices.len(), 8);
+        assert_eq!(cuboid.triangles.len(), 12);
+    }
+
+    #[test]
+    fn lathe_caps_open_ends() {
+        let cylinder = Mesh::lathe(&[(1.0, 0.0), (1.0, 1.0)], 8, [0, 0, 0]);
+        let cone = Mesh::lathe(&[(1.0, 0.0), (0.0, 1.0)], 8, [0, 0, 0]);
+
+        assert_eq!(cylinder.triangles.len(), 2 * 8 + 2 * 8);
+        assert_eq!(cone.triangles.len(), 2 * 8 + 8);
+    }
+
+    #[test]
+    fn built_pieces_fit_on_their_square() {
+        for piece in all_pieces(Color::White)
+            .into_iter()
+            .chain(all_pieces(Color::Black))
+        {
+            let mesh = object_mesh(&SceneObject {
+                mesh: piece_mesh(&piece),
+                transform: piece_transform(&piece, Vec3::ZERO),
+                square: None,
+            });
+            for vertex in &mesh.vertices {
+                assert!(vertex.x.abs() <= 0.5 && vertex.z.abs() <= 0.5, "{piece:?}");
+                assert!(vertex.y >= 0.0, "{piece:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn objects_are_built_in_place_and_in_their_material_color() {
+        let knight = Piece::Knight(Color::Black, Duration::ZERO.into());
+        let object = SceneObject {
+            mesh: piece_mesh(&knight),
+            transform: Mat4::translation(Vec3::new(2.0, 0.0, 0.0)),
+            square: None,
+        };
+        let mesh = object_mesh(&object);
+
+        assert_eq!(mesh.color, BLACK_PIECE);
+        assert!(mesh.vertices.iter().all(|vertex| vertex.x > 1.4));
+    }
+
+    #[test]
+    fn squares_alternate_colors() {
+        let scene = Scene::still(&Board::empty());
+        let colors: Vec<_> = scene
+            .objects
+            .iter()
+            .filter(|object| object.square.is_some())
+            .map(|object| object_mesh(object).color)
+            .collect();
+
+        assert_eq!(colors.len(), 64);
+        assert_eq!(colors.iter().filter(|c| **c == LIGHT_SQUARE).count(), 32);
+        assert_eq!(colors.iter().filter(|c| **c == DARK_SQUARE).count(), 32);
+    }
+}
@@ -0,0 +1,4 @@
+mod image;
+mod mesh;
+mod raster;
+mod render;
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod rasterization {
+    use crate::raster::Framebuffer;
+
+    const RED: [u8; 3] = [255, 0, 0];
+    const BLUE: [u8; 3] = [0, 0, 255];
+    const BLACK: [u8; 3] = [0, 0, 0];
+
+    /// A triangle covering the bottom left half of the screen at a depth of `z`.
+    fn lower_left(z: f32) -> [[f32; 4]; 3] {
+        [
+            [-1.0, -1.0, z, 1.0],
+            [1.0, -1.0, z, 1.0],
+            [-1.0, 1.0, z, 1.0],
+        ]
+    }
+
+    #[test]
+    fn fills_only_inside_the_triangle() {
+        let mut framebuffer = Framebuffer::new(8, 8, BLACK);
+        framebuffer.draw_triangle(lower_left(0.0), RED);
+        let image = framebuffer.into_image();
+
+        assert_eq!(image.get(0, 7), RED);
+        assert_eq!(image.get(2, 4), RED);
+        assert_eq!(image.get(7, 0), BLACK);
+        assert_eq!(image.get(5, 4), BLACK);
+    }
+
+    #[test]
+    fn nearer_triangle_wins_in_any_order() {
+        for near_first in [true, false] {
+            let mut framebuffer = Framebuffer::new(4, 4, BLACK);
+            let mut triangles = [(lower_left(-0.5), RED), (lower_left(0.5), BLUE)];
+            if !near_first {
+                triangles.reverse();
+            }
+            for (triangle, color) in triangles {
+                framebuffer.draw_triangle(triangle, color);
+            }
+
+            assert_eq!(framebuffer.into_image().get(0, 3), RED);
+        }
+    }
+
+    #[test]
+    fn skips_triangles_behind_the_camera() {
+        let mut framebuffer = Framebuffer::new(4, 4, BLACK);
+        let mut triangle = lower_left(0.0);
+        triangle[0][3] = -1.0;
+        framebuffer.draw_triangle(triangle, RED);
+
+        assert!(framebuffer
+            .into_image()
+            .pixels
+            .iter()
+            .all(|pixel| *pixel == BLACK));
+    }
+
+    #[test]
+    fn clips_to_the_image() {
+        let mut framebuffer = Framebuffer::new(4, 4, BLACK);
+        framebuffer.draw_triangle(
+            [
+                [-3.0, -3.0, 0.0, 1.0],
+                [3.0, -3.0, 0.0, 1.0],
+                [0.0, 3.0, 0.0, 1.0],
+            ],
+            RED,
+        );
+
+        assert_eq!(framebuffer.into_image().get(3, 3), RED);
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod board_render {
+    use state::{board::Board, coordinate::Coord, piece::Color, square::*};
+
+    use scene::{camera::OrbitCamera, math::Vec3, world::square_center};
+
+    use crate::{
+        geometry::{BLACK_PIECE, WHITE_PIECE},
+        image::{Image, Rgb},
+        Renderer,
+    };
+
+    const WIDTH: usize = 160;
+    const HEIGHT: usize = 120;
+
+    fn pixel(renderer: &Renderer, image: &Image, point: Vec3) -> Rgb {
+        let (x, y) = renderer.project(point).expect("in front of the camera");
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        image.get(x as usize, y as usize)
+    }
+
+    /// A point a little way up the piece standing on `coord`.
+    fn on_piece(coord: Coord) -> Vec3 {
+        square_center(coord) + Vec3::new(0.0, 0.3, 0.0)
+    }
+
+    fn brightness(color: Rgb) -> u32 {
+        color.iter().map(|channel| u32::from(*channel)).sum()
+    }
+
+    #[test]
+    fn rendering_is_deterministic() {
+        let renderer = Renderer::new(WIDTH, HEIGHT);
+
+        assert_eq!(
+            renderer.render(&Board::standard()),
+            renderer.render(&Board::standard())
+        );
+    }
+
+    #[test]
+    fn pieces_show_in_their_colors() {
+        let renderer = Renderer::new(WIDTH, HEIGHT);
+        let image = renderer.render(&Board::standard());
+
+        let white = pixel(&renderer, &image, on_piece(E2));
+        let black = pixel(&renderer, &image, on_piece(E7));
+        assert!(brightness(white) > brightness(black));
+        assert!(white
+            .iter()
+            .zip(WHITE_PIECE)
+            .all(|(lit, full)| *lit <= full));
+        assert!(black
+            .iter()
+            .zip(BLACK_PIECE)
+            .all(|(lit, full)| *lit <= full));
+    }
+
+    #[test]
+    fn empty_squares_look_the_same_with_or_without_pieces_elsewhere() {
+        let renderer = Renderer::new(WIDTH, HEIGHT);
+        let standard = renderer.render(&Board::standard());
+        let empty = renderer.render(&Board::empty());
+
+        for coord in [D4, E5, A4, H5] {
+            let point = square_center(coord);
+            assert_eq!(
+                pixel(&renderer, &standard, point),
+                pixel(&renderer, &empty, point)
+            );
+        }
+        let on_e2 = on_piece(E2);
+        assert_ne!(
+            pixel(&renderer, &standard, on_e2),
+            pixel(&renderer, &empty, on_e2)
+        );
+    }
+
+    #[test]
+    fn light_and_dark_squares_alternate() {
+        let renderer = Renderer::new(WIDTH, HEIGHT);
+        let image = renderer.render(&Board::empty());
+
+        let d4 = pixel(&renderer, &image, square_center(D4));
+        let e4 = pixel(&renderer, &image, square_center(E4));
+        assert!(brightness(e4) > brightness(d4));
+    }
+
+    #[test]
+    fn the_camera_can_sit_behind_black() {
+        let mut renderer = Renderer::new(WIDTH, HEIGHT);
+        #[allow(clippy::cast_precision_loss)]
+        let middle = HEIGHT as f32 / 2.0;
+
+        let (_, white_king) = renderer.project(square_center(E1)).expect("visible");
+        assert!(white_king > middle);
+
+        renderer.camera = OrbitCamera::behind(Color::Black);
+        let (_, white_king) = renderer.project(square_center(E1)).expect("visible");
+        assert!(white_king < middle);
+    }
+
+    #[test]
+    fn empty_image_renders_nothing() {
+        let image = Renderer::new(0, 0).render(&Board::standard());
+
+        assert!(image.pixels.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use state::{board::Board, coordinate::Coord, piece::Move};
+
+use crate::{math::Vec3, world::square_center};
+
+/// How long a piece takes to glide to its new square.
+pub const MOVE_DURATION: Duration = Duration::from_millis(250);
+
+/// How high a knight jumps over the pieces in its way, at the middle of its move.
+pub const HOP_HEIGHT: f32 = 0.6;
+
+/// A piece moving from one square to another over time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveAnimation {
+    pub from: Coord,
+    pub to: Coord,
+    pub started_at: Duration,
+    pub duration: Duration,
+    /// How high the piece rises halfway there.
+    pub hop: f32,
+}
+
+impl MoveAnimation {
+    /// A move from `from` to `to` starting at `now`. Knight-shaped moves hop.
+    #[must_use]
+    pub fn new(from: Coord, to: Coord, now: Duration) -> Self {
+        let jump = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+        Self {
+            from,
+            to,
+            started_at: now,
+            duration: MOVE_DURATION,
+            hop: if matches!(jump, (1, 2) | (2, 1)) {
+                HOP_HEIGHT
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// How far along the move is at `now`, from 0 to 1, easing in and out.
+    #[must_use]
+    pub fn progress(&self, now: Duration) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_sub(self.started_at);
+        let t = (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0);
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// Where the piece is at `now`.
+    #[must_use]
+    pub fn position(&self, now: Duration) -> Vec3 {
+        let t = self.progress(now);
+        let height = self.hop * 4.0 * t * (1.0 - t);
+        square_center(self.from).lerp(square_center(self.to), t) + Vec3::UP * height
+    }
+
+    #[must_use]
+    pub fn is_finished(&self, now: Duration) -> bool {
+        now >= self.started_at + self.duration
+    }
+}
+
+/// The moves being animated, keyed by the square each moving piece is headed to.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Animations {
+    moves: Vec<MoveAnimation>,
+}
+
+impl Animations {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+    Starts animating `mv` at `now`, for a board the move has already been made on. Castling
+    moves both the king and the rook. A piece that moves again, or is captured, stops
+    whatever animation it had.
+    */
+    pub fn start(&mut self, mv: &Move, now: Duration) {
+        let (color, king_to, rook_from, rook_to) = match mv {
+            Move::Piece(from, to) | Move::Promotion(from, to, _) => {
+                return self.push(MoveAnimation::new(*from, *to, now));
+            }
+            Move::KingSideCastle(color) => (color, 6, 7, 5),
+            Move::QueenSideCastle(color) => (color, 2, 0, 3),
+        };
+        let rank = Board::castle_rank(*color);
+        self.push(MoveAnimation::new(
+            Coord(4, rank),
+            Coord(king_to, rank),
+            now,
+        ));
+        self.push(MoveAnimation::new(
+            Coord(rook_from, rank),
+            Coord(rook_to, rank),
+            now,
+        ));
+    }
+
+    fn push(&mut self, animation: MoveAnimation) {
+        self.moves
+            .retain(|other| other.to != animation.from && other.to != animation.to);
+        self.moves.push(animation);
+    }
+
+    /// Where the piece standing on `coord` should be drawn at `now`, or `None` if it is not
+    /// moving.
+    #[must_use]
+    pub fn position_of(&self, coord: Coord, now: Duration) -> Option<Vec3> {
+        self.moves
+            .iter()
+            .find(|animation| animation.to == coord && !animation.is_finished(now))
+            .map(|animation| animation.position(now))
+    }
+
+    /// Drops the animations that have finished by `now`.
+    pub fn prune(&mut self, now: Duration) {
+        self.moves.retain(|animation| !animation.is_finished(now));
+    }
+
+    #[must_use]
+    pub fn is_animating(&self, now: Duration) -> bool {
+        self.moves
+            .iter()
+            .any(|animation| !animation.is_finished(now))
+    }
+}
@@ -0,0 +1,149 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use state::piece::Color;
+
+use crate::{
+    math::{Mat4, Vec3},
+    picking::Ray,
+};
+
+/// Degrees above the board the default camera looks down from.
+pub const DEFAULT_PITCH: f32 = 55.0;
+pub const DEFAULT_DISTANCE: f32 = 13.0;
+
+/// The lowest pitch in radians, a little above level with the board.
+const MIN_PITCH: f32 = 0.05;
+/// The steepest pitch in radians, a little short of looking straight down.
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+/// The closest the camera gets to the center of the board, keeping it outside the scene.
+pub const MIN_DISTANCE: f32 = 7.0;
+pub const MAX_DISTANCE: f32 = 40.0;
+
+/**
+A perspective camera circling a target, the center of the board unless moved. With a `yaw` of
+zero it looks from behind White, and moves round toward the h-file as `yaw` grows. `pitch` is
+how far above the board it is, from just above level with it to just short of straight down,
+where there would be no telling which way is up. Both are in radians.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCamera {
+    pub target: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    /// The vertical field of view in radians.
+    pub fov_y: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl OrbitCamera {
+    /// A camera circling the center of the board `distance` away, with `pitch` and `distance`
+    /// clamped to what [`OrbitCamera::rotate`] and [`OrbitCamera::zoom`] allow.
+    #[must_use]
+    pub fn new(yaw: f32, pitch: f32, distance: f32) -> Self {
+        Self {
+            target: Vec3::ZERO,
+            yaw,
+            pitch: pitch.clamp(MIN_PITCH, MAX_PITCH),
+            distance: distance.clamp(MIN_DISTANCE, MAX_DISTANCE),
+            fov_y: 45f32.to_radians(),
+            near: 0.1,
+            far: 100.0,
+        }
+    }
+
+    /// The default camera, from behind `color`'s pieces.
+    #[must_use]
+    pub fn behind(color: Color) -> Self {
+        let yaw = match color {
+            Color::White => 0.0,
+            Color::Black => PI,
+        };
+        Self::new(yaw, DEFAULT_PITCH.to_radians(), DEFAULT_DISTANCE)
+    }
+
+    /// Turns the camera around the target and tilts it up or down, in radians.
+    pub fn rotate(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = (self.yaw + yaw).rem_euclid(2.0 * PI);
+        self.pitch = (self.pitch + pitch).clamp(MIN_PITCH, MAX_PITCH);
+    }
+
+    /// Moves the camera `factor` times as far from the target, so below 1 zooms in.
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).clamp(MIN_DISTANCE, MAX_DISTANCE);
+    }
+
+    /// Where the camera is in world space.
+    #[must_use]
+    pub fn eye(&self) -> Vec3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        self.target
+            + Vec3::new(
+                self.distance * cos_pitch * sin_yaw,
+                self.distance * sin_pitch,
+                self.distance * cos_pitch * cos_yaw,
+            )
+    }
+
+    /// World space to clip space for an image `aspect` times wider than it is high.
+    #[must_use]
+    pub fn view_projection(&self, aspect: f32) -> Mat4 {
+        let view = Mat4::look_at(self.eye(), self.target, Vec3::UP);
+        let projection = Mat4::perspective(self.fov_y, aspect, self.near, self.far);
+        projection * view
+    }
+
+    /// Where `point` lands in a `width` by `height` image in pixels from the top left, or
+    /// `None` if it is behind the camera.
+    #[must_use]
+    pub fn project(&self, point: Vec3, width: f32, height: f32) -> Option<(f32, f32)> {
+        let clip = self
+            .view_projection(width / height.max(1.0))
+            .transform(point);
+        if clip[3] <= 0.0 {
+            return None;
+        }
+        let screen = to_screen(clip, width, height);
+        Some((screen.x, screen.y))
+    }
+
+    /// The ray from the camera through the pixel at `x`, `y` of a `width` by `height` image,
+    /// the inverse of [`OrbitCamera::project`].
+    #[must_use]
+    pub fn ray_through(&self, x: f32, y: f32, width: f32, height: f32) -> Ray {
+        let eye = self.eye();
+        let forward = (self.target - eye).normalized();
+        let right = forward.cross(Vec3::UP).normalized();
+        let up = right.cross(forward);
+
+        let half_height = (self.fov_y / 2.0).tan();
+        let half_width = half_height * width / height.max(1.0);
+        let across = (x / width.max(1.0)) * 2.0 - 1.0;
+        let down = (y / height.max(1.0)) * 2.0 - 1.0;
+        Ray {
+            origin: eye,
+            direction: (forward + right * (across * half_width) - up * (down * half_height))
+                .normalized(),
+        }
+    }
+}
+
+impl Default for OrbitCamera {
+    fn default() -> Self {
+        Self::behind(Color::White)
+    }
+}
+
+/// A point in clip space as pixels from the top left of a `width` by `height` image, with
+/// its depth from -1 to 1 kept as z.
+#[must_use]
+pub fn to_screen([x, y, z, w]: [f32; 4], width: f32, height: f32) -> Vec3 {
+    Vec3::new(
+        f32::midpoint(x / w, 1.0) * width,
+        f32::midpoint(1.0, -y / w) * height,
+        z / w,
+    )
+}
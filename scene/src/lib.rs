@@ -0,0 +1,99 @@
+pub mod animation;
+pub mod camera;
+pub mod math;
+pub mod mesh;
+pub mod picking;
+mod tests;
+pub mod world;
+
+use std::{f32::consts::PI, time::Duration};
+
+use state::{
+    board::Board,
+    coordinate::Coord,
+    piece::{Color, Piece},
+};
+
+use crate::{
+    animation::Animations,
+    math::{Mat4, Vec3},
+    mesh::{frame_mesh, piece_mesh, square_mesh, Material, MeshDescriptor},
+    world::square_center,
+};
+
+/// A mesh placed in the world.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneObject {
+    pub mesh: MeshDescriptor,
+    /// The mesh's space to world space.
+    pub transform: Mat4,
+    /// The square the object belongs to: the one a piece stands on, or the square itself.
+    pub square: Option<Coord>,
+}
+
+/// Everything there is to draw for a board at one moment, for any renderer to draw.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    pub objects: Vec<SceneObject>,
+}
+
+impl Scene {
+    /// The board and its pieces at `now`, with moving pieces partway to their squares.
+    #[must_use]
+    pub fn new(board: &Board, animations: &Animations, now: Duration) -> Self {
+        let mut objects = vec![];
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                let material = if (x + y) % 2 == 1 {
+                    Material::LightSquare
+                } else {
+                    Material::DarkSquare
+                };
+                objects.push(SceneObject {
+                    mesh: square_mesh(material),
+                    transform: Mat4::translation(square_center(coord)),
+                    square: Some(coord),
+                });
+            }
+        }
+        objects.push(SceneObject {
+            mesh: frame_mesh(),
+            transform: Mat4::IDENTITY,
+            square: None,
+        });
+
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                if let Some(piece) = board.get_piece_at(&coord) {
+                    let position = animations
+                        .position_of(coord, now)
+                        .unwrap_or_else(|| square_center(coord));
+                    objects.push(SceneObject {
+                        mesh: piece_mesh(piece),
+                        transform: piece_transform(piece, position),
+                        square: Some(coord),
+                    });
+                }
+            }
+        }
+        Self { objects }
+    }
+
+    /// The board and its pieces with nothing moving.
+    #[must_use]
+    pub fn still(board: &Board) -> Self {
+        Self::new(board, &Animations::new(), Duration::ZERO)
+    }
+}
+
+/// Places a piece's mesh standing at `position`, turning Black's knights to face White.
+#[must_use]
+pub fn piece_transform(piece: &Piece, position: Vec3) -> Mat4 {
+    let placed = Mat4::translation(position);
+    match piece {
+        Piece::Knight(Color::Black, _) => placed * Mat4::rotation_y(PI),
+        _ => placed,
+    }
+}
@@ -0,0 +1,200 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A point or direction in world space. The board lies in the XZ plane with +Y up.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3::new(0.0, 0.0, 0.0);
+    pub const UP: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+    #[must_use]
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn dot(self, other: Vec3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[must_use]
+    pub fn cross(self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    #[must_use]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// The point `t` of the way from `self` to `other`.
+    #[must_use]
+    pub fn lerp(self, other: Vec3, t: f32) -> Vec3 {
+        self + (other - self) * t
+    }
+
+    /// The vector scaled to length 1, or zero if it has no length.
+    #[must_use]
+    pub fn normalized(self) -> Vec3 {
+        let length = self.length();
+        if length == 0.0 {
+            Vec3::ZERO
+        } else {
+            self * (1.0 / length)
+        }
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, scale: f32) -> Vec3 {
+        Vec3::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// A 4x4 matrix in row-major order, applied to column vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4(pub [[f32; 4]; 4]);
+
+impl Mat4 {
+    pub const IDENTITY: Mat4 = Mat4([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    #[must_use]
+    pub fn translation(offset: Vec3) -> Mat4 {
+        Mat4([
+            [1.0, 0.0, 0.0, offset.x],
+            [0.0, 1.0, 0.0, offset.y],
+            [0.0, 0.0, 1.0, offset.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    #[must_use]
+    pub fn scale(factor: f32) -> Mat4 {
+        Mat4([
+            [factor, 0.0, 0.0, 0.0],
+            [0.0, factor, 0.0, 0.0],
+            [0.0, 0.0, factor, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// A rotation of `angle` radians about the Y axis, counterclockwise seen from above.
+    #[must_use]
+    pub fn rotation_y(angle: f32) -> Mat4 {
+        let (sin, cos) = angle.sin_cos();
+        Mat4([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// The view matrix of a camera at `eye` looking at `target`, with the camera looking down
+    /// its -Z axis.
+    #[must_use]
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Mat4 {
+        let forward = (target - eye).normalized();
+        let side = forward.cross(up).normalized();
+        let up = side.cross(forward);
+        Mat4([
+            [side.x, side.y, side.z, -side.dot(eye)],
+            [up.x, up.y, up.z, -up.dot(eye)],
+            [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /**
+    A perspective projection with a vertical field of view of `fov_y` radians. Points between
+    the `near` and `far` planes end up with a depth from -1 to 1 after dividing by w.
+    */
+    #[must_use]
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let focal = 1.0 / (fov_y / 2.0).tan();
+        Mat4([
+            [focal / aspect, 0.0, 0.0, 0.0],
+            [0.0, focal, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    /// `point` with w = 1, transformed into homogeneous coordinates.
+    #[must_use]
+    pub fn transform(&self, point: Vec3) -> [f32; 4] {
+        let row = |row: [f32; 4]| row[0] * point.x + row[1] * point.y + row[2] * point.z + row[3];
+        [
+            row(self.0[0]),
+            row(self.0[1]),
+            row(self.0[2]),
+            row(self.0[3]),
+        ]
+    }
+
+    /// `point` transformed and divided by w.
+    #[must_use]
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        let [x, y, z, w] = self.transform(point);
+        Vec3::new(x / w, y / w, z / w)
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, other: Mat4) -> Mat4 {
+        let mut product = [[0.0; 4]; 4];
+        for (row, cells) in product.iter_mut().enumerate() {
+            for (column, cell) in cells.iter_mut().enumerate() {
+                *cell = (0..4).map(|i| self.0[row][i] * other.0[i][column]).sum();
+            }
+        }
+        Mat4(product)
+    }
+}
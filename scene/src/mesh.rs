@@ -0,0 +1,197 @@
+use state::piece::{Color, Piece};
+
+use crate::math::Vec3;
+
+/// How thick the squares are, below the board's surface at y = 0.
+pub const SQUARE_THICKNESS: f32 = 0.1;
+
+/// How far the frame reaches past the squares.
+pub const FRAME_WIDTH: f32 = 0.35;
+
+/// A solid a mesh is built from, in the mesh's own space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /**
+    A surface of revolution about the Y axis, like a piece turned on a lathe. The profile is
+    the outline as `(radius, height)` pairs from the bottom up, closed off with a flat cap at
+    either end wherever the radius is not zero.
+    */
+    Lathe(&'static [(f32, f32)]),
+    /// An axis-aligned box between two opposite corners.
+    Cuboid(Vec3, Vec3),
+}
+
+impl Shape {
+    /// The height of the highest point of the shape.
+    #[must_use]
+    pub fn top(&self) -> f32 {
+        match self {
+            Shape::Lathe(profile) => profile
+                .iter()
+                .map(|(_, height)| *height)
+                .fold(f32::NEG_INFINITY, f32::max),
+            Shape::Cuboid(min, max) => min.y.max(max.y),
+        }
+    }
+}
+
+/// What a surface is made of, for the renderer to pick a color or texture for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Material {
+    Piece(Color),
+    LightSquare,
+    DarkSquare,
+    Frame,
+}
+
+/// A mesh to be built by a renderer: the shapes it is made of and what they are made of.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshDescriptor {
+    pub shapes: Vec<Shape>,
+    pub material: Material,
+}
+
+impl MeshDescriptor {
+    /// The height of the highest point of the mesh.
+    #[must_use]
+    pub fn top(&self) -> f32 {
+        self.shapes
+            .iter()
+            .map(Shape::top)
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+}
+
+const PAWN: &[(f32, f32)] = &[
+    (0.3, 0.0),
+    (0.3, 0.07),
+    (0.22, 0.1),
+    (0.12, 0.2),
+    (0.09, 0.32),
+    (0.14, 0.36),
+    (0.15, 0.42),
+    (0.12, 0.49),
+    (0.06, 0.53),
+    (0.0, 0.55),
+];
+
+const ROOK: &[(f32, f32)] = &[
+    (0.32, 0.0),
+    (0.32, 0.08),
+    (0.25, 0.12),
+    (0.2, 0.2),
+    (0.2, 0.5),
+    (0.26, 0.55),
+    (0.26, 0.7),
+    (0.0, 0.7),
+];
+
+const BISHOP: &[(f32, f32)] = &[
+    (0.32, 0.0),
+    (0.32, 0.08),
+    (0.24, 0.12),
+    (0.14, 0.25),
+    (0.1, 0.5),
+    (0.18, 0.55),
+    (0.12, 0.6),
+    (0.15, 0.68),
+    (0.12, 0.78),
+    (0.04, 0.84),
+    (0.0, 0.86),
+];
+
+const QUEEN: &[(f32, f32)] = &[
+    (0.34, 0.0),
+    (0.34, 0.08),
+    (0.26, 0.13),
+    (0.15, 0.3),
+    (0.11, 0.65),
+    (0.22, 0.72),
+    (0.16, 0.76),
+    (0.24, 0.92),
+    (0.1, 0.95),
+    (0.07, 1.0),
+    (0.0, 1.02),
+];
+
+const KING: &[(f32, f32)] = &[
+    (0.34, 0.0),
+    (0.34, 0.08),
+    (0.26, 0.13),
+    (0.15, 0.3),
+    (0.12, 0.7),
+    (0.22, 0.76),
+    (0.16, 0.8),
+    (0.2, 0.95),
+    (0.0, 0.96),
+];
+
+const KNIGHT_BASE: &[(f32, f32)] = &[
+    (0.32, 0.0),
+    (0.32, 0.08),
+    (0.26, 0.12),
+    (0.2, 0.2),
+    (0.18, 0.3),
+    (0.0, 0.3),
+];
+
+/**
+The mesh of a piece standing at the origin, no wider than a square. Knights face -Z, which is
+toward Black for a White knight; [`piece_transform`](crate::piece_transform) turns Black's
+around.
+*/
+#[must_use]
+pub fn piece_mesh(piece: &Piece) -> MeshDescriptor {
+    let cuboid = |min: [f32; 3], max: [f32; 3]| {
+        Shape::Cuboid(
+            Vec3::new(min[0], min[1], min[2]),
+            Vec3::new(max[0], max[1], max[2]),
+        )
+    };
+    let shapes = match piece {
+        Piece::Pawn(_, _) => vec![Shape::Lathe(PAWN)],
+        Piece::Rook(_, _) => vec![Shape::Lathe(ROOK)],
+        Piece::Bishop(_, _) => vec![Shape::Lathe(BISHOP)],
+        Piece::Queen(_, _) => vec![Shape::Lathe(QUEEN)],
+        Piece::King(_, _) => vec![
+            Shape::Lathe(KING),
+            cuboid([-0.03, 0.96, -0.03], [0.03, 1.18, 0.03]),
+            cuboid([-0.09, 1.05, -0.03], [0.09, 1.11, 0.03]),
+        ],
+        Piece::Knight(_, _) => vec![
+            Shape::Lathe(KNIGHT_BASE),
+            cuboid([-0.1, 0.3, -0.05], [0.1, 0.62, 0.15]),
+            cuboid([-0.09, 0.5, -0.25], [0.09, 0.7, 0.1]),
+            cuboid([-0.07, 0.7, 0.02], [0.07, 0.78, 0.08]),
+        ],
+    };
+    MeshDescriptor {
+        shapes,
+        material: Material::Piece(piece.get_color()),
+    }
+}
+
+/// A square centered on the origin, with its top face at y = 0.
+#[must_use]
+pub fn square_mesh(material: Material) -> MeshDescriptor {
+    MeshDescriptor {
+        shapes: vec![Shape::Cuboid(
+            Vec3::new(-0.5, -SQUARE_THICKNESS, -0.5),
+            Vec3::new(0.5, 0.0, 0.5),
+        )],
+        material,
+    }
+}
+
+/// The frame the squares sit in, already in place around the origin.
+#[must_use]
+pub fn frame_mesh() -> MeshDescriptor {
+    let edge = crate::world::BOARD_HALF_WIDTH + FRAME_WIDTH;
+    MeshDescriptor {
+        shapes: vec![Shape::Cuboid(
+            Vec3::new(-edge, -SQUARE_THICKNESS - 0.05, -edge),
+            Vec3::new(edge, -0.01, edge),
+        )],
+        material: Material::Frame,
+    }
+}
@@ -0,0 +1,91 @@
+use state::coordinate::Coord;
+
+use crate::{math::Vec3, mesh::Material, world::square_at, Scene};
+
+/// How far from its axis a piece can be clicked, a little under half a square.
+pub const PIECE_RADIUS: f32 = 0.35;
+
+/// A half-line in world space, such as the one under the mouse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    /// The direction the ray travels in, of length 1.
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// The point `distance` along the ray.
+    #[must_use]
+    pub fn at(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Where the ray meets the board's surface, or `None` if it never does.
+    #[must_use]
+    pub fn hit_board(&self) -> Option<Vec3> {
+        if self.direction.y.abs() < f32::EPSILON {
+            return None;
+        }
+        let distance = -self.origin.y / self.direction.y;
+        (distance >= 0.0).then(|| self.at(distance))
+    }
+
+    /**
+    How far along the ray it first meets an upright cylinder standing on `base`, or `None` if
+    it misses. The cylinder is open at the bottom, which rests on the board.
+    */
+    #[must_use]
+    pub fn hit_cylinder(&self, base: Vec3, radius: f32, height: f32) -> Option<f32> {
+        let top = base.y + height;
+        let offset = self.origin - base;
+        let mut hits = vec![];
+
+        let a = self.direction.x.powi(2) + self.direction.z.powi(2);
+        let b = 2.0 * (offset.x * self.direction.x + offset.z * self.direction.z);
+        let c = offset.x.powi(2) + offset.z.powi(2) - radius.powi(2);
+        let discriminant = b * b - 4.0 * a * c;
+        if a > f32::EPSILON && discriminant >= 0.0 {
+            let root = discriminant.sqrt();
+            hits.extend([(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]);
+        }
+        if self.direction.y.abs() > f32::EPSILON {
+            hits.push((top - self.origin.y) / self.direction.y);
+        }
+
+        hits.into_iter()
+            .filter(|distance| *distance >= 0.0)
+            .filter(|distance| {
+                let point = self.at(*distance) - base;
+                (-1e-4..=height + 1e-4).contains(&point.y)
+                    && point.x.powi(2) + point.z.powi(2) <= radius.powi(2) + 1e-4
+            })
+            .min_by(f32::total_cmp)
+    }
+}
+
+/// The square under the ray, ignoring pieces.
+#[must_use]
+pub fn pick_square(ray: &Ray) -> Option<Coord> {
+    ray.hit_board().and_then(square_at)
+}
+
+/**
+The square the ray points at in `scene`. A piece counts for its own square wherever it is
+drawn, so a piece standing in front of another square, or still gliding off its old one, is
+picked rather than the square behind it. Otherwise this is the square under the ray.
+*/
+#[must_use]
+pub fn pick(ray: &Ray, scene: &Scene) -> Option<Coord> {
+    scene
+        .objects
+        .iter()
+        .filter(|object| matches!(object.mesh.material, Material::Piece(_)))
+        .filter_map(|object| {
+            let base = object.transform.transform_point(Vec3::ZERO);
+            let distance = ray.hit_cylinder(base, PIECE_RADIUS, object.mesh.top())?;
+            Some((distance, object.square?))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, square)| square)
+        .or_else(|| pick_square(ray))
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod moves {
+    use std::time::Duration;
+
+    use state::{piece::Color, piece::Move, square::*};
+
+    use crate::{
+        animation::{Animations, MoveAnimation, HOP_HEIGHT, MOVE_DURATION},
+        math::Vec3,
+        world::square_center,
+    };
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn assert_near(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).length() < 1e-4,
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    #[test]
+    fn moves_run_from_square_to_square() {
+        let start = ms(1_000);
+        let animation = MoveAnimation::new(E2, E4, start);
+
+        assert_near(animation.position(start), square_center(E2));
+        assert_near(animation.position(ms(500)), square_center(E2));
+        assert_near(
+            animation.position(start + MOVE_DURATION / 2),
+            square_center(E3),
+        );
+        assert_near(animation.position(start + MOVE_DURATION), square_center(E4));
+        assert_near(animation.position(ms(5_000)), square_center(E4));
+    }
+
+    #[test]
+    fn progress_eases_in_and_out() {
+        let animation = MoveAnimation::new(A1, A8, Duration::ZERO);
+        let early = animation.progress(MOVE_DURATION / 10);
+        let late = animation.progress(MOVE_DURATION * 9 / 10);
+
+        assert!(early < 0.1);
+        assert!(late > 0.9);
+        assert!((animation.progress(MOVE_DURATION / 2) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn only_knights_hop() {
+        let knight = MoveAnimation::new(G1, F3, Duration::ZERO);
+        let rook = MoveAnimation::new(A1, A3, Duration::ZERO);
+        let halfway = MOVE_DURATION / 2;
+
+        assert!((knight.position(halfway).y - HOP_HEIGHT).abs() < 1e-4);
+        assert!(rook.position(halfway).y.abs() < f32::EPSILON);
+        assert!(knight.position(MOVE_DURATION).y.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn pieces_are_drawn_on_their_square_once_done() {
+        let mut animations = Animations::new();
+        animations.start(&Move::Piece(D2, D4), Duration::ZERO);
+
+        assert!(animations.is_animating(MOVE_DURATION / 2));
+        assert!(animations.position_of(D4, MOVE_DURATION / 2).is_some());
+        assert_eq!(animations.position_of(D2, MOVE_DURATION / 2), None);
+        assert_eq!(animations.position_of(D4, MOVE_DURATION), None);
+        assert!(!animations.is_animating(MOVE_DURATION));
+    }
+
+    #[test]
+    fn castling_moves_king_and_rook() {
+        let mut animations = Animations::new();
+        animations.start(&Move::QueenSideCastle(Color::Black), Duration::ZERO);
+        let halfway = MOVE_DURATION / 2;
+
+        assert_near(
+            animations.position_of(C8, halfway).expect("king moving"),
+            square_center(D8),
+        );
+        assert_near(
+            animations.position_of(D8, halfway).expect("rook moving"),
+            square_center(A8).lerp(square_center(D8), 0.5),
+        );
+    }
+
+    #[test]
+    fn capturing_a_moving_piece_ends_its_animation() {
+        let mut animations = Animations::new();
+        animations.start(&Move::Piece(B1, C3), Duration::ZERO);
+        animations.start(&Move::Piece(D4, C3), ms(100));
+
+        assert_near(
+            animations.position_of(C3, ms(100)).expect("moving"),
+            square_center(D4),
+        );
+    }
+
+    #[test]
+    fn pruning_drops_finished_moves() {
+        let mut animations = Animations::new();
+        animations.start(&Move::Piece(A2, A3), Duration::ZERO);
+        animations.start(&Move::Piece(H7, H5), ms(200));
+
+        animations.prune(ms(300));
+        assert!(animations.is_animating(ms(300)));
+        animations.prune(ms(450));
+        assert_eq!(animations, Animations::new());
+    }
+}
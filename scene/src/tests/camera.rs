@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod orbit {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use state::{piece::Color, square::*};
+
+    use crate::{
+        camera::{OrbitCamera, MAX_DISTANCE, MIN_DISTANCE},
+        math::Vec3,
+        world::square_center,
+    };
+
+    const WIDTH: f32 = 160.0;
+    const HEIGHT: f32 = 120.0;
+
+    #[test]
+    fn default_camera_looks_from_behind_white() {
+        let eye = OrbitCamera::default().eye();
+
+        assert!(eye.z > 0.0 && eye.y > 0.0);
+        assert!(eye.x.abs() < 1e-4);
+    }
+
+    #[test]
+    fn the_camera_can_sit_behind_black() {
+        let (_, white) = OrbitCamera::behind(Color::White)
+            .project(square_center(E1), WIDTH, HEIGHT)
+            .expect("visible");
+        let (_, black) = OrbitCamera::behind(Color::Black)
+            .project(square_center(E1), WIDTH, HEIGHT)
+            .expect("visible");
+
+        assert!(white > HEIGHT / 2.0);
+        assert!(black < HEIGHT / 2.0);
+    }
+
+    #[test]
+    fn the_target_is_in_the_middle_of_the_image() {
+        let (x, y) = OrbitCamera::default()
+            .project(Vec3::ZERO, WIDTH, HEIGHT)
+            .expect("visible");
+
+        assert!((x - WIDTH / 2.0).abs() < 1e-3);
+        assert!((y - HEIGHT / 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn rotating_keeps_the_distance_and_stays_above_the_board() {
+        let mut camera = OrbitCamera::default();
+        camera.rotate(1.0, -PI);
+
+        assert!((camera.eye().length() - camera.distance).abs() < 1e-3);
+        assert!(camera.eye().y > 0.0);
+
+        camera.rotate(0.0, 2.0 * PI);
+        assert!(camera.pitch < FRAC_PI_2);
+    }
+
+    #[test]
+    fn rotating_all_the_way_round_comes_back() {
+        let mut camera = OrbitCamera::default();
+        let eye = camera.eye();
+        camera.rotate(2.0 * PI, 0.0);
+
+        assert!((camera.eye() - eye).length() < 1e-3);
+    }
+
+    #[test]
+    fn zoom_is_clamped() {
+        let mut camera = OrbitCamera::default();
+        camera.zoom(0.5);
+        let closer = camera.distance;
+        camera.zoom(0.01);
+        assert!(closer < OrbitCamera::default().distance);
+        assert!((camera.distance - MIN_DISTANCE).abs() < f32::EPSILON);
+
+        camera.zoom(1000.0);
+        assert!((camera.distance - MAX_DISTANCE).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn points_behind_the_camera_do_not_project() {
+        let camera = OrbitCamera::default();
+        let behind = camera.eye() * 2.0;
+
+        assert_eq!(camera.project(behind, WIDTH, HEIGHT), None);
+    }
+
+    #[test]
+    fn rays_pass_through_what_projects_to_their_pixel() {
+        let camera = OrbitCamera::new(0.7, 0.9, 11.0);
+        for point in [Vec3::ZERO, square_center(A1), Vec3::new(2.0, 1.0, -3.0)] {
+            let (x, y) = camera.project(point, WIDTH, HEIGHT).expect("visible");
+            let ray = camera.ray_through(x, y, WIDTH, HEIGHT);
+
+            let along = (point - ray.origin).dot(ray.direction);
+            assert!((ray.at(along) - point).length() < 1e-3, "{point:?}");
+        }
+    }
+}
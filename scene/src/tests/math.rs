@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod transforms {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::math::{Mat4, Vec3};
+
+    fn assert_near(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).length() < 1e-5,
+            "{actual:?} is not {expected:?}"
+        );
+    }
+
+    #[test]
+    fn cross_product_follows_the_right_hand() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+
+        assert_near(z.cross(x), Vec3::UP);
+        assert_near(
+            Vec3::new(3.0, 4.0, 0.0).normalized(),
+            Vec3::new(0.6, 0.8, 0.0),
+        );
+        assert_near(Vec3::ZERO.normalized(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn multiplying_applies_the_right_matrix_first() {
+        let offset = Vec3::new(1.0, 2.0, 3.0);
+        let moved_then_scaled = Mat4::scale(2.0) * Mat4::translation(offset);
+
+        assert_near(
+            moved_then_scaled.transform_point(Vec3::ZERO),
+            Vec3::new(2.0, 4.0, 6.0),
+        );
+        assert_eq!(Mat4::IDENTITY * moved_then_scaled, moved_then_scaled);
+    }
+
+    #[test]
+    fn quarter_turn_about_y() {
+        let turned = Mat4::rotation_y(FRAC_PI_2).transform_point(Vec3::new(1.0, 0.0, 0.0));
+
+        assert_near(turned, Vec3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn look_at_puts_the_target_straight_ahead() {
+        let eye = Vec3::new(0.0, 3.0, 4.0);
+        let view = Mat4::look_at(eye, Vec3::ZERO, Vec3::UP);
+
+        assert_near(view.transform_point(Vec3::ZERO), Vec3::new(0.0, 0.0, -5.0));
+        assert_near(view.transform_point(eye), Vec3::ZERO);
+    }
+
+    #[test]
+    fn perspective_maps_near_and_far_to_the_depth_range() {
+        let projection = Mat4::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+
+        assert_near(
+            projection.transform_point(Vec3::new(0.0, 0.0, -1.0)),
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+        assert_near(
+            projection.transform_point(Vec3::new(0.0, 0.0, -10.0)),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+        // A 90 degree field of view reaches as far up as it is deep.
+        assert_near(
+            projection.transform_point(Vec3::new(0.0, 2.0, -2.0)),
+            Vec3::new(
+                0.0,
+                1.0,
+                projection.transform_point(Vec3::new(0.0, 0.0, -2.0)).z,
+            ),
+        );
+    }
+}
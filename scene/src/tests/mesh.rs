@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod descriptors {
+    use std::time::Duration;
+
+    use state::{
+        board::Board,
+        piece::{Color, Piece},
+        square::*,
+    };
+
+    use crate::{
+        math::Vec3,
+        mesh::{piece_mesh, Material, Shape},
+        world::square_center,
+        Scene,
+    };
+
+    fn all_pieces(color: Color) -> [Piece; 6] {
+        let state = Duration::ZERO.into();
+        [
+            Piece::Pawn(color, state),
+            Piece::Knight(color, state),
+            Piece::Bishop(color, state),
+            Piece::Rook(color, state),
+            Piece::Queen(color, state),
+            Piece::King(color, state),
+        ]
+    }
+
+    #[test]
+    fn kings_are_tallest_and_pawns_shortest() {
+        let heights = all_pieces(Color::White).map(|piece| piece_mesh(&piece).top());
+
+        let tallest = heights.iter().copied().fold(0.0, f32::max);
+        let shortest = heights.iter().copied().fold(f32::INFINITY, f32::min);
+        assert!((heights[5] - tallest).abs() < f32::EPSILON);
+        assert!((heights[0] - shortest).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn lathe_profiles_stay_within_a_square() {
+        for piece in all_pieces(Color::White) {
+            for shape in piece_mesh(&piece).shapes {
+                if let Shape::Lathe(profile) = shape {
+                    assert!(profile.iter().all(|(radius, _)| *radius <= 0.5));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pieces_are_made_of_their_color() {
+        for color in [Color::White, Color::Black] {
+            for piece in all_pieces(color) {
+                assert_eq!(piece_mesh(&piece).material, Material::Piece(color));
+            }
+        }
+    }
+
+    #[test]
+    fn board_has_sixty_four_squares_and_a_frame() {
+        let scene = Scene::still(&Board::empty());
+        let count = |material| {
+            scene
+                .objects
+                .iter()
+                .filter(|object| object.mesh.material == material)
+                .count()
+        };
+
+        assert_eq!(scene.objects.len(), 65);
+        assert_eq!(count(Material::LightSquare), 32);
+        assert_eq!(count(Material::DarkSquare), 32);
+        assert_eq!(count(Material::Frame), 1);
+    }
+
+    #[test]
+    fn pieces_stand_on_their_squares() {
+        let scene = Scene::still(&Board::standard());
+        let pieces: Vec<_> = scene
+            .objects
+            .iter()
+            .filter(|object| matches!(object.mesh.material, Material::Piece(_)))
+            .collect();
+
+        assert_eq!(pieces.len(), 32);
+        let king = pieces
+            .iter()
+            .find(|object| object.square == Some(E1))
+            .expect("a king on e1");
+        assert_eq!(
+            king.transform.transform_point(Vec3::ZERO),
+            square_center(E1)
+        );
+    }
+
+    #[test]
+    fn black_knights_face_the_other_way() {
+        let scene = Scene::still(&Board::standard());
+        let nose = |square| {
+            let knight = scene
+                .objects
+                .iter()
+                .rev()
+                .find(|object| object.square == Some(square))
+                .expect("a knight");
+            knight.transform.transform_point(Vec3::new(0.0, 0.0, -1.0)) - square_center(square)
+        };
+
+        assert!(nose(G1).z < 0.0);
+        assert!(nose(G8).z > 0.0);
+    }
+}
@@ -0,0 +1,6 @@
+mod animation;
+mod camera;
+mod math;
+mod mesh;
+mod picking;
+mod world;
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod mouse_picking {
+    use std::time::Duration;
+
+    use state::{board::Board, coordinate::Coord, piece::Move, square::*};
+
+    use crate::{
+        animation::Animations,
+        camera::OrbitCamera,
+        math::Vec3,
+        picking::{pick, pick_square, Ray},
+        world::square_center,
+        Scene,
+    };
+
+    const WIDTH: f32 = 160.0;
+    const HEIGHT: f32 = 120.0;
+
+    fn straight_down(point: Vec3) -> Ray {
+        Ray {
+            origin: point + Vec3::new(0.0, 10.0, 0.0),
+            direction: Vec3::new(0.0, -1.0, 0.0),
+        }
+    }
+
+    #[test]
+    fn clicking_a_square_picks_it_from_any_angle() {
+        for camera in [
+            OrbitCamera::default(),
+            OrbitCamera::new(2.3, 0.4, 9.0),
+            OrbitCamera::new(-1.0, 1.5, 20.0),
+        ] {
+            for y in 0..8 {
+                for x in 0..8 {
+                    let coord = Coord(x, y);
+                    let (px, py) = camera
+                        .project(square_center(coord), WIDTH, HEIGHT)
+                        .expect("visible");
+                    let ray = camera.ray_through(px, py, WIDTH, HEIGHT);
+                    assert_eq!(pick_square(&ray), Some(coord), "{camera:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rays_missing_the_board_pick_nothing() {
+        let up = Ray {
+            origin: Vec3::new(0.0, 1.0, 0.0),
+            direction: Vec3::UP,
+        };
+
+        assert_eq!(pick_square(&up), None);
+        assert_eq!(pick_square(&straight_down(Vec3::new(6.0, 0.0, 0.0))), None);
+        assert_eq!(
+            pick(&up, &Scene::still(&Board::standard())),
+            None,
+            "nothing above the board"
+        );
+    }
+
+    #[test]
+    fn a_piece_in_front_of_a_square_is_picked_instead() {
+        let scene = Scene::still(&Board::standard());
+        let camera = OrbitCamera::new(0.0, 0.3, 13.0);
+        let queen_top = square_center(D1) + Vec3::new(0.0, 0.9, 0.0);
+        let (x, y) = camera.project(queen_top, WIDTH, HEIGHT).expect("visible");
+        let ray = camera.ray_through(x, y, WIDTH, HEIGHT);
+
+        assert_ne!(pick_square(&ray), Some(D1));
+        assert_eq!(pick(&ray, &scene), Some(D1));
+    }
+
+    #[test]
+    fn moving_pieces_are_picked_where_they_are_drawn() {
+        let mut board = Board::standard();
+        let mv = Move::Piece(E2, E4);
+        board.process_move(mv);
+        let mut animations = Animations::new();
+        animations.start(&mv, Duration::ZERO);
+        let halfway = square_center(E2).lerp(square_center(E4), 0.5);
+
+        let scene = Scene::new(&board, &animations, Duration::from_millis(125));
+        assert_eq!(pick(&straight_down(halfway), &scene), Some(E4));
+
+        let scene = Scene::still(&board);
+        assert_eq!(pick(&straight_down(halfway), &scene), Some(E3));
+    }
+
+    #[test]
+    fn cylinders_are_hit_on_the_side_or_the_top() {
+        let side = Ray {
+            origin: Vec3::new(-5.0, 0.5, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+        };
+        let distance = side
+            .hit_cylinder(Vec3::ZERO, 0.5, 1.0)
+            .expect("hits the side");
+        assert!((distance - 4.5).abs() < 1e-4);
+
+        let top = straight_down(Vec3::new(0.2, 0.0, 0.0));
+        let distance = top
+            .hit_cylinder(Vec3::ZERO, 0.5, 1.0)
+            .expect("hits the top");
+        assert!((distance - 9.0).abs() < 1e-4);
+
+        let over = Ray {
+            origin: Vec3::new(-5.0, 1.5, 0.0),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+        };
+        assert_eq!(over.hit_cylinder(Vec3::ZERO, 0.5, 1.0), None);
+    }
+}
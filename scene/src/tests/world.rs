@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod board_space {
+    use state::{coordinate::Coord, square::*};
+
+    use crate::{
+        math::Vec3,
+        world::{square_at, square_center},
+    };
+
+    #[test]
+    fn squares_are_laid_out_from_whites_side() {
+        assert_eq!(square_center(A1), Vec3::new(-3.5, 0.0, 3.5));
+        assert_eq!(square_center(H8), Vec3::new(3.5, 0.0, -3.5));
+        assert_eq!(square_center(E4), Vec3::new(0.5, 0.0, 0.5));
+    }
+
+    #[test]
+    fn every_square_center_maps_back_to_its_square() {
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                assert_eq!(square_at(square_center(coord)), Some(coord));
+            }
+        }
+    }
+
+    #[test]
+    fn points_anywhere_on_a_square_belong_to_it() {
+        let center = square_center(E4);
+
+        assert_eq!(square_at(center + Vec3::new(0.49, 3.0, -0.49)), Some(E4));
+        assert_eq!(square_at(center + Vec3::new(-0.49, -1.0, 0.49)), Some(E4));
+        assert_eq!(square_at(center + Vec3::new(0.51, 0.0, 0.0)), Some(F4));
+    }
+
+    #[test]
+    fn points_off_the_board_have_no_square() {
+        assert_eq!(square_at(Vec3::new(4.1, 0.0, 0.0)), None);
+        assert_eq!(square_at(Vec3::new(0.0, 0.0, -4.1)), None);
+        assert_eq!(square_at(Vec3::new(-4.01, 0.0, 4.01)), None);
+    }
+}
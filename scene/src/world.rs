@@ -0,0 +1,28 @@
+use state::coordinate::Coord;
+
+use crate::math::Vec3;
+
+/// How far the board reaches from its center along X and Z.
+pub const BOARD_HALF_WIDTH: f32 = 4.0;
+
+/**
+The center of a square's top face in world space. Squares are one unit wide and the board
+is centered on the origin with its surface at y = 0, files along +X and White's back rank at
++Z.
+*/
+#[must_use]
+pub fn square_center(coord: Coord) -> Vec3 {
+    Vec3::new(f32::from(coord.0) - 3.5, 0.0, 3.5 - f32::from(coord.1))
+}
+
+/// The square under `point`, ignoring its height, or `None` if it is off the board.
+#[must_use]
+pub fn square_at(point: Vec3) -> Option<Coord> {
+    let file = (point.x + BOARD_HALF_WIDTH).floor();
+    let rank = (BOARD_HALF_WIDTH - point.z).floor();
+    if !(0.0..8.0).contains(&file) || !(0.0..8.0).contains(&rank) {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    Some(Coord(file as i8, rank as i8))
+}
@@ -0,0 +1,20 @@
+use std::net::SocketAddr;
+
+use crate::world::World;
+use network::unmarshal::Unmarshal;
+
+use super::handler::Handler;
+
+pub(crate) struct SnapshotAckHandler;
+
+impl Handler for SnapshotAckHandler {
+    fn handle(player: SocketAddr, msg: &[u8], world: &mut World) {
+        let Some(position) = Unmarshal::snapshot_ack(msg) else {
+            println!("{player} sent an undecodable snapshot acknowledgement {msg:?}");
+            return;
+        };
+        if let Some(game) = world.get_game_for_player_mut(&player) {
+            game.acknowledge(player, position);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::net::SocketAddr;
+
+use crate::world::World;
+use network::{
+    marshal::Marshal,
+    rejection::{CardRejectReason, CardRejected},
+    unmarshal::Unmarshal,
+};
+
+use super::handler::Handler;
+
+pub(crate) struct CardHandler;
+
+impl Handler for CardHandler {
+    fn handle(player: SocketAddr, msg: &[u8], world: &mut World) {
+        let received_at = world.server_time();
+        let Some(play) = Unmarshal::play_card(msg) else {
+            println!("{player} sent an undecodable card play {msg:?}");
+            return;
+        };
+        let latency = world.get_latency(&player);
+        let (Ok(socket), Some(game)) = (
+            world.socket.try_clone(),
+            world.get_game_for_player_mut(&player),
+        ) else {
+            println!("could not find an active game for {player}");
+            return;
+        };
+        let reject = |reason: CardRejectReason| {
+            let rejection = CardRejected {
+                card_id: play.card_id,
+                reason,
+            };
+            let _ = socket.send_to(&Marshal::card_rejected(rejection), player);
+        };
+        let Some(color) = game.get_seat(&player) else {
+            println!("{player} is spectating and cannot play cards");
+            reject(CardRejectReason::NotInHand);
+            return;
+        };
+        if game.board.winner().is_some() {
+            println!("{player} tried to play a card after the game ended");
+            reject(CardRejectReason::GameOver);
+            return;
+        }
+
+        game.sync_clock(game.issued_at(received_at, latency));
+        let result = game.play_card(color, &play);
+        game.sync_clock(received_at);
+        match result {
+            Ok(card_play) => {
+                println!("{player} played {}", card_play.kind);
+                let bytes = Marshal::card_played(&card_play);
+                let position = game.record_position();
+                game.get_players().for_each(|participant| {
+                    let _ = socket.send_to(&bytes, participant);
+                    let _ = socket.send_to(&game.snapshot_for(participant, position), participant);
+                });
+            }
+            Err(error) => {
+                println!("{player} tried to play card {}: {error}", play.card_id);
+                reject(error.into());
+            }
+        }
+        let _ = socket.send_to(&Marshal::hand(&game.hand_update(color)), player);
+    }
+}
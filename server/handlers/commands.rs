@@ -1,31 +1,50 @@
 use super::handler::Handler;
 use crate::world::World;
-use network::{game_command::GameCmd, marshal::Marshal};
+use network::{game_command::GameCmd, marshal::Marshal, unmarshal::Unmarshal};
 use std::net::SocketAddr;
 
 #[derive(Debug)]
 pub struct CommandHandler;
 
 impl Handler for CommandHandler {
-    fn handle(player: SocketAddr, msg: [u8; 4], world: &mut World) {
-        let cmd: GameCmd = msg.into();
+    fn handle(player: SocketAddr, msg: &[u8], world: &mut World) {
+        let Some((command, _)) = Unmarshal::sequenced(msg) else {
+            println!("{player} sent a truncated command");
+            return;
+        };
+        let Some(cmd) = Unmarshal::game_command(command) else {
+            println!("{player} sent an undecodable command {command:?}");
+            return;
+        };
 
         match cmd {
             GameCmd::Join(game_id) => {
-                if let Some(game) = world.get_game(&game_id) {
-                    for mv in &game.move_history {
-                        let _ = world.socket.send_to(&Marshal::command(*mv), player);
-                    }
-                    println!("{player} joined {game_id}");
-                    world.add_player(player, &game_id);
+                let seat = if world.get_game(&game_id).is_some() {
+                    let seat = world.add_player(player, &game_id);
+                    println!("{player} joined {game_id} as {seat:?}");
+                    seat
                 } else {
-                    println!("{player} created {game_id}");
                     world.create_game(&game_id);
-                    world.add_player(player, &game_id);
+                    let seat = world.add_player(player, &game_id);
+                    println!("{player} created {game_id} as {seat:?}");
+                    seat
+                };
+                let now = world.server_time();
+                if let (Ok(socket), Some(game)) = (
+                    world.socket.try_clone(),
+                    world.get_game_for_player_mut(&player),
+                ) {
+                    let _ = socket.send_to(&Marshal::seat(seat), player);
+                    game.sync_clock(now);
+                    let position = game.record_position();
+                    let _ = socket.send_to(&game.snapshot_for(&player, position), player);
+                    if let Some(color) = game.get_seat(&player) {
+                        let _ = socket.send_to(&Marshal::hand(&game.hand_update(color)), player);
+                    }
                 }
             }
             GameCmd::Leave => println!("{player} is leaving their game"),
             GameCmd::Resign => println!("{player} is resigning"),
-        };
+        }
     }
 }
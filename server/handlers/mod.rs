@@ -1,3 +1,7 @@
+pub mod acks;
+pub mod cards;
 pub mod commands;
 pub mod handler;
 pub mod moves;
+pub mod ping;
+pub mod resync;
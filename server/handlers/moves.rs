@@ -1,34 +1,65 @@
-use std::net::SocketAddr;
+use std::net::{SocketAddr, UdpSocket};
 
 use crate::world::World;
-use network::unmarshal::Unmarshal;
-use state::movegen::MoveGen;
+use network::{
+    command::Sequence,
+    marshal::Marshal,
+    rejection::{MoveRejected, RejectReason},
+    unmarshal::Unmarshal,
+};
 
 use super::handler::Handler;
 
 pub(crate) struct MoveHandler;
 
 impl Handler for MoveHandler {
-    fn handle(player: SocketAddr, msg: [u8; 4], world: &mut World) {
-        let mv = Unmarshal::command(msg);
+    fn handle(player: SocketAddr, msg: &[u8], world: &mut World) {
+        let Some((command, sequence)) = Unmarshal::sequenced(msg) else {
+            println!("{player} sent a truncated move");
+            return;
+        };
+        let received_at = world.server_time();
+        let Some(mv) = Unmarshal::try_command(command) else {
+            println!("{player} sent an undecodable move {command:?}");
+            reject(&world.socket, player, RejectReason::Illegal, sequence);
+            return;
+        };
         println!("{player} is making move {mv}");
+        let latency = world.get_latency(&player);
         if let (Ok(socket), Some(game)) = (
             world.socket.try_clone(),
             world.get_game_for_player_mut(&player),
         ) {
-            if !game.is_valid_move(&mv) {
-                println!("{player} tried to make illegal move {mv}");
-                let gen = MoveGen::new(&game.board);
-                MoveGen::render_movelist(&game.board, &gen.get_possible_moves());
+            game.sync_clock(game.issued_at(received_at, latency));
+            if let Err(reason) = game.validate_move(&player, &mv) {
+                println!("{player} tried to make move {mv}: {reason}");
+                reject(&socket, player, reason, sequence);
                 return;
             }
             game.make_move(&mv);
+            game.sync_clock(received_at);
+            let hash = game.board.sync_hash();
             game.get_players().for_each(|participant| {
                 println!("sending {mv} to {participant}");
-                let _ = socket.send_to(&msg, participant);
+                let sequence = sequence.filter(|_| participant == &player);
+                let bytes = Marshal::move_broadcast(command, sequence, hash);
+                let _ = socket.send_to(&bytes, participant);
             });
         } else {
             println!("could not find an active game for {player}");
         }
     }
 }
+
+fn reject(
+    socket: &UdpSocket,
+    player: SocketAddr,
+    reason: RejectReason,
+    sequence: Option<Sequence>,
+) {
+    let rejection = Marshal::move_rejected(MoveRejected { reason });
+    let _ = match sequence {
+        Some(sequence) => socket.send_to(&Marshal::sequenced(rejection, sequence), player),
+        None => socket.send_to(&rejection, player),
+    };
+}
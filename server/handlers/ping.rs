@@ -0,0 +1,30 @@
+use std::net::SocketAddr;
+
+use crate::world::World;
+use network::{clock_sync::Pong, marshal::Marshal, unmarshal::Unmarshal};
+
+use super::handler::Handler;
+
+pub(crate) struct PingHandler;
+
+impl Handler for PingHandler {
+    fn handle(player: SocketAddr, msg: &[u8], world: &mut World) {
+        let server_received = world.server_time();
+        let Some(ping) = Unmarshal::ping(msg) else {
+            println!("{player} sent a truncated ping");
+            return;
+        };
+        world.set_latency(player, ping.rtt);
+        let now = world.server_time();
+        if let Some(game) = world.get_game_for_player_mut(&player) {
+            game.sync_clock(now);
+        }
+
+        let reply = Pong {
+            client_sent: ping.client_sent,
+            server_received,
+            server_sent: world.server_time(),
+        };
+        let _ = world.socket.send_to(&Marshal::pong(reply), player);
+    }
+}
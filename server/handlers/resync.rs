@@ -0,0 +1,26 @@
+use std::net::SocketAddr;
+
+use crate::world::World;
+use network::marshal::Marshal;
+
+use super::handler::Handler;
+
+pub(crate) struct ResyncHandler;
+
+impl Handler for ResyncHandler {
+    fn handle(player: SocketAddr, _msg: &[u8], world: &mut World) {
+        let now = world.server_time();
+        let (Ok(socket), Some(game)) = (
+            world.socket.try_clone(),
+            world.get_game_for_player_mut(&player),
+        ) else {
+            println!("could not find an active game for {player}");
+            return;
+        };
+
+        game.sync_clock(now);
+        println!("{player} is out of sync, resending {}", game.board.fen());
+        let position = game.record_position();
+        let _ = socket.send_to(&Marshal::snapshot(&game.board, position), player);
+    }
+}
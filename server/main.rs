@@ -6,14 +6,36 @@ use std::{
     thread::{self, JoinHandle},
 };
 
+use clap::Parser;
 use handlers::handler::Handler;
+use network::constants::{
+    GAME_JOIN, GAME_LEAVE, GAME_RESIGN, MOVE_KING_SIDE_CASTLE, MOVE_PIECE, MOVE_PROMOTION,
+    MOVE_QUEEN_SIDE_CASTLE, PING, PLAY_CARD, RESYNC, SNAPSHOT_ACK,
+};
+use state::{energy::EnergyRules, rules::Rules};
 use world::World;
 
+const MAX_MESSAGE_SIZE: usize = 1024;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Play games with the energy economy, paying for cards with energy.
+    #[arg(long)]
+    energy: bool,
+}
+
 fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let rules = Rules {
+        energy: args.energy.then(EnergyRules::default),
+        ..Rules::default()
+    };
+
     let socket = UdpSocket::bind("127.0.0.1:8080")?;
     let socket_clone = socket.try_clone()?;
 
-    let world = Arc::new(Mutex::new(World::new(socket)));
+    let world = Arc::new(Mutex::new(World::new(socket).with_rules(rules)));
     let command_handle = handle_commands(&world, socket_clone);
 
     let _ = command_handle.join();
@@ -28,14 +50,35 @@ fn handle_commands(
     let world = Arc::clone(world);
     thread::spawn(move || -> std::io::Result<()> {
         loop {
-            let mut msg: [u8; 4] = [0, 0, 0, 0];
-            let (_, addr) = socket.recv_from(&mut msg)?;
+            let mut buf = [0; MAX_MESSAGE_SIZE];
+            let (len, addr) = socket.recv_from(&mut buf)?;
+            let msg = &buf[..len];
+
+            if len < 4 {
+                println!("{addr} sent a truncated message");
+                continue;
+            }
 
             if let Ok(mut world) = world.lock() {
                 match msg[0] {
-                    0..=3 => handlers::moves::MoveHandler::handle(addr, msg, &mut world),
-                    _ => handlers::commands::CommandHandler::handle(addr, msg, &mut world),
-                };
+                    MOVE_PIECE
+                    | MOVE_PROMOTION
+                    | MOVE_KING_SIDE_CASTLE
+                    | MOVE_QUEEN_SIDE_CASTLE => {
+                        handlers::moves::MoveHandler::handle(addr, msg, &mut world);
+                    }
+                    GAME_JOIN | GAME_LEAVE | GAME_RESIGN => {
+                        handlers::commands::CommandHandler::handle(addr, msg, &mut world);
+                    }
+                    PING => handlers::ping::PingHandler::handle(addr, msg, &mut world),
+                    PLAY_CARD => handlers::cards::CardHandler::handle(addr, msg, &mut world),
+                    SNAPSHOT_ACK => {
+                        handlers::acks::SnapshotAckHandler::handle(addr, msg, &mut world);
+                    }
+                    RESYNC => handlers::resync::ResyncHandler::handle(addr, msg, &mut world),
+                    lead => println!("{addr} sent a message with an unknown lead byte {lead}"),
+                }
+                world.send_draws();
             }
         }
     })
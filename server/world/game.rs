@@ -1,38 +1,84 @@
 use std::{
-    collections::{hash_set::Iter, HashSet},
+    collections::{hash_set::Iter, HashMap, HashSet},
     net::SocketAddr,
+    time::Duration,
 };
 
-use state::{board::Board, movegen::MoveGen, piece::Move};
+use network::{
+    cards::HandUpdate,
+    cards::PlayCard,
+    delta::{DeltaSnapshot, Position, PositionHistory},
+    marshal::Marshal,
+    rejection::RejectReason,
+};
+use state::{
+    board::Board,
+    cards::{
+        deck::{Deck, HeldCard, STARTER_DECK},
+        CardError, CardPlay,
+    },
+    history::GameEvent,
+    movegen::MoveGen,
+    piece::{Color, Move},
+    rules::Rules,
+};
 
 #[derive(Debug)]
 pub struct Game {
     pub board: Board,
-    pub move_history: Vec<Move>,
-    pub last_processed_at: u64,
+    pub move_history: Vec<GameEvent>,
+    /// Server time the board was last brought up to date, see [`Game::sync_clock`].
+    pub last_processed_at: Duration,
 
     players: HashSet<SocketAddr>,
-}
-
-impl Default for Game {
-    fn default() -> Self {
-        Self::new()
-    }
+    white: Option<SocketAddr>,
+    black: Option<SocketAddr>,
+    white_deck: Deck,
+    black_deck: Deck,
+    pending_draws: Vec<(Color, HeldCard)>,
+    positions: PositionHistory,
+    acknowledged: HashMap<SocketAddr, Position>,
 }
 
 impl Game {
+    /// A game played under `rules`, starting at server time `now`.
     #[must_use]
-    pub fn new() -> Self {
+    pub fn new(now: Duration, rules: Rules) -> Self {
+        let board = Board::with_rules(rules);
         Self {
             players: HashSet::new(),
-            board: Board::standard(),
+            white: None,
+            black: None,
+            white_deck: Deck::new(&STARTER_DECK, board.rules),
+            black_deck: Deck::new(&STARTER_DECK, board.rules),
+            pending_draws: vec![],
+            positions: PositionHistory::new(),
+            acknowledged: HashMap::new(),
+            board,
             move_history: vec![],
-            last_processed_at: 0,
+            last_processed_at: now,
         }
     }
 
-    pub fn add_player(&mut self, player: SocketAddr) {
+    /// Adds a player to the game and seats them at the first free color.
+    /// Players joining a game with both seats taken are spectators.
+    pub fn add_player(&mut self, player: SocketAddr) -> Option<Color> {
         self.players.insert(player);
+        self.acknowledged.remove(&player);
+
+        if let Some(color) = self.get_seat(&player) {
+            return Some(color);
+        }
+
+        if self.white.is_none() {
+            self.white = Some(player);
+            Some(Color::White)
+        } else if self.black.is_none() {
+            self.black = Some(player);
+            Some(Color::Black)
+        } else {
+            None
+        }
     }
 
     #[allow(unused)]
@@ -45,32 +91,182 @@ impl Game {
         self.players.iter()
     }
 
+    #[must_use]
+    pub fn get_seat(&self, player: &SocketAddr) -> Option<Color> {
+        if self.white.as_ref() == Some(player) {
+            Some(Color::White)
+        } else if self.black.as_ref() == Some(player) {
+            Some(Color::Black)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_player(&self, color: Color) -> Option<SocketAddr> {
+        match color {
+            Color::White => self.white,
+            Color::Black => self.black,
+        }
+    }
+
+    /// Brings the board and both decks up to date with the server clock, see [`Board::advance`].
+    /// Cards drawn meanwhile are kept until [`Game::take_draws`].
+    pub fn sync_clock(&mut self, now: Duration) {
+        let elapsed = now.saturating_sub(self.last_processed_at);
+        self.board.advance(elapsed);
+        for color in [Color::White, Color::Black] {
+            let drawn = self.get_deck_mut(color).advance(elapsed);
+            self.pending_draws
+                .extend(drawn.into_iter().map(|card| (color, card)));
+        }
+        self.last_processed_at = self.last_processed_at.max(now);
+    }
+
+    /// When a move that arrived at `received_at` was most likely sent, given the sender's
+    /// latency. Moves are never placed before the last processed move.
+    #[must_use]
+    pub fn issued_at(&self, received_at: Duration, latency: Duration) -> Duration {
+        received_at
+            .saturating_sub(latency)
+            .max(self.last_processed_at)
+    }
+
     /**
-    # Panics
-    Can't really panic
+    Checks `mv` against the current board on behalf of `player`.
+    Call [`Game::sync_clock`] first so that cooldowns are current.
+
+    # Errors
+    Returns the reason the move was refused.
     */
+    pub fn validate_move(&self, player: &SocketAddr, mv: &Move) -> Result<(), RejectReason> {
+        if self.board.winner().is_some() {
+            return Err(RejectReason::GameOver);
+        }
+
+        let seat = self.get_seat(player).ok_or(RejectReason::WrongColor)?;
+        let piece = self
+            .board
+            .get_piece_for_move(mv)
+            .ok_or(RejectReason::Illegal)?;
+
+        if piece.get_color() != seat {
+            return Err(RejectReason::WrongColor);
+        }
+
+        let ready_in = piece.ready_in();
+        if !ready_in.is_zero() {
+            return Err(RejectReason::Cooldown(ready_in));
+        }
+
+        let cost = self.board.move_cost();
+        if !self.board.can_afford(seat, cost) {
+            return Err(RejectReason::NotEnoughEnergy(cost));
+        }
+
+        if !MoveGen::new(&self.board)
+            .get_possible_moves_for_color(seat)
+            .contains(mv)
+        {
+            return Err(RejectReason::Illegal);
+        }
+
+        Ok(())
+    }
+
     pub fn make_move(&mut self, mv: &Move) {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .expect("Time went backwards");
-        let diff = now - std::time::Duration::from_micros(self.last_processed_at);
-
-        for y in 0..8 {
-            for x in 0..8 {
-                if let Some(mut piece) = self.board.pieces[y][x] {
-                    let new_cd = piece.get_cooldown().saturating_sub(diff);
-                    piece.set_cooldown(new_cd);
-                    self.board.pieces[y][x] = Some(piece);
-                }
-            }
-        }
-
-        self.move_history.push(*mv);
+        if let Some(piece) = self.board.get_piece_for_move(mv) {
+            self.move_history.push(GameEvent::Move {
+                mv: *mv,
+                piece: piece.get_id(),
+            });
+        }
         self.board.process_move(*mv);
     }
 
+    /**
+    Plays a card from `color`'s hand, on the squares its targets refer to on the current board.
+    Call [`Game::sync_clock`] first so that the card cooldown is current.
+
+    # Errors
+    Returns the reason the card could not be played, in which case nothing changes.
+    */
+    pub fn play_card(&mut self, color: Color, play: &PlayCard) -> Result<CardPlay, CardError> {
+        let card = self.get_deck(color).check_play(play.card_id)?;
+        let card_play = CardPlay {
+            kind: card.kind,
+            color,
+            targets: play
+                .targets
+                .iter()
+                .map(|target| target.resolve(&self.board))
+                .collect::<Result<_, _>>()?,
+        };
+        card_play.validate(&self.board)?;
+
+        self.get_deck_mut(color).play(card.id);
+        self.move_history.push(card_play.event(&self.board));
+        self.board.process_card_play(&card_play);
+        Ok(card_play)
+    }
+
     #[must_use]
-    pub fn is_valid_move(&self, mv: &Move) -> bool {
-        MoveGen::new(&self.board).get_possible_moves().contains(mv)
+    pub fn hand_update(&self, color: Color) -> HandUpdate {
+        let deck = self.get_deck(color);
+        HandUpdate {
+            hand: deck.hand().to_vec(),
+            cooldown: deck.cooldown(),
+            until_next_draw: deck.until_next_draw(),
+        }
+    }
+
+    /// Numbers the current board so that it can be sent with [`Game::snapshot_for`].
+    pub fn record_position(&mut self) -> Position {
+        self.positions.push(self.board.clone())
+    }
+
+    /// Remembers that `player` has applied the snapshot numbered `position`.
+    pub fn acknowledge(&mut self, player: SocketAddr, position: Position) {
+        if self.positions.get(position).is_some() {
+            self.acknowledged.insert(player, position);
+        }
+    }
+
+    /// The recorded `position` encoded for `player`: a delta against the last position they
+    /// acknowledged, or a full snapshot if there is none or it is too old.
+    #[must_use]
+    pub fn snapshot_for(&self, player: &SocketAddr, position: Position) -> Vec<u8> {
+        let board = self.positions.get(position).unwrap_or(&self.board);
+        let base = self
+            .acknowledged
+            .get(player)
+            .and_then(|base| Some((*base, self.positions.get(*base)?)));
+        match base {
+            Some((base, base_board)) => Marshal::delta_snapshot(&DeltaSnapshot {
+                base,
+                position,
+                delta: base_board.diff(board),
+            }),
+            None => Marshal::snapshot(board, position),
+        }
+    }
+
+    /// Cards drawn since the last call, with the color of the hand they were drawn into.
+    pub fn take_draws(&mut self) -> Vec<(Color, HeldCard)> {
+        std::mem::take(&mut self.pending_draws)
+    }
+
+    fn get_deck(&self, color: Color) -> &Deck {
+        match color {
+            Color::White => &self.white_deck,
+            Color::Black => &self.black_deck,
+        }
+    }
+
+    fn get_deck_mut(&mut self, color: Color) -> &mut Deck {
+        match color {
+            Color::White => &mut self.white_deck,
+            Color::Black => &mut self.black_deck,
+        }
     }
 }
@@ -3,27 +3,57 @@ pub mod game;
 use std::{
     collections::HashMap,
     net::{SocketAddr, UdpSocket},
+    sync::Arc,
+    time::Duration,
 };
 
 use game::Game;
+use network::marshal::Marshal;
+use state::{
+    clock::{Clock, RealClock},
+    piece::Color,
+    rules::Rules,
+};
+
+/// Moves are back-dated by at most this much to make up for the sender's latency.
+pub const MAX_LATENCY_COMPENSATION: Duration = Duration::from_millis(150);
 
 #[derive(Debug)]
 pub struct World {
     pub socket: UdpSocket,
     games: HashMap<String, Game>,
     participants: HashMap<SocketAddr, String>,
+    latencies: HashMap<SocketAddr, Duration>,
+    clock: Arc<dyn Clock>,
+    rules: Rules,
 }
 
 impl World {
     #[must_use]
     pub fn new(socket: UdpSocket) -> Self {
+        Self::with_clock(socket, Arc::new(RealClock::new()))
+    }
+
+    /// A world that keeps time with `clock`, for cooldowns, card draws and the server clock.
+    #[must_use]
+    pub fn with_clock(socket: UdpSocket, clock: Arc<dyn Clock>) -> Self {
         Self {
             games: HashMap::new(),
             participants: HashMap::new(),
+            latencies: HashMap::new(),
+            clock,
+            rules: Rules::default(),
             socket,
         }
     }
 
+    /// Plays new games under `rules`.
+    #[must_use]
+    pub fn with_rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
     pub fn games_mut(&mut self) -> std::collections::hash_map::ValuesMut<'_, String, Game> {
         self.games.values_mut()
     }
@@ -41,14 +71,43 @@ impl World {
     }
 
     pub fn create_game(&mut self, game_id: &str) {
-        self.games.insert(game_id.into(), Game::new());
+        self.games
+            .insert(game_id.into(), Game::new(self.clock.now(), self.rules));
     }
 
-    pub fn add_player(&mut self, player: SocketAddr, game_id: &String) -> Option<()> {
+    pub fn add_player(&mut self, player: SocketAddr, game_id: &String) -> Option<Color> {
         let game = self.games.get_mut(game_id)?;
-        game.add_player(player);
-        self.participants.insert(player, game_id.to_string());
+        let seat = game.add_player(player);
+        self.participants.insert(player, game_id.clone());
+
+        seat
+    }
 
-        None
+    /// The server clock as sent to clients, measured from when the server started.
+    #[must_use]
+    pub fn server_time(&self) -> Duration {
+        self.clock.now()
+    }
+
+    /// Stores the player's one way latency, estimated as half of their reported round trip.
+    pub fn set_latency(&mut self, player: SocketAddr, rtt: Duration) {
+        self.latencies
+            .insert(player, (rtt / 2).min(MAX_LATENCY_COMPENSATION));
+    }
+
+    #[must_use]
+    pub fn get_latency(&self, player: &SocketAddr) -> Duration {
+        self.latencies.get(player).copied().unwrap_or_default()
+    }
+
+    /// Sends every card drawn since the last call to the owner of the hand, and nobody else.
+    pub fn send_draws(&mut self) {
+        for game in self.games.values_mut() {
+            for (color, card) in game.take_draws() {
+                if let Some(owner) = game.get_player(color) {
+                    let _ = self.socket.send_to(&Marshal::draw_card(card), owner);
+                }
+            }
+        }
     }
 }
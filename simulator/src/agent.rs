@@ -0,0 +1,177 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use bot::difficulty::{BotSettings, Difficulty};
+use state::{
+    board::Board,
+    movegen::MoveGen,
+    perft::uci,
+    piece::{Color, Move},
+};
+
+use crate::rng::Rng;
+
+/// How long the random and greedy agents take to react.
+pub const DEFAULT_REACTION_TIME: Duration = Duration::from_millis(250);
+
+/// A player in a simulated game.
+pub trait Agent: std::fmt::Debug {
+    /// How long after looking at the board the agent looks again.
+    fn reaction_time(&self) -> Duration;
+
+    /// The move to make now, if any, with `color`'s pieces.
+    fn choose(&mut self, board: &Board, color: Color) -> Option<Move>;
+}
+
+/// Makes a random move with one of its ready pieces.
+#[derive(Debug, Clone)]
+pub struct RandomAgent {
+    rng: Rng,
+}
+
+impl RandomAgent {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Agent for RandomAgent {
+    fn reaction_time(&self) -> Duration {
+        DEFAULT_REACTION_TIME
+    }
+
+    fn choose(&mut self, board: &Board, color: Color) -> Option<Move> {
+        let moves = sorted_moves(board, color);
+        if moves.is_empty() {
+            return None;
+        }
+        Some(moves[self.rng.below(moves.len())])
+    }
+}
+
+/// Makes the ready move with the best static evaluation right after it, picking at random
+/// between equally good moves.
+#[derive(Debug, Clone)]
+pub struct GreedyAgent {
+    rng: Rng,
+}
+
+impl GreedyAgent {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Agent for GreedyAgent {
+    fn reaction_time(&self) -> Duration {
+        DEFAULT_REACTION_TIME
+    }
+
+    fn choose(&mut self, board: &Board, color: Color) -> Option<Move> {
+        let scored = sorted_moves(board, color)
+            .into_iter()
+            .map(|mv| {
+                let mut after = board.clone();
+                after.process_move(mv);
+                let score = match after.winner() {
+                    Some(winner) if winner == color => i32::MAX,
+                    _ => after.static_evaluation().for_color(color),
+                };
+                (mv, score)
+            })
+            .collect::<Vec<_>>();
+        let best = scored.iter().map(|(_, score)| *score).max()?;
+        let best_moves = scored
+            .into_iter()
+            .filter(|(_, score)| *score == best)
+            .map(|(mv, _)| mv)
+            .collect::<Vec<_>>();
+        Some(best_moves[self.rng.below(best_moves.len())])
+    }
+}
+
+/// Plays with the bot's search, reacting after its difficulty's reaction delay.
+#[derive(Debug, Clone)]
+pub struct BotAgent {
+    settings: BotSettings,
+}
+
+impl BotAgent {
+    #[must_use]
+    pub fn new(settings: BotSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Agent for BotAgent {
+    fn reaction_time(&self) -> Duration {
+        self.settings.reaction_delay
+    }
+
+    fn choose(&mut self, board: &Board, color: Color) -> Option<Move> {
+        bot::search::best_move(board, color, &self.settings)
+    }
+}
+
+/// The moves `color` can make now, in a fixed order so that seeded choices repeat. Moves are
+/// generated in parallel and come out in whichever order the threads finish.
+fn sorted_moves(board: &Board, color: Color) -> Vec<Move> {
+    let mut moves = MoveGen::new(board).get_possible_moves_for_color(color);
+    moves.sort_by_key(uci);
+    moves
+}
+
+/// The agents that can be picked from the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentKind {
+    Random,
+    Greedy,
+    Bot(Difficulty),
+}
+
+impl AgentKind {
+    /// A new agent of this kind. Agents that make random choices make them from `seed`.
+    #[must_use]
+    pub fn build(self, seed: u64) -> Box<dyn Agent + Send> {
+        match self {
+            AgentKind::Random => Box::new(RandomAgent::new(seed)),
+            AgentKind::Greedy => Box::new(GreedyAgent::new(seed)),
+            AgentKind::Bot(difficulty) => Box::new(BotAgent::new(difficulty.settings())),
+        }
+    }
+}
+
+impl FromStr for AgentKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "random" => Ok(AgentKind::Random),
+            "greedy" => Ok(AgentKind::Greedy),
+            "easy" => Ok(AgentKind::Bot(Difficulty::Easy)),
+            "medium" => Ok(AgentKind::Bot(Difficulty::Medium)),
+            "hard" => Ok(AgentKind::Bot(Difficulty::Hard)),
+            _ => Err(format!(
+                "unknown agent {value}, expected random, greedy, easy, medium or hard"
+            )),
+        }
+    }
+}
+
+impl Display for AgentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AgentKind::Random => "random",
+            AgentKind::Greedy => "greedy",
+            AgentKind::Bot(Difficulty::Easy) => "easy",
+            AgentKind::Bot(Difficulty::Medium) => "medium",
+            AgentKind::Bot(Difficulty::Hard) => "hard",
+        };
+        write!(f, "{name}")
+    }
+}
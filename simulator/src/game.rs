@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use state::{
+    board::Board,
+    movegen::MoveGen,
+    piece::{Color, Move, Piece},
+    rules::Rules,
+};
+
+use crate::{agent::Agent, rng::Rng};
+
+/// The piece kinds in the order [`PieceUsage`] counts them.
+pub const PIECE_NAMES: [&str; 6] = ["pawn", "knight", "bishop", "rook", "queen", "king"];
+
+/// Shortest step virtual time is advanced by, so agents that answer at once cannot stall it.
+const MIN_STEP: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameConfig {
+    pub rules: Rules,
+    /// Games still going after this much virtual time end without a winner.
+    pub max_duration: Duration,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            rules: Rules::default(),
+            max_duration: Duration::from_mins(5),
+        }
+    }
+}
+
+/// How many moves were made with each kind of piece, in the order of [`PIECE_NAMES`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PieceUsage(pub [usize; 6]);
+
+impl PieceUsage {
+    pub fn record(&mut self, piece: &Piece) {
+        let kind = match piece {
+            Piece::Pawn(_, _) => 0,
+            Piece::Knight(_, _) => 1,
+            Piece::Bishop(_, _) => 2,
+            Piece::Rook(_, _) => 3,
+            Piece::Queen(_, _) => 4,
+            Piece::King(_, _) => 5,
+        };
+        self.0[kind] += 1;
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.0.iter().sum()
+    }
+}
+
+/// How a simulated game went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameRecord {
+    /// `None` if the game ran out of time.
+    pub winner: Option<Color>,
+    /// Virtual time from the start to the end of the game.
+    pub duration: Duration,
+    pub white_hp: usize,
+    pub black_hp: usize,
+    pub white_usage: PieceUsage,
+    pub black_usage: PieceUsage,
+}
+
+/**
+Plays a game between two agents in virtual time, from the standard position with every piece
+on its cooldown from `config.rules`.
+
+Instead of ticking, time jumps straight to the next moment an agent looks at the board. Each
+agent looks again after its reaction time, or once one of its pieces is ready if none is.
+Agents that look at the same moment choose from the same board, and their moves are made in
+an order picked at random from `seed`. A move that the other side's move made illegal is
+dropped, as the server would reject it, and so are moves an agent cannot afford under the
+energy rules. Between steps the board is moved forward with `Board::advance`, so kings lose
+HP in proportion to how long each attack lasted, with the step split wherever a cooldown runs
+out.
+*/
+pub fn play(
+    white: &mut dyn Agent,
+    black: &mut dyn Agent,
+    config: &GameConfig,
+    seed: u64,
+) -> GameRecord {
+    let mut board = Board::with_rules(config.rules);
+    let mut now = Duration::ZERO;
+    let mut wake = [Duration::ZERO; 2];
+    let mut usage = [PieceUsage::default(); 2];
+
+    let mut rng = Rng::new(seed);
+
+    while board.winner().is_none() && now < config.max_duration {
+        let snapshot = board.clone();
+        let mut chosen = vec![];
+        let order = if rng.below(2) == 0 {
+            [Color::White, Color::Black]
+        } else {
+            [Color::Black, Color::White]
+        };
+        for color in order {
+            let index = usize::from(color == Color::Black);
+            if wake[index] > now {
+                continue;
+            }
+            let agent: &mut dyn Agent = match color {
+                Color::White => white,
+                Color::Black => black,
+            };
+            if let Some(mv) = agent.choose(&snapshot, color) {
+                chosen.push((color, mv));
+            }
+            wake[index] = now + agent.reaction_time();
+        }
+
+        for (color, mv) in chosen {
+            let index = usize::from(color == Color::Black);
+            if !board.can_afford(color, board.move_cost())
+                || !is_still_legal(&board, &snapshot, color, mv)
+            {
+                continue;
+            }
+            if let Some(piece) = board.get_piece_for_move(&mv) {
+                usage[index].record(&piece);
+            }
+            board.process_move(mv);
+        }
+        for (index, color) in [Color::White, Color::Black].into_iter().enumerate() {
+            if wake[index] > now {
+                wake[index] = wake[index].max(now + next_ready(&board, color));
+            }
+        }
+
+        let next = wake[0]
+            .min(wake[1])
+            .min(config.max_duration)
+            .max(now + MIN_STEP);
+        board.advance(next.saturating_sub(now));
+        now = next;
+    }
+
+    GameRecord {
+        winner: board.winner(),
+        duration: now,
+        white_hp: board.white_hp,
+        black_hp: board.black_hp,
+        white_usage: usage[0],
+        black_usage: usage[1],
+    }
+}
+
+/// Whether `mv`, chosen on `snapshot`, can still be made after the other side's move on `board`.
+fn is_still_legal(board: &Board, snapshot: &Board, color: Color, mv: Move) -> bool {
+    board.zobrist == snapshot.zobrist
+        || MoveGen::new(board)
+            .get_possible_moves_for_color(color)
+            .contains(&mv)
+}
+
+/// How long until the first of `color`'s pieces is ready, zero if one already is.
+fn next_ready(board: &Board, color: Color) -> Duration {
+    board
+        .pieces
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|piece| piece.get_color() == color)
+        .map(Piece::ready_in)
+        .min()
+        .unwrap_or_default()
+}
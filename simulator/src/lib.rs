@@ -0,0 +1,59 @@
+pub mod agent;
+pub mod game;
+pub mod report;
+pub mod rng;
+pub mod rule_set;
+mod tests;
+
+use std::{num::NonZeroUsize, panic, thread};
+
+use agent::AgentKind;
+use game::{GameConfig, GameRecord};
+
+/**
+Plays `games` games between a White and a Black agent, spread over as many threads as the
+machine has cores. Game `n` seeds its agents and itself from `seed + n`, so the same arguments
+always give the same records, in the same order. A game that panics panics the caller too,
+rather than leaving its record out.
+*/
+#[must_use]
+pub fn run_games(
+    white: AgentKind,
+    black: AgentKind,
+    config: &GameConfig,
+    games: usize,
+    seed: u64,
+) -> Vec<GameRecord> {
+    let threads = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(games.max(1));
+    let play = |index: usize| {
+        let seed = seed.wrapping_add(index as u64);
+        let mut white = white.build(seed);
+        let mut black = black.build(seed.wrapping_add(u64::MAX / 2));
+        game::play(white.as_mut(), black.as_mut(), config, seed)
+    };
+
+    thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|thread| {
+                scope.spawn(move || {
+                    (thread..games)
+                        .step_by(threads)
+                        .map(|index| (index, play(index)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut records = handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|error| panic::resume_unwind(error))
+            })
+            .collect::<Vec<_>>();
+        records.sort_by_key(|(index, _)| *index);
+        records.into_iter().map(|(_, record)| record).collect()
+    })
+}
@@ -0,0 +1,90 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use clap::Parser;
+use simulator::{
+    agent::AgentKind,
+    game::GameConfig,
+    report::{to_csv, to_json, Summary},
+    rule_set::RuleSet,
+    run_games,
+};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Games to play under each rule set.
+    #[arg(short, long, default_value_t = 1000)]
+    games: usize,
+
+    /// random, greedy, easy, medium or hard.
+    #[arg(long, default_value = "greedy")]
+    white: AgentKind,
+
+    /// random, greedy, easy, medium or hard.
+    #[arg(long, default_value = "greedy")]
+    black: AgentKind,
+
+    /// A rule set as `name:rule=millis,...`, such as `fast-queen:queen=4500`. Can be given more
+    /// than once; the standard rules are used if none is given.
+    #[arg(short, long)]
+    rules: Vec<RuleSet>,
+
+    /// Games still going after this many seconds of virtual time end without a winner.
+    #[arg(long, default_value_t = 300)]
+    max_seconds: u64,
+
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Where to write the results. Written as JSON if the file ends in `.json`, as CSV otherwise.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let rule_sets = if args.rules.is_empty() {
+        vec![RuleSet::default()]
+    } else {
+        args.rules
+    };
+
+    let mut summaries = vec![];
+    for rule_set in rule_sets {
+        let config = GameConfig {
+            rules: rule_set.rules,
+            max_duration: Duration::from_secs(args.max_seconds),
+        };
+        let records = run_games(args.white, args.black, &config, args.games, args.seed);
+        let summary = Summary::new(
+            &rule_set.name,
+            &args.white.to_string(),
+            &args.black.to_string(),
+            &records,
+        );
+        println!(
+            "{}: White {} / Black {} / timeout {} in {} games, {:.1}s on average",
+            summary.rules,
+            summary.white_wins,
+            summary.black_wins,
+            summary.timeouts,
+            summary.games,
+            summary.average_duration.as_secs_f64()
+        );
+        summaries.push(summary);
+    }
+
+    match args.output {
+        Some(path)
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json") =>
+        {
+            fs::write(path, to_json(&summaries))?;
+        }
+        Some(path) => fs::write(path, to_csv(&summaries))?,
+        None => print!("{}", to_csv(&summaries)),
+    }
+
+    Ok(())
+}
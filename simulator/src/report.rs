@@ -0,0 +1,189 @@
+use std::{fmt::Write, time::Duration};
+
+use state::piece::Color;
+
+use crate::game::{GameRecord, PieceUsage, PIECE_NAMES};
+
+/// The results of every game played under one rule set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub rules: String,
+    pub white: String,
+    pub black: String,
+    pub games: usize,
+    pub white_wins: usize,
+    pub black_wins: usize,
+    /// Games that ran out of time without a winner.
+    pub timeouts: usize,
+    pub average_duration: Duration,
+    pub average_white_hp: f64,
+    pub average_black_hp: f64,
+    /// Moves per game with each kind of piece, in the order of [`PIECE_NAMES`].
+    pub white_usage: [f64; 6],
+    pub black_usage: [f64; 6],
+}
+
+impl Summary {
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(rules: &str, white: &str, black: &str, records: &[GameRecord]) -> Self {
+        let games = records.len();
+        let wins = |color| {
+            records
+                .iter()
+                .filter(|record| record.winner == Some(color))
+                .count()
+        };
+        let average = |value: &dyn Fn(&GameRecord) -> f64| {
+            if games == 0 {
+                0.0
+            } else {
+                records.iter().map(value).sum::<f64>() / games as f64
+            }
+        };
+        let usage = |usage: &dyn Fn(&GameRecord) -> PieceUsage| {
+            let mut average_usage = [0.0; 6];
+            for (kind, slot) in average_usage.iter_mut().enumerate() {
+                *slot = average(&|record| usage(record).0[kind] as f64);
+            }
+            average_usage
+        };
+
+        Self {
+            rules: rules.into(),
+            white: white.into(),
+            black: black.into(),
+            games,
+            white_wins: wins(Color::White),
+            black_wins: wins(Color::Black),
+            timeouts: records
+                .iter()
+                .filter(|record| record.winner.is_none())
+                .count(),
+            average_duration: Duration::from_secs_f64(average(&|record| {
+                record.duration.as_secs_f64()
+            })),
+            average_white_hp: average(&|record| record.white_hp as f64),
+            average_black_hp: average(&|record| record.black_hp as f64),
+            white_usage: usage(&|record| record.white_usage),
+            black_usage: usage(&|record| record.black_usage),
+        }
+    }
+
+    /// The share of games `color` won, from 0 to 1.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn win_rate(&self, color: Color) -> f64 {
+        let wins = match color {
+            Color::White => self.white_wins,
+            Color::Black => self.black_wins,
+        };
+        if self.games == 0 {
+            0.0
+        } else {
+            wins as f64 / self.games as f64
+        }
+    }
+}
+
+/// One row per summary, with a header row naming the columns.
+#[must_use]
+pub fn to_csv(summaries: &[Summary]) -> String {
+    let mut header = vec![
+        "rules",
+        "white",
+        "black",
+        "games",
+        "white_wins",
+        "black_wins",
+        "timeouts",
+        "white_win_rate",
+        "black_win_rate",
+        "average_seconds",
+        "average_white_hp",
+        "average_black_hp",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect::<Vec<_>>();
+    for color in ["white", "black"] {
+        header.extend(
+            PIECE_NAMES
+                .iter()
+                .map(|piece| format!("{color}_{piece}_moves")),
+        );
+    }
+
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for summary in summaries {
+        let mut row = vec![
+            summary.rules.clone(),
+            summary.white.clone(),
+            summary.black.clone(),
+            summary.games.to_string(),
+            summary.white_wins.to_string(),
+            summary.black_wins.to_string(),
+            summary.timeouts.to_string(),
+            format!("{:.4}", summary.win_rate(Color::White)),
+            format!("{:.4}", summary.win_rate(Color::Black)),
+            format!("{:.3}", summary.average_duration.as_secs_f64()),
+            format!("{:.1}", summary.average_white_hp),
+            format!("{:.1}", summary.average_black_hp),
+        ];
+        for usage in [summary.white_usage, summary.black_usage] {
+            row.extend(usage.iter().map(|moves| format!("{moves:.2}")));
+        }
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// A JSON array with an object per summary.
+#[must_use]
+pub fn to_json(summaries: &[Summary]) -> String {
+    let usage = |usage: &[f64; 6]| {
+        let fields = PIECE_NAMES
+            .iter()
+            .zip(usage)
+            .map(|(piece, moves)| format!("\"{piece}\": {moves:.2}"))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", fields.join(", "))
+    };
+
+    let mut json = String::from("[");
+    for (index, summary) in summaries.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            "\n  {{\"rules\": \"{}\", \"white\": \"{}\", \"black\": \"{}\", \"games\": {}, \
+             \"white_wins\": {}, \"black_wins\": {}, \"timeouts\": {}, \
+             \"white_win_rate\": {:.4}, \"black_win_rate\": {:.4}, \"average_seconds\": {:.3}, \
+             \"average_white_hp\": {:.1}, \"average_black_hp\": {:.1}, \
+             \"white_moves\": {}, \"black_moves\": {}}}",
+            escape(&summary.rules),
+            escape(&summary.white),
+            escape(&summary.black),
+            summary.games,
+            summary.white_wins,
+            summary.black_wins,
+            summary.timeouts,
+            summary.win_rate(Color::White),
+            summary.win_rate(Color::Black),
+            summary.average_duration.as_secs_f64(),
+            summary.average_white_hp,
+            summary.average_black_hp,
+            usage(&summary.white_usage),
+            usage(&summary.black_usage),
+        );
+    }
+    json.push_str("\n]\n");
+    json
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,87 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use state::rules::Rules;
+
+/// A named set of rules to simulate games under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    pub name: String,
+    pub rules: Rules,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            name: "standard".into(),
+            rules: Rules::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleSetError {
+    MissingName,
+    UnknownRule(String),
+    InvalidMillis(String),
+}
+
+impl Display for RuleSetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleSetError::MissingName => write!(f, "rule set needs a name, as in name:queen=6000"),
+            RuleSetError::UnknownRule(rule) => write!(
+                f,
+                "unknown rule {rule}, expected pawn, knight, bishop, rook, queen, king or promotion"
+            ),
+            RuleSetError::InvalidMillis(value) => {
+                write!(f, "{value} is not a number of milliseconds")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuleSetError {}
+
+/**
+Parses `name:rule=millis,...`, where each rule sets the cooldown in milliseconds of a piece
+(`pawn`, `knight`, `bishop`, `rook`, `queen` or `king`) or of a freshly promoted piece
+(`promotion`). Rules that are not mentioned keep their defaults, so `standard:` is the
+standard game.
+*/
+impl FromStr for RuleSet {
+    type Err = RuleSetError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, rules) = value.split_once(':').unwrap_or((value, ""));
+        if name.is_empty() {
+            return Err(RuleSetError::MissingName);
+        }
+
+        let mut rule_set = RuleSet {
+            name: name.into(),
+            rules: Rules::default(),
+        };
+        for rule in rules.split(',').filter(|rule| !rule.is_empty()) {
+            let (rule, millis) = rule
+                .split_once('=')
+                .ok_or_else(|| RuleSetError::UnknownRule(rule.into()))?;
+            let millis = millis
+                .parse()
+                .map(Duration::from_millis)
+                .map_err(|_| RuleSetError::InvalidMillis(millis.into()))?;
+            let cooldowns = &mut rule_set.rules.cooldowns;
+            match rule {
+                "pawn" => cooldowns.pawn = millis,
+                "knight" => cooldowns.knight = millis,
+                "bishop" => cooldowns.bishop = millis,
+                "rook" => cooldowns.rook = millis,
+                "queen" => cooldowns.queen = millis,
+                "king" => cooldowns.king = millis,
+                "promotion" => rule_set.rules.promotion_cooldown = millis,
+                _ => return Err(RuleSetError::UnknownRule(rule.into())),
+            }
+        }
+
+        Ok(rule_set)
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod games {
+    use std::time::Duration;
+
+    use state::{
+        board::Board,
+        piece::{Color, Move},
+        rules::Rules,
+    };
+
+    use crate::{
+        agent::{Agent, AgentKind},
+        game::{play, GameConfig},
+        run_games,
+    };
+
+    /// Never moves.
+    #[derive(Debug)]
+    struct Idle;
+
+    impl Agent for Idle {
+        fn reaction_time(&self) -> Duration {
+            Duration::from_secs(1)
+        }
+
+        fn choose(&mut self, _board: &Board, _color: Color) -> Option<Move> {
+            None
+        }
+    }
+
+    fn short_game() -> GameConfig {
+        GameConfig {
+            max_duration: Duration::from_secs(5),
+            ..GameConfig::default()
+        }
+    }
+
+    #[test]
+    fn idle_agents_time_out() {
+        let record = play(&mut Idle, &mut Idle, &short_game(), 0);
+        assert_eq!(record.winner, None);
+        assert_eq!(record.duration, Duration::from_secs(5));
+        assert_eq!(record.white_usage.total(), 0);
+        assert_eq!((record.white_hp, record.black_hp), (5000, 5000));
+    }
+
+    #[test]
+    fn greedy_beats_an_idle_opponent() {
+        let mut greedy = AgentKind::Greedy.build(1);
+        let config = GameConfig {
+            max_duration: Duration::from_mins(2),
+            ..GameConfig::default()
+        };
+        let record = play(greedy.as_mut(), &mut Idle, &config, 0);
+        assert_eq!(record.winner, Some(Color::White));
+        assert!(record.white_usage.total() > 0);
+        assert_eq!(record.black_usage.total(), 0);
+    }
+
+    #[test]
+    fn starting_cooldowns_follow_the_rules() {
+        let mut rules = Rules::default();
+        rules.cooldowns.queen = Duration::from_secs(2);
+        let board = Board::with_rules(rules);
+        let queen = board
+            .pieces
+            .iter()
+            .flatten()
+            .flatten()
+            .find(|piece| piece.is_queen())
+            .expect("a queen");
+        assert_eq!(queen.get_cooldown(), Duration::from_secs(2));
+        assert_eq!(board.rules, rules);
+    }
+
+    #[test]
+    fn runs_repeat_with_the_same_seed() {
+        let config = short_game();
+        let first = run_games(AgentKind::Random, AgentKind::Greedy, &config, 3, 7);
+        let second = run_games(AgentKind::Random, AgentKind::Greedy, &config, 3, 7);
+        assert_eq!(first.len(), 3);
+        assert_eq!(first, second);
+    }
+}
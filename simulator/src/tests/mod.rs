@@ -0,0 +1,3 @@
+mod game;
+mod report;
+mod rule_set;
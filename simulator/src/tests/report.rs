@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod output {
+    use std::time::Duration;
+
+    use state::piece::Color;
+
+    use crate::{
+        game::{GameRecord, PieceUsage},
+        report::{to_csv, to_json, Summary},
+    };
+
+    fn record(winner: Option<Color>, seconds: u64, white_hp: usize) -> GameRecord {
+        GameRecord {
+            winner,
+            duration: Duration::from_secs(seconds),
+            white_hp,
+            black_hp: 5000,
+            white_usage: PieceUsage([4, 2, 0, 0, 0, 0]),
+            black_usage: PieceUsage::default(),
+        }
+    }
+
+    fn summary() -> Summary {
+        Summary::new(
+            "standard",
+            "greedy",
+            "random",
+            &[
+                record(Some(Color::White), 10, 5000),
+                record(Some(Color::White), 20, 4000),
+                record(Some(Color::Black), 30, 3000),
+                record(None, 40, 4000),
+            ],
+        )
+    }
+
+    #[test]
+    fn summary_averages_the_games() {
+        let summary = summary();
+        assert_eq!(summary.games, 4);
+        assert_eq!(
+            (summary.white_wins, summary.black_wins, summary.timeouts),
+            (2, 1, 1)
+        );
+        assert!((summary.win_rate(Color::White) - 0.5).abs() < f64::EPSILON);
+        assert_eq!(summary.average_duration, Duration::from_secs(25));
+        assert!((summary.average_white_hp - 4000.0).abs() < f64::EPSILON);
+        assert!((summary.white_usage[0] - 4.0).abs() < f64::EPSILON);
+        assert!((summary.white_usage[1] - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn csv_has_a_header_and_a_row_per_summary() {
+        let csv = to_csv(&[summary(), summary()]);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("rules,white,black,games"));
+        assert!(lines[0].ends_with("black_king_moves"));
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[1].starts_with("standard,greedy,random,4,2,1,1,0.5000"));
+    }
+
+    #[test]
+    fn json_has_an_object_per_summary() {
+        let json = to_json(&[summary()]);
+        assert!(json.trim_start().starts_with('['));
+        assert!(json.trim_end().ends_with(']'));
+        assert!(json.contains("\"rules\": \"standard\""));
+        assert!(json.contains("\"white_win_rate\": 0.5000"));
+        assert!(json.contains("\"white_moves\": {\"pawn\": 4.00"));
+    }
+}
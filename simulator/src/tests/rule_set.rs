@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod parsing {
+    use std::time::Duration;
+
+    use state::rules::Rules;
+
+    use crate::rule_set::{RuleSet, RuleSetError};
+
+    #[test]
+    fn name_alone_is_the_standard_game() {
+        let rule_set: RuleSet = "standard".parse().expect("rule set");
+        assert_eq!(rule_set, RuleSet::default());
+        assert_eq!("standard:".parse(), Ok(RuleSet::default()));
+    }
+
+    #[test]
+    fn rules_set_cooldowns() {
+        let rule_set: RuleSet = "fast:queen=4500,pawn=250,promotion=100"
+            .parse()
+            .expect("rule set");
+        let mut rules = Rules::default();
+        rules.cooldowns.queen = Duration::from_millis(4500);
+        rules.cooldowns.pawn = Duration::from_millis(250);
+        rules.promotion_cooldown = Duration::from_millis(100);
+
+        assert_eq!(rule_set.name, "fast");
+        assert_eq!(rule_set.rules, rules);
+    }
+
+    #[test]
+    fn bad_rule_sets_are_rejected() {
+        assert_eq!(
+            ":queen=1".parse::<RuleSet>(),
+            Err(RuleSetError::MissingName)
+        );
+        assert_eq!(
+            "x:emperor=1".parse::<RuleSet>(),
+            Err(RuleSetError::UnknownRule("emperor".into()))
+        );
+        assert_eq!(
+            "x:queen=soon".parse::<RuleSet>(),
+            Err(RuleSetError::InvalidMillis("soon".into()))
+        );
+    }
+}
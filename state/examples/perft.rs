@@ -0,0 +1,103 @@
+//! Counts perft nodes from a position and how fast they were generated, as a benchmark for
+//! move generation.
+//!
+//! ```text
+//! cargo run --release -p state --example perft -- <depth> [<fen>] [--divide] [--reference <file>]
+//! ```
+//!
+//! `--divide` prints the count below each root move. `--reference` reads a divide in the
+//! format Stockfish's `go perft` prints, one `e2e4: 20` per line, and lists the root moves
+//! that disagree with it.
+
+use std::{fs, time::Instant};
+
+use state::{
+    board::Board,
+    perft::{divide_mismatches, uci},
+    piece::Color,
+};
+
+const STANDARD_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut depth = None;
+    let mut fen = STANDARD_FEN.to_string();
+    let mut divide = false;
+    let mut reference = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--divide" => divide = true,
+            "--reference" => reference = args.next(),
+            _ => match arg.parse::<usize>() {
+                Ok(parsed) if depth.is_none() => depth = Some(parsed),
+                _ => fen = arg,
+            },
+        }
+    }
+
+    let Some(depth) = depth else {
+        eprintln!("usage: perft <depth> [<fen>] [--divide] [--reference <file>]");
+        return;
+    };
+    let board = Board::from(fen.as_str());
+    let color = match fen.split_whitespace().nth(1) {
+        Some("b") => Color::Black,
+        _ => Color::White,
+    };
+
+    let start = Instant::now();
+    let counts = board.divide(color, depth);
+    let elapsed = start.elapsed();
+    let nodes = if depth == 0 {
+        1
+    } else {
+        counts.iter().map(|(_, count)| count).sum::<u64>()
+    };
+
+    if divide {
+        let mut lines = counts
+            .iter()
+            .map(|(mv, count)| format!("{}: {count}", uci(mv)))
+            .collect::<Vec<_>>();
+        lines.sort();
+        for line in lines {
+            println!("{line}");
+        }
+        println!();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let nodes_per_second = nodes as f64 / elapsed.as_secs_f64();
+    println!("nodes: {nodes}");
+    println!("time: {elapsed:?} ({nodes_per_second:.0} nodes/s)");
+
+    if let Some(path) = reference {
+        let Ok(text) = fs::read_to_string(&path) else {
+            eprintln!("could not read {path}");
+            return;
+        };
+        let reference = text
+            .lines()
+            .filter_map(|line| {
+                let (mv, count) = line.split_once(':')?;
+                Some((mv.trim().to_string(), count.trim().parse().ok()?))
+            })
+            .collect::<Vec<(String, u64)>>();
+
+        let mismatches = divide_mismatches(&counts, &reference);
+        if mismatches.is_empty() {
+            println!("every root move matches {path}");
+        }
+        for mismatch in mismatches {
+            let count = |count: Option<u64>| count.map_or("-".to_string(), |c| c.to_string());
+            println!(
+                "{}: ours {}, reference {}",
+                mismatch.mv,
+                count(mismatch.ours),
+                count(mismatch.reference)
+            );
+        }
+    }
+}
@@ -4,12 +4,19 @@ use super::{
         COOLDOWN_QUEEN, COOLDOWN_ROOK,
     },
     coordinate::Coord,
+    history::SquareHistory,
     movegen::MoveGen,
     piece::{Color, Move, Piece},
+    piece_state::{PieceId, StatusEffect, NO_PIECE_ID},
+    rules::Rules,
+    square::{A1, A8, E1, E8, H1, H8},
 };
 use core::fmt;
 use std::{collections::HashSet, fmt::Debug, time::Duration};
 
+/// The HP each king starts a standard game with.
+pub const STARTING_HP: usize = 5_000;
+
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Board {
@@ -22,6 +29,28 @@ pub struct Board {
 
     pub white_hp: usize,
     pub black_hp: usize,
+    /// Time each king has been attacked for, once per attacker, that has not yet added up to a
+    /// whole HP lost. See [`Board::advance`].
+    pub white_hp_drain: Duration,
+    pub black_hp_drain: Duration,
+
+    /// Only used when `rules.energy` is set, see [`Board::enable_energy`].
+    pub white_energy: usize,
+    pub black_energy: usize,
+    /// Time since both players last regained energy.
+    pub energy_regen_progress: Duration,
+
+    pub rules: Rules,
+
+    /// What stood on each square before recent moves, for rewinding.
+    pub history: SquareHistory,
+
+    /// The id the next piece put on the board will get.
+    pub next_piece_id: PieceId,
+
+    /// The position hash, kept up to date by the board's own methods. Call [`Board::rehash`]
+    /// after writing to the fields above directly.
+    pub zobrist: u64,
 }
 
 impl Board {
@@ -29,45 +58,57 @@ impl Board {
     pub fn standard() -> Self {
         let mut pieces: [[Option<Piece>; 8]; 8] = [[None; 8]; 8];
 
-        pieces[1] = [Some(Piece::Pawn(Color::White, COOLDOWN_PAWN)); 8];
-        pieces[6] = [Some(Piece::Pawn(Color::Black, COOLDOWN_PAWN)); 8];
+        pieces[1] = [Some(Piece::Pawn(Color::White, COOLDOWN_PAWN.into())); 8];
+        pieces[6] = [Some(Piece::Pawn(Color::Black, COOLDOWN_PAWN.into())); 8];
 
         pieces[0] = [
-            Some(Piece::Rook(Color::White, COOLDOWN_ROOK)),
-            Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT)),
-            Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP)),
-            Some(Piece::Queen(Color::White, COOLDOWN_QUEEN)),
-            Some(Piece::King(Color::White, COOLDOWN_KING)),
-            Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP)),
-            Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT)),
-            Some(Piece::Rook(Color::White, COOLDOWN_ROOK)),
+            Some(Piece::Rook(Color::White, COOLDOWN_ROOK.into())),
+            Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT.into())),
+            Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP.into())),
+            Some(Piece::Queen(Color::White, COOLDOWN_QUEEN.into())),
+            Some(Piece::King(Color::White, COOLDOWN_KING.into())),
+            Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP.into())),
+            Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT.into())),
+            Some(Piece::Rook(Color::White, COOLDOWN_ROOK.into())),
         ];
 
         pieces[7] = [
-            Some(Piece::Rook(Color::Black, COOLDOWN_ROOK)),
-            Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT)),
-            Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP)),
-            Some(Piece::Queen(Color::Black, COOLDOWN_QUEEN)),
-            Some(Piece::King(Color::Black, COOLDOWN_KING)),
-            Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP)),
-            Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT)),
-            Some(Piece::Rook(Color::Black, COOLDOWN_ROOK)),
+            Some(Piece::Rook(Color::Black, COOLDOWN_ROOK.into())),
+            Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT.into())),
+            Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP.into())),
+            Some(Piece::Queen(Color::Black, COOLDOWN_QUEEN.into())),
+            Some(Piece::King(Color::Black, COOLDOWN_KING.into())),
+            Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP.into())),
+            Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT.into())),
+            Some(Piece::Rook(Color::Black, COOLDOWN_ROOK.into())),
         ];
 
-        Self {
+        let mut board = Self {
             pieces,
             white_can_castle_kingside: true,
             white_can_castle_queenside: true,
             black_can_castle_kingside: true,
             black_can_castle_queenside: true,
-            white_hp: 5_000,
-            black_hp: 5_000,
-        }
+            white_hp: STARTING_HP,
+            black_hp: STARTING_HP,
+            white_hp_drain: Duration::ZERO,
+            black_hp_drain: Duration::ZERO,
+            white_energy: 0,
+            black_energy: 0,
+            energy_regen_progress: Duration::ZERO,
+            rules: Rules::default(),
+            history: SquareHistory::default(),
+            next_piece_id: NO_PIECE_ID + 1,
+            zobrist: 0,
+        };
+        board.assign_piece_ids();
+        board.rehash();
+        board
     }
 
     #[must_use]
     pub fn empty() -> Self {
-        Self {
+        let mut board = Self {
             pieces: [[None; 8]; 8],
             white_can_castle_kingside: false,
             white_can_castle_queenside: false,
@@ -75,7 +116,18 @@ impl Board {
             black_can_castle_queenside: false,
             white_hp: 0,
             black_hp: 0,
-        }
+            white_hp_drain: Duration::ZERO,
+            black_hp_drain: Duration::ZERO,
+            white_energy: 0,
+            black_energy: 0,
+            energy_regen_progress: Duration::ZERO,
+            rules: Rules::default(),
+            history: SquareHistory::default(),
+            next_piece_id: NO_PIECE_ID + 1,
+            zobrist: 0,
+        };
+        board.rehash();
+        board
     }
 
     #[must_use]
@@ -95,18 +147,31 @@ impl Board {
         &self.pieces[position.1 as usize][position.0 as usize]
     }
 
+    /// Puts `piece` on `position`. A piece without an id gets the board's next one.
     #[allow(clippy::cast_sign_loss)]
     pub fn set_piece_at(&mut self, piece: Option<Piece>, position: Coord) {
+        let piece = piece.map(|piece| self.with_id(piece));
+        let previous = *self.get_piece_at(&position);
+        self.hash_square(position, previous, piece);
         self.pieces[position.1 as usize][position.0 as usize] = piece;
     }
 
+    /// Like [`Board::set_piece_at`], but remembers what stood on `position` before.
+    pub(crate) fn place_piece_at(&mut self, piece: Option<Piece>, position: Coord) {
+        let previous = *self.get_piece_at(&position);
+        self.history
+            .record(position, previous, self.rules.square_history_depth);
+        self.set_piece_at(piece, position);
+    }
+
+    /// Removes every piece of the same kind and color as `piece`. Use [`Board::remove_by_id`]
+    /// to remove one specific piece.
     pub fn remove_by_piece(&mut self, piece: &Piece) {
         for y in 0..8 {
             for x in 0..8 {
-                if let Some(target) = self.pieces[y][x] {
-                    if piece == &target {
-                        self.pieces[y][x] = None;
-                    }
+                let coord = Coord(x, y);
+                if self.get_piece_at(&coord).as_ref() == Some(piece) {
+                    self.set_piece_at(None, coord);
                 }
             }
         }
@@ -130,10 +195,12 @@ impl Board {
             });
         });
 
-        Board {
+        let mut board = Board {
             pieces,
             ..self.clone()
-        }
+        };
+        board.rehash();
+        board
     }
 
     #[must_use]
@@ -155,52 +222,162 @@ impl Board {
             });
         });
 
-        Board {
+        let mut board = Board {
             pieces,
             ..self.clone()
-        }
+        };
+        board.rehash();
+        board
     }
 
+    /**
+    Applies `m` to the board without checking that it is legal.
+
+    Every moved piece is put on its cooldown from the board's rules, shortened while it is
+    hasted; castling puts both the king and the rook on cooldown. Capturing a shielded piece
+    only breaks the shield. Castling rights are revoked when the king moves or castles, and per
+    side when the rook leaves its starting corner or anything lands on it. A promoted piece
+    starts on the promotion cooldown from the board's rules and with a new id, and a promotion
+    that the pawn on `src` is not allowed to make leaves the board untouched. Every square the
+    move touches remembers what stood there before, see [`Board::rewind_square`]. With an
+    energy economy the mover pays the move cost, also when the move only breaks a shield, but
+    not for a move that leaves the board untouched.
+    */
     pub fn process_move(&mut self, m: Move) -> &Self {
+        if let Move::Promotion(src, _, piece) = m {
+            if !self
+                .get_piece_at(&src)
+                .is_some_and(|pawn| pawn.can_promote_to(&piece))
+            {
+                return self;
+            }
+        }
+        if let Some(piece) = self.get_piece_for_move(&m) {
+            self.spend_energy(piece.get_color(), self.move_cost());
+        }
         match m {
             Move::Piece(from, to) => {
-                if let Some(mut piece) = self.get_piece_at(&from) {
-                    piece.set_cooldown(Piece::std_piece_cooldown(&piece));
-                    self.set_piece_at(Some(piece), to);
+                if self.absorb_capture(from, to) {
+                    return self;
                 }
-                self.set_piece_at(None, from);
-            }
-            Move::KingSideCastle(color) => {
-                let src_file = if color == Color::White { 0 } else { 7 };
-                if color == Color::White {
-                    self.white_can_castle_kingside = false;
-                    self.white_can_castle_queenside = false;
-                } else {
-                    self.black_can_castle_kingside = false;
-                    self.black_can_castle_queenside = false;
+                if let Some(mut piece) = *self.get_piece_at(&from) {
+                    piece.restart_cooldown(&self.rules.cooldowns);
+                    self.place_piece_at(Some(piece), to);
                 }
-                self.pieces[src_file][4] = None;
-                self.pieces[src_file][7] = None;
-                self.pieces[src_file][6] = Some(Piece::King(color, COOLDOWN_KING));
-                self.pieces[src_file][5] = Some(Piece::Rook(color, COOLDOWN_ROOK));
+                self.place_piece_at(None, from);
+                self.revoke_castling_rights(from);
+                self.revoke_castling_rights(to);
             }
-            Move::QueenSideCastle(color) => {
-                let src_file = if color == Color::White { 0 } else { 7 };
-                self.pieces[src_file][4] = None;
-                self.pieces[src_file][0] = None;
-                self.pieces[src_file][2] = Some(Piece::King(color, COOLDOWN_KING));
-                self.pieces[src_file][2] = Some(Piece::Rook(color, COOLDOWN_ROOK));
-            }
-            #[allow(clippy::cast_sign_loss)]
-            Move::Promotion(src, dest, piece) => {
-                self.pieces[src.1 as usize][src.0 as usize] = None;
-                self.pieces[dest.1 as usize][dest.0 as usize] = Some(piece);
+            Move::KingSideCastle(color) => self.castle(color, 7, 6, 5),
+            Move::QueenSideCastle(color) => self.castle(color, 0, 2, 3),
+            Move::Promotion(src, dest, mut piece) => {
+                if self.absorb_capture(src, dest) {
+                    return self;
+                }
+                piece.set_cooldown(self.rules.promotion_cooldown);
+                piece.get_state_mut().id = NO_PIECE_ID;
+                self.place_piece_at(None, src);
+                self.place_piece_at(Some(piece), dest);
+                self.revoke_castling_rights(dest);
             }
         }
 
         self
     }
 
+    /// Moves the king and the rook on `rook_file` to their castled files, keeping their effects.
+    fn castle(&mut self, color: Color, rook_file: i8, king_to: i8, rook_to: i8) {
+        let rank = Board::castle_rank(color);
+        let mut king = self
+            .get_piece_at(&Coord(4, rank))
+            .unwrap_or(Piece::King(color, COOLDOWN_KING.into()));
+        let mut rook = self
+            .get_piece_at(&Coord(rook_file, rank))
+            .unwrap_or(Piece::Rook(color, COOLDOWN_ROOK.into()));
+        king.restart_cooldown(&self.rules.cooldowns);
+        rook.restart_cooldown(&self.rules.cooldowns);
+
+        self.revoke_castling_rights(Coord(4, rank));
+        self.place_piece_at(None, Coord(4, rank));
+        self.place_piece_at(None, Coord(rook_file, rank));
+        self.place_piece_at(Some(king), Coord(king_to, rank));
+        self.place_piece_at(Some(rook), Coord(rook_to, rank));
+    }
+
+    /// A shielded piece survives being captured once. The shield is used up and the attacker
+    /// stays where it is, on cooldown as if it had moved.
+    fn absorb_capture(&mut self, from: Coord, to: Coord) -> bool {
+        let (Some(mut attacker), Some(mut target)) =
+            (*self.get_piece_at(&from), *self.get_piece_at(&to))
+        else {
+            return false;
+        };
+        if target.get_color() == attacker.get_color() || !target.has_effect(StatusEffect::Shield) {
+            return false;
+        }
+
+        target.get_state_mut().remove_effect(StatusEffect::Shield);
+        attacker.restart_cooldown(&self.rules.cooldowns);
+        self.set_piece_at(Some(target), to);
+        self.set_piece_at(Some(attacker), from);
+        true
+    }
+
+    /// Puts `effect` on the piece on `coord` for `duration`. Returns `false` if the square is
+    /// empty.
+    pub fn add_effect(&mut self, coord: Coord, effect: StatusEffect, duration: Duration) -> bool {
+        let Some(mut piece) = *self.get_piece_at(&coord) else {
+            return false;
+        };
+        piece.get_state_mut().add_effect(effect, duration);
+        self.set_piece_at(Some(piece), coord);
+        true
+    }
+
+    /// The rank the king and rooks of `color` castle on.
+    #[must_use]
+    pub fn castle_rank(color: Color) -> i8 {
+        match color {
+            Color::White => 0,
+            Color::Black => 7,
+        }
+    }
+
+    /// The castling rights in the order White king side, White queen side, Black king side,
+    /// Black queen side.
+    #[must_use]
+    pub fn castling_rights(&self) -> [bool; 4] {
+        [
+            self.white_can_castle_kingside,
+            self.white_can_castle_queenside,
+            self.black_can_castle_kingside,
+            self.black_can_castle_queenside,
+        ]
+    }
+
+    /// Revokes whatever castling rights depend on the piece that started on `square`.
+    fn revoke_castling_rights(&mut self, square: Coord) {
+        let previous = self.castling_rights();
+        match square {
+            E1 => {
+                self.white_can_castle_kingside = false;
+                self.white_can_castle_queenside = false;
+            }
+            E8 => {
+                self.black_can_castle_kingside = false;
+                self.black_can_castle_queenside = false;
+            }
+            H1 => self.white_can_castle_kingside = false,
+            A1 => self.white_can_castle_queenside = false,
+            H8 => self.black_can_castle_kingside = false,
+            A8 => self.black_can_castle_queenside = false,
+            _ => {}
+        }
+        self.hash_castling(previous);
+    }
+
+    /// The first square from a1 to h8 holding a piece of the same kind and color as `piece`.
+    /// Use [`Board::get_coord_for_id`] to find one specific piece.
     #[must_use]
     pub fn get_coord_for_piece(&self, piece: &Piece) -> Option<Coord> {
         for y in 0..8 {
@@ -218,20 +395,227 @@ impl Board {
         None
     }
 
+    #[must_use]
+    pub fn get_coord_for_id(&self, id: PieceId) -> Option<Coord> {
+        (0..8)
+            .flat_map(|y| (0..8).map(move |x| Coord(x, y)))
+            .find(|coord| self.get_piece_at(coord).is_some_and(|p| p.get_id() == id))
+    }
+
+    #[must_use]
+    pub fn get_piece_by_id(&self, id: PieceId) -> Option<Piece> {
+        self.get_coord_for_id(id)
+            .and_then(|coord| *self.get_piece_at(&coord))
+    }
+
+    /// Removes the piece with `id`, returning where it stood.
+    pub fn remove_by_id(&mut self, id: PieceId) -> Option<Coord> {
+        let coord = self.get_coord_for_id(id)?;
+        self.set_piece_at(None, coord);
+        Some(coord)
+    }
+
+    /// Gives every piece without an id the board's next one, from a1 to h8, so that boards set
+    /// up the same way number their pieces the same way.
+    fn assign_piece_ids(&mut self) {
+        for y in 0..8 {
+            for x in 0..8 {
+                if let Some(piece) = self.pieces[y][x] {
+                    self.pieces[y][x] = Some(self.with_id(piece));
+                }
+            }
+        }
+    }
+
+    /// Gives `piece` the board's next id if it has none. Once the ids run out they start
+    /// over, skipping the ones still on the board.
+    fn with_id(&mut self, mut piece: Piece) -> Piece {
+        if piece.get_id() == NO_PIECE_ID {
+            while self.get_coord_for_id(self.next_piece_id).is_some() {
+                self.next_piece_id = Self::id_after(self.next_piece_id);
+            }
+            piece.get_state_mut().id = self.next_piece_id;
+            self.next_piece_id = Self::id_after(self.next_piece_id);
+        }
+        piece
+    }
+
+    fn id_after(id: PieceId) -> PieceId {
+        id.checked_add(1).unwrap_or(NO_PIECE_ID + 1)
+    }
+
+    /// The board as a FEN string that `Board::from` reads back. Action chess has no side to
+    /// move, so it is always `w`, and each side's HP follows the move counters.
+    #[must_use]
+    pub fn fen(&self) -> String {
+        let ranks = self
+            .pieces
+            .iter()
+            .rev()
+            .map(|rank| {
+                let mut fen = String::new();
+                let mut empty = 0;
+                for square in rank {
+                    match square {
+                        Some(piece) => {
+                            if empty > 0 {
+                                fen.push_str(&empty.to_string());
+                                empty = 0;
+                            }
+                            fen.push(Board::fen_char(piece));
+                        }
+                        None => empty += 1,
+                    }
+                }
+                if empty > 0 {
+                    fen.push_str(&empty.to_string());
+                }
+                fen
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let castling = self
+            .castling_rights()
+            .into_iter()
+            .zip(['K', 'Q', 'k', 'q'])
+            .filter_map(|(allowed, right)| allowed.then_some(right))
+            .collect::<String>();
+        let castling = if castling.is_empty() {
+            "-".into()
+        } else {
+            castling
+        };
+
+        format!(
+            "{ranks} w {castling} - 0 1 {} {}",
+            self.white_hp, self.black_hp
+        )
+    }
+
+    fn fen_char(piece: &Piece) -> char {
+        let char = match piece {
+            Piece::Pawn(_, _) => 'p',
+            Piece::Knight(_, _) => 'n',
+            Piece::Bishop(_, _) => 'b',
+            Piece::Rook(_, _) => 'r',
+            Piece::Queen(_, _) => 'q',
+            Piece::King(_, _) => 'k',
+        };
+        match piece.get_color() {
+            Color::White => char.to_ascii_uppercase(),
+            Color::Black => char,
+        }
+    }
+
+    /// Moves the board one [`BOARD_TICK_RATE`] forward, see [`Board::advance`].
     pub fn tick(&mut self) {
+        self.advance(BOARD_TICK_RATE);
+    }
+
+    /**
+    Moves the board `elapsed` forward: cooldowns and status effects run down, energy
+    regenerates and each king loses 1 HP per attacking piece every [`BOARD_TICK_RATE`].
+
+    HP drains in proportion to the time passed, with part of a point carried over to the next
+    call, so advancing in many small steps ends the same as advancing once. Attacks only change
+    when a cooldown or effect runs out, so `elapsed` is split at those points and the attacks
+    counted again for each part.
+    */
+    pub fn advance(&mut self, mut elapsed: Duration) {
+        while !elapsed.is_zero() {
+            let step = self
+                .next_expiry()
+                .map_or(elapsed, |expiry| expiry.min(elapsed));
+            self.drain_hp(step);
+            self.decay_cooldowns(step);
+            self.decay_effects(step);
+            self.regenerate_energy(step);
+            elapsed -= step;
+        }
+    }
+
+    /// How long until the next cooldown or status effect on the board runs out.
+    fn next_expiry(&self) -> Option<Duration> {
+        self.pieces
+            .iter()
+            .flatten()
+            .flatten()
+            .flat_map(|piece| {
+                let effects = piece.get_state().effects().map(|timed| timed.remaining);
+                std::iter::once(piece.get_cooldown()).chain(effects)
+            })
+            .filter(|remaining| !remaining.is_zero())
+            .min()
+    }
+
+    fn drain_hp(&mut self, elapsed: Duration) {
+        let (attacks_on_white, attacks_on_black) = self.king_check_count();
+        if attacks_on_white == 0 && attacks_on_black == 0 {
+            return;
+        }
+        self.zobrist ^= self.extras_hash();
+        let white_lost = Self::hp_lost(&mut self.white_hp_drain, attacks_on_white, elapsed);
+        let black_lost = Self::hp_lost(&mut self.black_hp_drain, attacks_on_black, elapsed);
+        self.white_hp = self.white_hp.saturating_sub(white_lost);
+        self.black_hp = self.black_hp.saturating_sub(black_lost);
+        self.zobrist ^= self.extras_hash();
+    }
+
+    /// Adds `attacks` attackers for `elapsed` to `drain`, taking the whole HP off it.
+    fn hp_lost(drain: &mut Duration, attacks: u8, elapsed: Duration) -> usize {
+        *drain = drain.saturating_add(elapsed.saturating_mul(u32::from(attacks)));
+        let tick = BOARD_TICK_RATE.as_nanos();
+        let lost = drain.as_nanos() / tick;
+        *drain = Duration::from_nanos(u64::try_from(drain.as_nanos() % tick).unwrap_or_default());
+        usize::try_from(lost).unwrap_or(usize::MAX)
+    }
+
+    pub fn decay_cooldowns(&mut self, elapsed: Duration) {
         for y in 0..8 {
             for x in 0..8 {
                 if let Some(mut piece) = self.pieces[y][x] {
-                    let new_cd = piece.get_cooldown().saturating_sub(BOARD_TICK_RATE);
+                    let new_cd = piece.get_cooldown().saturating_sub(elapsed);
                     piece.set_cooldown(new_cd);
                     self.pieces[y][x] = Some(piece);
                 }
             }
         }
+    }
+
+    /// Runs down the status effects on every piece.
+    pub fn decay_effects(&mut self, elapsed: Duration) {
+        for piece in self.pieces.iter_mut().flatten().flatten() {
+            piece.get_state_mut().decay_effects(elapsed);
+        }
+    }
 
-        let (attacks_on_white, attacks_on_black) = self.king_check_count();
-        self.white_hp = self.white_hp.saturating_sub(attacks_on_white as usize);
-        self.black_hp = self.white_hp.saturating_sub(attacks_on_black as usize);
+    /// The piece that would be moved by `mv`. For castling this is the king.
+    #[must_use]
+    pub fn get_piece_for_move(&self, mv: &Move) -> Option<Piece> {
+        match mv {
+            Move::Piece(from, _) | Move::Promotion(from, _, _) => *self.get_piece_at(from),
+            Move::KingSideCastle(color) | Move::QueenSideCastle(color) => self
+                .get_piece_at(&Coord(4, Board::castle_rank(*color)))
+                .filter(|piece| piece.is_king() && piece.get_color() == *color),
+        }
+    }
+
+    /// The game is won by reducing the enemy king to 0 HP or by capturing it.
+    #[must_use]
+    pub fn winner(&self) -> Option<Color> {
+        let white_king =
+            self.get_coord_for_piece(&Piece::King(Color::White, Duration::ZERO.into()));
+        let black_king =
+            self.get_coord_for_piece(&Piece::King(Color::Black, Duration::ZERO.into()));
+
+        if self.white_hp == 0 || white_king.is_none() {
+            Some(Color::Black)
+        } else if self.black_hp == 0 || black_king.is_none() {
+            Some(Color::White)
+        } else {
+            None
+        }
     }
 
     #[must_use]
@@ -243,39 +627,35 @@ impl Board {
         let mut attack_positions: HashSet<Coord> = HashSet::new();
 
         let white_king_pos =
-            self.get_coord_for_piece(&Piece::King(Color::White, Duration::default()));
+            self.get_coord_for_piece(&Piece::King(Color::White, Duration::default().into()));
         let black_king_pos =
-            self.get_coord_for_piece(&Piece::King(Color::Black, Duration::default()));
+            self.get_coord_for_piece(&Piece::King(Color::Black, Duration::default().into()));
 
         for mv in gen.get_possible_moves() {
             match (mv, white_king_pos) {
-                (Move::Piece(_, to), Some(white_king_pos)) => {
-                    if to == white_king_pos {
-                        attacks_on_white += 1;
-                    }
+                (Move::Piece(_, to), Some(white_king_pos)) if to == white_king_pos => {
+                    attacks_on_white += 1;
                 }
-                (Move::Promotion(from, to, _), Some(white_king_pos)) => {
-                    if to == white_king_pos && !attack_positions.contains(&from) {
-                        attack_positions.insert(from);
-                        attacks_on_white += 1;
-                    }
+                (Move::Promotion(from, to, _), Some(white_king_pos))
+                    if to == white_king_pos && !attack_positions.contains(&from) =>
+                {
+                    attack_positions.insert(from);
+                    attacks_on_white += 1;
                 }
                 _ => {}
-            };
+            }
             match (mv, black_king_pos) {
-                (Move::Piece(_, to), Some(black_king_pos)) => {
-                    if to == black_king_pos {
-                        attacks_on_black += 1;
-                    }
+                (Move::Piece(_, to), Some(black_king_pos)) if to == black_king_pos => {
+                    attacks_on_black += 1;
                 }
-                (Move::Promotion(from, to, _), Some(black_king_pos)) => {
-                    if to == black_king_pos && !attack_positions.contains(&from) {
-                        attack_positions.insert(from);
-                        attacks_on_black += 1;
-                    }
+                (Move::Promotion(from, to, _), Some(black_king_pos))
+                    if to == black_king_pos && !attack_positions.contains(&from) =>
+                {
+                    attack_positions.insert(from);
+                    attacks_on_black += 1;
                 }
                 _ => {}
-            };
+            }
         }
 
         (attacks_on_white, attacks_on_black)
@@ -283,7 +663,10 @@ impl Board {
 
     #[must_use]
     pub fn is_valid_move(&self, mv: Move) -> bool {
-        MoveGen::new(self).get_possible_moves().contains(&mv)
+        let affordable = self
+            .get_piece_for_move(&mv)
+            .is_some_and(|piece| self.can_afford(piece.get_color(), self.move_cost()));
+        affordable && MoveGen::new(self).get_possible_moves().contains(&mv)
     }
 }
 
@@ -306,23 +689,22 @@ impl From<&str> for Board {
                     for _ in 0..empties {
                         pieces[rank][file] = None;
                         file += 1;
-                        continue;
                     }
                 }
             }
             let piece = match c {
-                'p' => Some(Piece::Pawn(Color::Black, COOLDOWN_PAWN)),
-                'b' => Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP)),
-                'n' => Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT)),
-                'r' => Some(Piece::Rook(Color::Black, COOLDOWN_ROOK)),
-                'q' => Some(Piece::Queen(Color::Black, COOLDOWN_QUEEN)),
-                'k' => Some(Piece::King(Color::Black, COOLDOWN_KING)),
-                'P' => Some(Piece::Pawn(Color::White, COOLDOWN_PAWN)),
-                'B' => Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP)),
-                'N' => Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT)),
-                'R' => Some(Piece::Rook(Color::White, COOLDOWN_ROOK)),
-                'Q' => Some(Piece::Queen(Color::White, COOLDOWN_QUEEN)),
-                'K' => Some(Piece::King(Color::White, COOLDOWN_KING)),
+                'p' => Some(Piece::Pawn(Color::Black, COOLDOWN_PAWN.into())),
+                'b' => Some(Piece::Bishop(Color::Black, COOLDOWN_BISHOP.into())),
+                'n' => Some(Piece::Knight(Color::Black, COOLDOWN_KNIGHT.into())),
+                'r' => Some(Piece::Rook(Color::Black, COOLDOWN_ROOK.into())),
+                'q' => Some(Piece::Queen(Color::Black, COOLDOWN_QUEEN.into())),
+                'k' => Some(Piece::King(Color::Black, COOLDOWN_KING.into())),
+                'P' => Some(Piece::Pawn(Color::White, COOLDOWN_PAWN.into())),
+                'B' => Some(Piece::Bishop(Color::White, COOLDOWN_BISHOP.into())),
+                'N' => Some(Piece::Knight(Color::White, COOLDOWN_KNIGHT.into())),
+                'R' => Some(Piece::Rook(Color::White, COOLDOWN_ROOK.into())),
+                'Q' => Some(Piece::Queen(Color::White, COOLDOWN_QUEEN.into())),
+                'K' => Some(Piece::King(Color::White, COOLDOWN_KING.into())),
                 _ => None,
             };
             if file < 8 {
@@ -352,7 +734,7 @@ impl From<&str> for Board {
         let white_king_hp = parts.get(6).and_then(|hp| hp.parse().ok()).unwrap_or(1000);
         let black_king_hp = parts.get(7).and_then(|hp| hp.parse().ok()).unwrap_or(1000);
 
-        Board {
+        let mut board = Board {
             pieces,
             white_can_castle_kingside: white_kingside,
             white_can_castle_queenside: white_queenside,
@@ -360,7 +742,19 @@ impl From<&str> for Board {
             black_can_castle_queenside: black_queenside,
             white_hp: white_king_hp,
             black_hp: black_king_hp,
-        }
+            white_hp_drain: Duration::ZERO,
+            black_hp_drain: Duration::ZERO,
+            white_energy: 0,
+            black_energy: 0,
+            energy_regen_progress: Duration::ZERO,
+            rules: Rules::default(),
+            history: SquareHistory::default(),
+            next_piece_id: NO_PIECE_ID + 1,
+            zobrist: 0,
+        };
+        board.assign_piece_ids();
+        board.rehash();
+        board
     }
 }
 
@@ -376,7 +770,16 @@ impl fmt::Display for Board {
         let trip_dash = "\u{02501}\u{02501}\u{02501}";
         let pipe = "\u{02503}";
 
-        let mut render = format!("\t\tBlack HP: {}\n\n", self.black_hp);
+        let energy = |energy: usize| match self.rules.energy {
+            Some(rules) => format!("\tEnergy: {energy}/{}", rules.max),
+            None => String::new(),
+        };
+
+        let mut render = format!(
+            "\t\tBlack HP: {}{}\n\n",
+            self.black_hp,
+            energy(self.black_energy)
+        );
         render = format!("{render}      A | B | C | D | E | F | G | H \n");
         render = format!("{render}    \u{0250F}{}\u{02513}\n", [dash; 31].join(""));
 
@@ -400,7 +803,11 @@ impl fmt::Display for Board {
         }
 
         render = format!("{render}      A | B | C | D | E | F | G | H \n");
-        render = format!("{render}\n\t\tWhite HP: {}", self.white_hp);
+        render = format!(
+            "{render}\n\t\tWhite HP: {}{}",
+            self.white_hp,
+            energy(self.white_energy)
+        );
 
         write!(f, "{render}")
     }
@@ -0,0 +1,148 @@
+use std::{collections::VecDeque, time::Duration};
+
+use super::{CardError, CardId, CardKind};
+use crate::rules::Rules;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldCard {
+    pub id: CardId,
+    pub kind: CardKind,
+}
+
+/**
+A player's cards during a game: the draw pile, the hand and the discard pile.
+
+Cards are drawn from the top of the draw pile in the order the deck was built. A card is drawn
+every `card_draw_interval` of game time while the hand has room. Played cards go to the discard
+pile, which becomes the new draw pile once the draw pile runs out. Playing a card puts the whole
+hand on a cooldown of the card's cost.
+*/
+#[derive(Debug, Clone)]
+pub struct Deck {
+    draw_pile: VecDeque<HeldCard>,
+    hand: Vec<HeldCard>,
+    discard: Vec<HeldCard>,
+    until_next_draw: Duration,
+    cooldown: Duration,
+    rules: Rules,
+}
+
+impl Deck {
+    /// Builds a deck from `cards`, numbering them in order, and draws the starting hand.
+    #[must_use]
+    pub fn new(cards: &[CardKind], rules: Rules) -> Self {
+        let draw_pile = cards
+            .iter()
+            .zip(0..=CardId::MAX)
+            .map(|(kind, id)| HeldCard { id, kind: *kind })
+            .collect();
+
+        let mut deck = Self {
+            draw_pile,
+            hand: vec![],
+            discard: vec![],
+            until_next_draw: rules.card_draw_interval,
+            cooldown: Duration::ZERO,
+            rules,
+        };
+        for _ in 0..rules.starting_hand {
+            deck.draw();
+        }
+        deck
+    }
+
+    #[must_use]
+    pub fn hand(&self) -> &[HeldCard] {
+        &self.hand
+    }
+
+    #[must_use]
+    pub fn draw_pile_len(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    #[must_use]
+    pub fn discard_len(&self) -> usize {
+        self.discard.len()
+    }
+
+    /// Game time left until the next card is drawn.
+    #[must_use]
+    pub fn until_next_draw(&self) -> Duration {
+        self.until_next_draw
+    }
+
+    /// Game time left until another card can be played.
+    #[must_use]
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    #[must_use]
+    pub fn get_card_in_hand(&self, id: CardId) -> Option<HeldCard> {
+        self.hand.iter().find(|card| card.id == id).copied()
+    }
+
+    /// Draws the top card into the hand, unless the hand is full or there is nothing to draw.
+    pub fn draw(&mut self) -> Option<HeldCard> {
+        if self.hand.len() >= self.rules.hand_size {
+            return None;
+        }
+        if self.draw_pile.is_empty() {
+            self.draw_pile.extend(self.discard.drain(..));
+        }
+        let card = self.draw_pile.pop_front()?;
+        self.hand.push(card);
+        Some(card)
+    }
+
+    /// Advances the draw timer and the cooldown by `elapsed` and returns the cards drawn
+    /// meanwhile.
+    pub fn advance(&mut self, mut elapsed: Duration) -> Vec<HeldCard> {
+        self.cooldown = self.cooldown.saturating_sub(elapsed);
+        let mut drawn = vec![];
+        if self.rules.card_draw_interval.is_zero() {
+            return drawn;
+        }
+        while elapsed >= self.until_next_draw {
+            elapsed -= self.until_next_draw;
+            self.until_next_draw = self.rules.card_draw_interval;
+            drawn.extend(self.draw());
+        }
+        self.until_next_draw -= elapsed;
+        drawn
+    }
+
+    /// # Errors
+    /// Returns an error if the card is not in the hand or cards are on cooldown.
+    pub fn check_play(&self, id: CardId) -> Result<HeldCard, CardError> {
+        let card = self.get_card_in_hand(id).ok_or(CardError::NotInHand(id))?;
+        if !self.cooldown.is_zero() {
+            return Err(CardError::Cooldown(self.cooldown));
+        }
+        Ok(card)
+    }
+
+    /// Removes a card from the hand, puts it on the discard pile and starts the cooldown.
+    /// Does not check the cooldown, see [`Deck::check_play`].
+    pub fn play(&mut self, id: CardId) -> Option<HeldCard> {
+        let index = self.hand.iter().position(|card| card.id == id)?;
+        let card = self.hand.remove(index);
+        self.discard.push(card);
+        self.cooldown = card.kind.card().cost();
+        Some(card)
+    }
+}
+
+/// The deck every player starts with until decks can be built.
+pub const STARTER_DECK: [CardKind; 9] = [
+    CardKind::Rewind,
+    CardKind::Duplicate,
+    CardKind::Transform,
+    CardKind::Rewind,
+    CardKind::Transform,
+    CardKind::Duplicate,
+    CardKind::Rewind,
+    CardKind::Transform,
+    CardKind::Duplicate,
+];
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use super::{expect_targets, own_piece, Card, CardError, CardKind};
+use crate::{
+    board::Board, cooldowns::COST_DUPLICATE, coordinate::Coord, energy::ENERGY_DUPLICATE,
+    piece::Color, piece_state::NO_PIECE_ID,
+};
+
+/**
+Copies one of the player's pieces onto an empty neighbouring square. Kings cannot be copied,
+pawns cannot be copied onto the first or last rank, and the copy starts on a full cooldown
+with a new id. Targets are the piece to copy and the square to put the copy on.
+*/
+#[derive(Debug)]
+pub struct Duplicate;
+
+impl Card for Duplicate {
+    fn kind(&self) -> CardKind {
+        CardKind::Duplicate
+    }
+
+    fn cost(&self) -> Duration {
+        COST_DUPLICATE
+    }
+
+    fn energy_cost(&self) -> usize {
+        ENERGY_DUPLICATE
+    }
+
+    fn validate(&self, board: &Board, color: Color, targets: &[Coord]) -> Result<(), CardError> {
+        expect_targets(targets, 2)?;
+        let (from, to) = (targets[0], targets[1]);
+        let piece = own_piece(board, color, from)?;
+
+        if piece.is_king() {
+            return Err(CardError::InvalidTarget(from));
+        }
+        if !to.is_valid() || (from.0 - to.0).abs() > 1 || (from.1 - to.1).abs() > 1 || from == to {
+            return Err(CardError::InvalidTarget(to));
+        }
+        if board.get_piece_at(&to).is_some() {
+            return Err(CardError::OccupiedSquare(to));
+        }
+        if piece.is_pawn() && (to.1 == 0 || to.1 == 7) {
+            return Err(CardError::InvalidTarget(to));
+        }
+        Ok(())
+    }
+
+    fn apply(&self, board: &mut Board, _color: Color, targets: &[Coord]) {
+        if let Some(mut copy) = *board.get_piece_at(&targets[0]) {
+            copy.set_cooldown(board.rules.cooldowns.for_piece(&copy));
+            copy.get_state_mut().id = NO_PIECE_ID;
+            board.place_piece_at(Some(copy), targets[1]);
+        }
+    }
+}
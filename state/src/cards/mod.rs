@@ -0,0 +1,230 @@
+pub mod deck;
+pub mod duplicate;
+pub mod rewind;
+pub mod transform;
+
+use std::{
+    fmt::{Debug, Display},
+    time::Duration,
+};
+
+use crate::{
+    board::Board,
+    coordinate::Coord,
+    history::{GameEvent, RewindError},
+    piece::{Color, Piece},
+    piece_state::PieceId,
+};
+
+use duplicate::Duplicate;
+use rewind::{Rewind, REWIND_STEPS};
+use transform::Transform;
+
+/// Identifies one card in a player's deck, so that two copies of the same card can be told apart.
+pub type CardId = u8;
+
+/**
+A card is a one-off effect on the board. Cards never check whether the player can afford
+them, only whether the targets make sense for the player playing the card.
+*/
+pub trait Card: Debug + Sync {
+    fn kind(&self) -> CardKind;
+
+    /// How long the player has to wait before playing another card.
+    fn cost(&self) -> Duration;
+
+    /// The energy the card costs when the game has an energy economy.
+    fn energy_cost(&self) -> usize;
+
+    /// # Errors
+    /// Returns why `targets` cannot be used by `color` on `board`.
+    fn validate(&self, board: &Board, color: Color, targets: &[Coord]) -> Result<(), CardError>;
+
+    /// Applies the effect without validating it first.
+    fn apply(&self, board: &mut Board, color: Color, targets: &[Coord]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardKind {
+    Rewind,
+    Duplicate,
+    Transform,
+}
+
+impl CardKind {
+    pub const ALL: [CardKind; 3] = [CardKind::Rewind, CardKind::Duplicate, CardKind::Transform];
+
+    #[must_use]
+    pub fn card(self) -> &'static dyn Card {
+        match self {
+            CardKind::Rewind => &Rewind,
+            CardKind::Duplicate => &Duplicate,
+            CardKind::Transform => &Transform,
+        }
+    }
+}
+
+impl Display for CardKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CardKind::Rewind => "Rewind",
+            CardKind::Duplicate => "Duplicate",
+            CardKind::Transform => "Transform",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardError {
+    WrongTargetCount { expected: usize, actual: usize },
+    EmptySquare(Coord),
+    OccupiedSquare(Coord),
+    NotYourPiece(Coord),
+    InvalidTarget(Coord),
+    NotInHand(CardId),
+    Cooldown(Duration),
+    Rewind(RewindError),
+    NotEnoughEnergy { required: usize, available: usize },
+    UnknownPiece(PieceId),
+}
+
+impl Display for CardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardError::WrongTargetCount { expected, actual } => {
+                write!(f, "expected {expected} targets, got {actual}")
+            }
+            CardError::EmptySquare(coord) => write!(f, "{coord} is empty"),
+            CardError::OccupiedSquare(coord) => write!(f, "{coord} is occupied"),
+            CardError::NotYourPiece(coord) => write!(f, "the piece on {coord} is not yours"),
+            CardError::InvalidTarget(coord) => write!(f, "{coord} cannot be targeted"),
+            CardError::NotInHand(id) => write!(f, "card {id} is not in hand"),
+            CardError::Cooldown(remaining) => {
+                write!(f, "cards are on cooldown for {}ms", remaining.as_millis())
+            }
+            CardError::Rewind(error) => write!(f, "{error}"),
+            CardError::NotEnoughEnergy {
+                required,
+                available,
+            } => write!(f, "card needs {required} energy, only {available} left"),
+            CardError::UnknownPiece(id) => write!(f, "there is no piece {id} on the board"),
+        }
+    }
+}
+
+/// A card being played by `color` on `targets`, the card equivalent of a `Move`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardPlay {
+    pub kind: CardKind,
+    pub color: Color,
+    pub targets: Vec<Coord>,
+}
+
+/// A card target as chosen by a player: a square, or a piece wherever it currently stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardTarget {
+    Square(Coord),
+    Piece(PieceId),
+}
+
+impl CardTarget {
+    /// The piece standing on `coord` by id, or the square itself if it is empty.
+    #[must_use]
+    pub fn on(board: &Board, coord: Coord) -> Self {
+        board
+            .get_piece_at(&coord)
+            .map_or(CardTarget::Square(coord), |piece| {
+                CardTarget::Piece(piece.get_id())
+            })
+    }
+
+    /**
+    The square the target currently refers to on `board`.
+
+    # Errors
+    Returns [`CardError::UnknownPiece`] if a targeted piece is not on the board.
+    */
+    pub fn resolve(self, board: &Board) -> Result<Coord, CardError> {
+        match self {
+            CardTarget::Square(coord) => Ok(coord),
+            CardTarget::Piece(id) => board
+                .get_coord_for_id(id)
+                .ok_or(CardError::UnknownPiece(id)),
+        }
+    }
+}
+
+impl CardPlay {
+    /// # Errors
+    /// Returns why the card cannot be played on `board`.
+    pub fn validate(&self, board: &Board) -> Result<(), CardError> {
+        let card = self.kind.card();
+        if !board.can_afford(self.color, card.energy_cost()) {
+            return Err(CardError::NotEnoughEnergy {
+                required: card.energy_cost(),
+                available: board.get_energy(self.color),
+            });
+        }
+        card.validate(board, self.color, &self.targets)
+    }
+
+    /// The event to record in a game's history, for `board` before the card is played. Rewinds
+    /// are recorded as such, so that replaying them does not depend on how far the card rewinds.
+    #[must_use]
+    pub fn event(&self, board: &Board) -> GameEvent {
+        match (self.kind, self.targets.as_slice()) {
+            (CardKind::Rewind, &[coord]) => GameEvent::Rewind {
+                color: self.color,
+                coord,
+                steps: REWIND_STEPS,
+            },
+            _ => GameEvent::Card {
+                kind: self.kind,
+                color: self.color,
+                targets: self
+                    .targets
+                    .iter()
+                    .map(|coord| CardTarget::on(board, *coord))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl Board {
+    /// Applies `play` to the board without checking that it is valid, see [`CardPlay::validate`].
+    /// With an energy economy the player pays the card's energy cost.
+    pub fn process_card_play(&mut self, play: &CardPlay) -> &Self {
+        let card = play.kind.card();
+        self.spend_energy(play.color, card.energy_cost());
+        card.apply(self, play.color, &play.targets);
+        self
+    }
+}
+
+pub(crate) fn expect_targets(targets: &[Coord], expected: usize) -> Result<(), CardError> {
+    if targets.len() == expected {
+        Ok(())
+    } else {
+        Err(CardError::WrongTargetCount {
+            expected,
+            actual: targets.len(),
+        })
+    }
+}
+
+/// The piece on `coord`, which has to belong to `color`.
+pub(crate) fn own_piece(board: &Board, color: Color, coord: Coord) -> Result<Piece, CardError> {
+    if !coord.is_valid() {
+        return Err(CardError::InvalidTarget(coord));
+    }
+    let piece = board
+        .get_piece_at(&coord)
+        .ok_or(CardError::EmptySquare(coord))?;
+    if piece.get_color() == color {
+        Ok(piece)
+    } else {
+        Err(CardError::NotYourPiece(coord))
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use super::{expect_targets, Card, CardError, CardKind};
+use crate::{
+    board::Board, cooldowns::COST_REWIND, coordinate::Coord, energy::ENERGY_REWIND, piece::Color,
+};
+
+/// How many changes to its square a Rewind card undoes.
+pub const REWIND_STEPS: usize = 1;
+
+/// Rewinds a square: whatever stood there before the last change to it is put back.
+#[derive(Debug)]
+pub struct Rewind;
+
+impl Card for Rewind {
+    fn kind(&self) -> CardKind {
+        CardKind::Rewind
+    }
+
+    fn cost(&self) -> Duration {
+        COST_REWIND
+    }
+
+    fn energy_cost(&self) -> usize {
+        ENERGY_REWIND
+    }
+
+    fn validate(&self, board: &Board, _color: Color, targets: &[Coord]) -> Result<(), CardError> {
+        expect_targets(targets, 1)?;
+        board
+            .validate_rewind(targets[0], REWIND_STEPS)
+            .map_err(CardError::Rewind)
+    }
+
+    fn apply(&self, board: &mut Board, _color: Color, targets: &[Coord]) {
+        let _ = board.rewind_square(targets[0], REWIND_STEPS);
+    }
+}
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use super::{expect_targets, own_piece, Card, CardError, CardKind};
+use crate::{
+    board::Board,
+    cooldowns::COST_TRANSFORM,
+    coordinate::Coord,
+    energy::ENERGY_TRANSFORM,
+    piece::{Color, Piece},
+    piece_state::PieceState,
+};
+
+/**
+Turns one of the player's pieces into the kind of piece standing on another square, which may
+belong to either player. Kings cannot be transformed, and the new kind follows the promotion
+rules: a queen, rook, bishop or knight. The transformed piece keeps its id and status effects
+and starts on its full cooldown. Targets are the piece to transform and the piece to copy the kind of.
+*/
+#[derive(Debug)]
+pub struct Transform;
+
+impl Card for Transform {
+    fn kind(&self) -> CardKind {
+        CardKind::Transform
+    }
+
+    fn cost(&self) -> Duration {
+        COST_TRANSFORM
+    }
+
+    fn energy_cost(&self) -> usize {
+        ENERGY_TRANSFORM
+    }
+
+    fn validate(&self, board: &Board, color: Color, targets: &[Coord]) -> Result<(), CardError> {
+        expect_targets(targets, 2)?;
+        let (target, template) = (targets[0], targets[1]);
+        let piece = own_piece(board, color, target)?;
+
+        if piece.is_king() {
+            return Err(CardError::InvalidTarget(target));
+        }
+        let Some(template_piece) = board.get_piece_at(&template) else {
+            return Err(CardError::EmptySquare(template));
+        };
+        if !Piece::Pawn(color, *piece.get_state()).can_promote_to(&Transform::transformed(
+            template_piece,
+            color,
+            *piece.get_state(),
+        )) {
+            return Err(CardError::InvalidTarget(template));
+        }
+        Ok(())
+    }
+
+    fn apply(&self, board: &mut Board, color: Color, targets: &[Coord]) {
+        if let (Some(target), Some(template)) = (
+            *board.get_piece_at(&targets[0]),
+            *board.get_piece_at(&targets[1]),
+        ) {
+            let mut piece = Transform::transformed(&template, color, *target.get_state());
+            piece.set_cooldown(board.rules.cooldowns.for_piece(&piece));
+            board.place_piece_at(Some(piece), targets[0]);
+        }
+    }
+}
+
+impl Transform {
+    fn transformed(template: &Piece, color: Color, state: PieceState) -> Piece {
+        match template {
+            Piece::Pawn(_, _) => Piece::Pawn(color, state),
+            Piece::Knight(_, _) => Piece::Knight(color, state),
+            Piece::Bishop(_, _) => Piece::Bishop(color, state),
+            Piece::Rook(_, _) => Piece::Rook(color, state),
+            Piece::Queen(_, _) => Piece::Queen(color, state),
+            Piece::King(_, _) => Piece::King(color, state),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+/**
+Where the server and clients get the time from. Times are durations since the clock was
+created, so a [`ManualClock`] can stand in for a [`RealClock`] and tests can move time
+forward exactly.
+*/
+pub trait Clock: Debug + Send + Sync {
+    /// The time since the clock was created.
+    fn now(&self) -> Duration;
+
+    /// Waits until `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The wall clock.
+#[derive(Debug, Clone, Copy)]
+pub struct RealClock {
+    started_at: Instant,
+}
+
+impl RealClock {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Default for RealClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for RealClock {
+    fn now(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time, so a test can keep one
+/// and hand the others out.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, elapsed: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now += elapsed;
+        }
+    }
+
+    pub fn set(&self, time: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now = time;
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.lock().map(|now| *now).unwrap_or_default()
+    }
+
+    /// Moves the clock forward by `duration` straight away, so that loops waiting on it run in
+    /// virtual time.
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
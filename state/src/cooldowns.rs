@@ -3,8 +3,16 @@ use std::time::Duration;
 pub const COOLDOWN_PAWN: Duration = Duration::from_millis(500);
 pub const COOLDOWN_KNIGHT: Duration = Duration::from_millis(1500);
 pub const COOLDOWN_BISHOP: Duration = Duration::from_millis(1500);
-pub const COOLDOWN_ROOK: Duration = Duration::from_millis(5000);
-pub const COOLDOWN_QUEEN: Duration = Duration::from_millis(9000);
+pub const COOLDOWN_ROOK: Duration = Duration::from_secs(5);
+pub const COOLDOWN_QUEEN: Duration = Duration::from_secs(9);
 pub const COOLDOWN_KING: Duration = Duration::from_millis(1500);
 
+/// A promoted piece inherits the cooldown of the pawn that moved.
+pub const COOLDOWN_PROMOTION: Duration = COOLDOWN_PAWN;
+
+/// After playing a card, a player cannot play another one until the card's cost has passed.
+pub const COST_REWIND: Duration = Duration::from_secs(4);
+pub const COST_DUPLICATE: Duration = Duration::from_secs(8);
+pub const COST_TRANSFORM: Duration = Duration::from_secs(6);
+
 pub const BOARD_TICK_RATE: Duration = Duration::from_millis(16);
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use crate::{board::Board, coordinate::Coord, piece::Piece, piece_state::PieceId};
+
+/**
+Everything that differs between two boards, see [`Board::diff`].
+
+Square history and energy regeneration progress are not part of a delta, as they are not part
+of a snapshot either.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoardDelta {
+    /// Squares that hold a different piece, or the same piece with different effects, along
+    /// with what stands there now.
+    pub squares: Vec<(Coord, Option<Piece>)>,
+    /// Squares that hold the same piece with only its cooldown changed.
+    pub cooldowns: Vec<(Coord, Duration)>,
+    /// White and Black HP.
+    pub hp: Option<(usize, usize)>,
+    /// White and Black energy.
+    pub energy: Option<(usize, usize)>,
+    /// Castling rights in the order White king side, White queen side, Black king side, Black
+    /// queen side.
+    pub castling: Option<[bool; 4]>,
+    pub next_piece_id: Option<PieceId>,
+}
+
+impl BoardDelta {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == BoardDelta::default()
+    }
+}
+
+impl Board {
+    /// The changes that turn `self` into `other`.
+    #[must_use]
+    pub fn diff(&self, other: &Board) -> BoardDelta {
+        let mut delta = BoardDelta::default();
+
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                match (*self.get_piece_at(&coord), *other.get_piece_at(&coord)) {
+                    (None, None) => {}
+                    (Some(before), Some(after)) if Board::same_but_cooldown(before, after) => {
+                        if before.get_cooldown() != after.get_cooldown() {
+                            delta.cooldowns.push((coord, after.get_cooldown()));
+                        }
+                    }
+                    (_, after) => delta.squares.push((coord, after)),
+                }
+            }
+        }
+
+        let hp = (other.white_hp, other.black_hp);
+        if (self.white_hp, self.black_hp) != hp {
+            delta.hp = Some(hp);
+        }
+        let energy = (other.white_energy, other.black_energy);
+        if (self.white_energy, self.black_energy) != energy {
+            delta.energy = Some(energy);
+        }
+        if self.castling_rights() != other.castling_rights() {
+            delta.castling = Some(other.castling_rights());
+        }
+        if self.next_piece_id != other.next_piece_id {
+            delta.next_piece_id = Some(other.next_piece_id);
+        }
+
+        delta
+    }
+
+    /// Applies a delta made by [`Board::diff`]. Pieces are put on the board exactly as they are
+    /// in the delta, ids included.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn apply_delta(&mut self, delta: &BoardDelta) -> &Self {
+        for (coord, piece) in &delta.squares {
+            if coord.is_valid() {
+                self.pieces[coord.1 as usize][coord.0 as usize] = *piece;
+            }
+        }
+        for (coord, cooldown) in &delta.cooldowns {
+            if let Some(mut piece) = *self.get_piece_at(coord) {
+                piece.set_cooldown(*cooldown);
+                self.pieces[coord.1 as usize][coord.0 as usize] = Some(piece);
+            }
+        }
+
+        if let Some((white, black)) = delta.hp {
+            self.white_hp = white;
+            self.black_hp = black;
+        }
+        if let Some((white, black)) = delta.energy {
+            self.white_energy = white;
+            self.black_energy = black;
+        }
+        if let Some([white_kingside, white_queenside, black_kingside, black_queenside]) =
+            delta.castling
+        {
+            self.white_can_castle_kingside = white_kingside;
+            self.white_can_castle_queenside = white_queenside;
+            self.black_can_castle_kingside = black_kingside;
+            self.black_can_castle_queenside = black_queenside;
+        }
+        if let Some(next_piece_id) = delta.next_piece_id {
+            self.next_piece_id = next_piece_id;
+        }
+        self.rehash();
+
+        self
+    }
+
+    fn same_but_cooldown(before: Piece, after: Piece) -> bool {
+        let mut state = *before.get_state();
+        state.cooldown = after.get_cooldown();
+        before == after && state == *after.get_state()
+    }
+}
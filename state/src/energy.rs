@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use crate::{board::Board, piece::Color};
+
+/// Energy spent by playing a card, on top of the card's cooldown.
+pub const ENERGY_REWIND: usize = 3;
+pub const ENERGY_DUPLICATE: usize = 5;
+pub const ENERGY_TRANSFORM: usize = 4;
+
+/// The energy economy of a game. Games without it only use cooldowns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnergyRules {
+    /// The energy each player starts with.
+    pub starting: usize,
+    /// Energy stops regenerating once it reaches this.
+    pub max: usize,
+    /// Each player regains one energy per interval.
+    pub regen_interval: Duration,
+    /// The energy a move costs. Moves are free when this is 0.
+    pub move_cost: usize,
+}
+
+impl Default for EnergyRules {
+    fn default() -> Self {
+        Self {
+            starting: 5,
+            max: 10,
+            regen_interval: Duration::from_secs(1),
+            move_cost: 0,
+        }
+    }
+}
+
+impl Board {
+    /// Turns the energy economy on, filling both meters to the starting energy.
+    pub fn enable_energy(&mut self, energy: EnergyRules) {
+        self.rules.energy = Some(energy);
+        self.zobrist ^= self.extras_hash();
+        self.white_energy = energy.starting;
+        self.black_energy = energy.starting;
+        self.zobrist ^= self.extras_hash();
+        self.energy_regen_progress = Duration::ZERO;
+    }
+
+    #[must_use]
+    pub fn get_energy(&self, color: Color) -> usize {
+        match color {
+            Color::White => self.white_energy,
+            Color::Black => self.black_energy,
+        }
+    }
+
+    /// Whether `color` has `cost` energy to spend. Always true without an energy economy.
+    #[must_use]
+    pub fn can_afford(&self, color: Color, cost: usize) -> bool {
+        self.rules.energy.is_none() || self.get_energy(color) >= cost
+    }
+
+    /// The energy a move by `color` costs, 0 without an energy economy.
+    #[must_use]
+    pub fn move_cost(&self) -> usize {
+        self.rules.energy.map_or(0, |energy| energy.move_cost)
+    }
+
+    /// Spends up to `cost` of `color`'s energy. Does nothing without an energy economy.
+    pub fn spend_energy(&mut self, color: Color, cost: usize) {
+        if self.rules.energy.is_none() {
+            return;
+        }
+        self.zobrist ^= self.extras_hash();
+        let energy = match color {
+            Color::White => &mut self.white_energy,
+            Color::Black => &mut self.black_energy,
+        };
+        *energy = energy.saturating_sub(cost);
+        self.zobrist ^= self.extras_hash();
+    }
+
+    /// Regains one energy for both players for every regeneration interval in `elapsed`.
+    pub fn regenerate_energy(&mut self, elapsed: Duration) {
+        let Some(energy) = self.rules.energy else {
+            return;
+        };
+        if energy.regen_interval.is_zero() {
+            return;
+        }
+
+        self.energy_regen_progress = self.energy_regen_progress.saturating_add(elapsed);
+        let interval = energy.regen_interval.as_nanos();
+        let regained = self.energy_regen_progress.as_nanos() / interval;
+        if regained == 0 {
+            return;
+        }
+        self.energy_regen_progress = Duration::from_nanos(
+            u64::try_from(self.energy_regen_progress.as_nanos() % interval).unwrap_or_default(),
+        );
+        let regained = usize::try_from(regained).unwrap_or(usize::MAX);
+
+        self.zobrist ^= self.extras_hash();
+        self.white_energy = self.white_energy.saturating_add(regained).min(energy.max);
+        self.black_energy = self.black_energy.saturating_add(regained).min(energy.max);
+        self.zobrist ^= self.extras_hash();
+    }
+}
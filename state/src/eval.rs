@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use crate::{
+    board::Board,
+    coordinate::Coord,
+    movegen::{MoveGen, MoveGenOptions},
+    piece::{Color, Piece},
+};
+
+/// Score per legal move a side has more than the other, counting pieces on cooldown too.
+pub const MOBILITY_WEIGHT: i32 = 5;
+
+/// Score per piece a side has off cooldown more than the other.
+pub const TEMPO_WEIGHT: i32 = 10;
+
+/// King HP per point of score. Kings start at 5000 HP, so a full bar is worth five pawns.
+pub const HP_PER_POINT: usize = 10;
+
+/**
+How a [`Board`] scores, term by term, in centipawns from White's perspective: positive when
+White is better, negative when Black is.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Evaluation {
+    /// The value of each side's pieces, see [`piece_value`].
+    pub material: i32,
+    /// How well placed each piece is, from the piece-square tables.
+    pub placement: i32,
+    /// How many more moves White has than Black, ignoring cooldowns.
+    pub mobility: i32,
+    /// The difference in king HP.
+    pub king_hp: i32,
+    /// How many more of White's pieces are ready to move than Black's.
+    pub tempo: i32,
+}
+
+impl Evaluation {
+    #[must_use]
+    pub fn total(&self) -> i32 {
+        self.material + self.placement + self.mobility + self.king_hp + self.tempo
+    }
+
+    /// The total from `color`'s perspective.
+    #[must_use]
+    pub fn for_color(&self, color: Color) -> i32 {
+        match color {
+            Color::White => self.total(),
+            Color::Black => -self.total(),
+        }
+    }
+}
+
+impl Display for Evaluation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "material:  {:+}", self.material)?;
+        writeln!(f, "placement: {:+}", self.placement)?;
+        writeln!(f, "mobility:  {:+}", self.mobility)?;
+        writeln!(f, "king hp:   {:+}", self.king_hp)?;
+        writeln!(f, "tempo:     {:+}", self.tempo)?;
+        write!(f, "total:     {:+}", self.total())
+    }
+}
+
+impl Board {
+    /// Scores the board from White's perspective, see [`Evaluation`].
+    #[must_use]
+    pub fn evaluate(&self) -> Evaluation {
+        let mut evaluation = self.static_evaluation();
+
+        let options = MoveGenOptions {
+            ignore_cooldowns: true,
+        };
+        let gen = MoveGen::with_options(self, options);
+        let count = |color| {
+            i32::try_from(gen.get_possible_moves_for_color(color).len()).unwrap_or(i32::MAX)
+        };
+        evaluation.mobility = (count(Color::White) - count(Color::Black)) * MOBILITY_WEIGHT;
+
+        evaluation
+    }
+
+    /**
+    Every term of [`Board::evaluate`] except mobility, which needs moves generated and is by
+    far the slowest to compute. Meant for searches that evaluate many boards.
+    */
+    #[must_use]
+    pub fn static_evaluation(&self) -> Evaluation {
+        let mut evaluation = Evaluation::default();
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                let Some(piece) = self.get_piece_at(&coord) else {
+                    continue;
+                };
+                let sign = match piece.get_color() {
+                    Color::White => 1,
+                    Color::Black => -1,
+                };
+                evaluation.material += sign * piece_value(piece);
+                evaluation.placement += sign * square_value(piece, coord);
+                if piece.can_move() {
+                    evaluation.tempo += sign * TEMPO_WEIGHT;
+                }
+            }
+        }
+
+        let hp = |hp: usize| i32::try_from(hp / HP_PER_POINT).unwrap_or(i32::MAX);
+        evaluation.king_hp = hp(self.white_hp) - hp(self.black_hp);
+
+        evaluation
+    }
+}
+
+/// The material value of a piece in centipawns. Kings are not counted, losing one loses the game.
+#[must_use]
+pub fn piece_value(piece: &Piece) -> i32 {
+    match piece {
+        Piece::Pawn(_, _) => 100,
+        Piece::Knight(_, _) => 320,
+        Piece::Bishop(_, _) => 330,
+        Piece::Rook(_, _) => 500,
+        Piece::Queen(_, _) => 900,
+        Piece::King(_, _) => 0,
+    }
+}
+
+/// The piece-square table entry for `piece` standing on `coord`.
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+pub fn square_value(piece: &Piece, coord: Coord) -> i32 {
+    let table = match piece {
+        Piece::Pawn(_, _) => &PAWN_TABLE,
+        Piece::Knight(_, _) => &KNIGHT_TABLE,
+        Piece::Bishop(_, _) => &BISHOP_TABLE,
+        Piece::Rook(_, _) => &ROOK_TABLE,
+        Piece::Queen(_, _) => &QUEEN_TABLE,
+        Piece::King(_, _) => &KING_TABLE,
+    };
+    // The tables are written from White's side with the 8th rank first, Black reads them
+    // mirrored.
+    let row = match piece.get_color() {
+        Color::White => 7 - coord.1,
+        Color::Black => coord.1,
+    };
+    table[row as usize][coord.0 as usize]
+}
+
+type PieceSquareTable = [[i32; 8]; 8];
+
+const PAWN_TABLE: PieceSquareTable = [
+    [0, 0, 0, 0, 0, 0, 0, 0],
+    [50, 50, 50, 50, 50, 50, 50, 50],
+    [10, 10, 20, 30, 30, 20, 10, 10],
+    [5, 5, 10, 25, 25, 10, 5, 5],
+    [0, 0, 0, 20, 20, 0, 0, 0],
+    [5, -5, -10, 0, 0, -10, -5, 5],
+    [5, 10, 10, -20, -20, 10, 10, 5],
+    [0, 0, 0, 0, 0, 0, 0, 0],
+];
+
+const KNIGHT_TABLE: PieceSquareTable = [
+    [-50, -40, -30, -30, -30, -30, -40, -50],
+    [-40, -20, 0, 0, 0, 0, -20, -40],
+    [-30, 0, 10, 15, 15, 10, 0, -30],
+    [-30, 5, 15, 20, 20, 15, 5, -30],
+    [-30, 0, 15, 20, 20, 15, 0, -30],
+    [-30, 5, 10, 15, 15, 10, 5, -30],
+    [-40, -20, 0, 5, 5, 0, -20, -40],
+    [-50, -40, -30, -30, -30, -30, -40, -50],
+];
+
+const BISHOP_TABLE: PieceSquareTable = [
+    [-20, -10, -10, -10, -10, -10, -10, -20],
+    [-10, 0, 0, 0, 0, 0, 0, -10],
+    [-10, 0, 5, 10, 10, 5, 0, -10],
+    [-10, 5, 5, 10, 10, 5, 5, -10],
+    [-10, 0, 10, 10, 10, 10, 0, -10],
+    [-10, 10, 10, 10, 10, 10, 10, -10],
+    [-10, 5, 0, 0, 0, 0, 5, -10],
+    [-20, -10, -10, -10, -10, -10, -10, -20],
+];
+
+const ROOK_TABLE: PieceSquareTable = [
+    [0, 0, 0, 0, 0, 0, 0, 0],
+    [5, 10, 10, 10, 10, 10, 10, 5],
+    [-5, 0, 0, 0, 0, 0, 0, -5],
+    [-5, 0, 0, 0, 0, 0, 0, -5],
+    [-5, 0, 0, 0, 0, 0, 0, -5],
+    [-5, 0, 0, 0, 0, 0, 0, -5],
+    [-5, 0, 0, 0, 0, 0, 0, -5],
+    [0, 0, 0, 5, 5, 0, 0, 0],
+];
+
+const QUEEN_TABLE: PieceSquareTable = [
+    [-20, -10, -10, -5, -5, -10, -10, -20],
+    [-10, 0, 0, 0, 0, 0, 0, -10],
+    [-10, 0, 5, 5, 5, 5, 0, -10],
+    [-5, 0, 5, 5, 5, 5, 0, -5],
+    [0, 0, 5, 5, 5, 5, 0, -5],
+    [-10, 5, 5, 5, 5, 5, 0, -10],
+    [-10, 0, 5, 0, 0, 0, 0, -10],
+    [-20, -10, -10, -5, -5, -10, -10, -20],
+];
+
+/// Kings lose HP to every piece attacking them, so they are best kept tucked away.
+const KING_TABLE: PieceSquareTable = [
+    [-30, -40, -40, -50, -50, -40, -40, -30],
+    [-30, -40, -40, -50, -50, -40, -40, -30],
+    [-30, -40, -40, -50, -50, -40, -40, -30],
+    [-30, -40, -40, -50, -50, -40, -40, -30],
+    [-20, -30, -30, -40, -40, -30, -30, -20],
+    [-10, -20, -20, -20, -20, -20, -20, -10],
+    [20, 20, 0, 0, 0, 0, 20, 20],
+    [20, 30, 10, 0, 0, 10, 30, 20],
+];
@@ -0,0 +1,212 @@
+use std::{collections::VecDeque, fmt::Display, time::Duration};
+
+use crate::{
+    board::Board,
+    cards::{CardKind, CardPlay, CardTarget},
+    coordinate::Coord,
+    piece::{Color, Move, Piece},
+    piece_state::{PieceId, NO_PIECE_ID},
+};
+
+/// What stood on each square before its most recent changes, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct SquareHistory {
+    squares: [[VecDeque<Option<Piece>>; 8]; 8],
+}
+
+impl SquareHistory {
+    /// Remembers that `previous` stood on `coord`, forgetting the oldest entry beyond `depth`.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn record(&mut self, coord: Coord, previous: Option<Piece>, depth: usize) {
+        let square = &mut self.squares[coord.1 as usize][coord.0 as usize];
+        square.push_back(previous);
+        while square.len() > depth {
+            square.pop_front();
+        }
+    }
+
+    /// How many changes to `coord` can be rewound.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn depth(&self, coord: Coord) -> usize {
+        if !coord.is_valid() {
+            return 0;
+        }
+        self.squares[coord.1 as usize][coord.0 as usize].len()
+    }
+
+    /// What stood on `coord` `steps` changes ago.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn get(&self, coord: Coord, steps: usize) -> Option<Option<Piece>> {
+        let square = &self.squares[coord.1 as usize][coord.0 as usize];
+        let index = square.len().checked_sub(steps)?;
+        square.get(index).copied()
+    }
+
+    /// Forgets the last `steps` changes to `coord`.
+    #[allow(clippy::cast_sign_loss)]
+    fn truncate(&mut self, coord: Coord, steps: usize) {
+        let square = &mut self.squares[coord.1 as usize][coord.0 as usize];
+        square.truncate(square.len().saturating_sub(steps));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RewindError {
+    InvalidSquare(Coord),
+    NotEnoughHistory {
+        requested: usize,
+        available: usize,
+    },
+    /// Kings can neither be rewound away nor brought back.
+    King(Coord),
+    /// The rewind would put a king in check that was not in check before, counting pieces
+    /// that are still on cooldown.
+    ExposesKing(Coord),
+}
+
+impl Display for RewindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RewindError::InvalidSquare(coord) => write!(f, "{coord} is not on the board"),
+            RewindError::NotEnoughHistory {
+                requested,
+                available,
+            } => write!(
+                f,
+                "cannot rewind {requested} steps, only {available} are remembered"
+            ),
+            RewindError::King(coord) => write!(f, "cannot rewind the king square {coord}"),
+            RewindError::ExposesKing(coord) => {
+                write!(f, "rewinding {coord} would put a king in check")
+            }
+        }
+    }
+}
+
+/**
+Everything that changes the board during a game, in the order it happened. Pieces are referred
+to by id, so a replay can follow a piece through the game and card targets are found wherever
+the targeted piece stands.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    /// A move and the id of the piece that made it, the king's when castling.
+    Move { mv: Move, piece: PieceId },
+    /// A card play with its targets: the piece on a square by id, empty squares by coord.
+    Card {
+        kind: CardKind,
+        color: Color,
+        targets: Vec<CardTarget>,
+    },
+    /// A Rewind card played by `color`, who pays for it again on replay.
+    Rewind {
+        color: Color,
+        coord: Coord,
+        steps: usize,
+    },
+}
+
+impl Board {
+    /**
+    Restores `coord` to what stood there `steps` changes ago. The restored piece starts on its
+    standard cooldown and the rewound changes are forgotten. A restored piece that is still on
+    the board elsewhere is a copy and gets a new id.
+
+    # Errors
+    Returns an error and leaves the board untouched if not enough history is remembered, if a
+    king would be removed or restored, or if a king would be attacked more than before.
+    */
+    pub fn rewind_square(&mut self, coord: Coord, steps: usize) -> Result<(), RewindError> {
+        let restored = self.check_rewind(coord, steps)?;
+        self.history.truncate(coord, steps);
+        self.set_piece_at(restored, coord);
+        Ok(())
+    }
+
+    /**
+    # Errors
+    Returns why [`Board::rewind_square`] would refuse to rewind `coord`.
+    */
+    pub fn validate_rewind(&self, coord: Coord, steps: usize) -> Result<(), RewindError> {
+        self.check_rewind(coord, steps).map(|_| ())
+    }
+
+    /// Applies `event` without checking that it is legal.
+    pub fn process_event(&mut self, event: &GameEvent) -> &Self {
+        match event {
+            GameEvent::Move { mv, .. } => {
+                self.process_move(*mv);
+            }
+            GameEvent::Card {
+                kind,
+                color,
+                targets,
+            } => {
+                if let Ok(targets) = targets.iter().map(|target| target.resolve(self)).collect() {
+                    self.process_card_play(&CardPlay {
+                        kind: *kind,
+                        color: *color,
+                        targets,
+                    });
+                }
+            }
+            GameEvent::Rewind {
+                color,
+                coord,
+                steps,
+            } => {
+                self.spend_energy(*color, CardKind::Rewind.card().energy_cost());
+                let _ = self.rewind_square(*coord, *steps);
+            }
+        }
+        self
+    }
+
+    fn check_rewind(&self, coord: Coord, steps: usize) -> Result<Option<Piece>, RewindError> {
+        if !coord.is_valid() {
+            return Err(RewindError::InvalidSquare(coord));
+        }
+        let available = self.history.depth(coord);
+        let mut restored = match steps {
+            0 => None,
+            _ => self.history.get(coord, steps),
+        }
+        .ok_or(RewindError::NotEnoughHistory {
+            requested: steps,
+            available,
+        })?;
+
+        let current = *self.get_piece_at(&coord);
+        if current.is_some_and(|piece| piece.is_king())
+            || restored.is_some_and(|piece| piece.is_king())
+        {
+            return Err(RewindError::King(coord));
+        }
+
+        if let Some(piece) = restored.as_mut() {
+            piece.set_cooldown(self.rules.cooldowns.for_piece(piece));
+            if self.get_coord_for_id(piece.get_id()).is_some() {
+                piece.get_state_mut().id = NO_PIECE_ID;
+            }
+        }
+        let mut rewound = self.clone();
+        rewound.set_piece_at(restored, coord);
+        let (white_before, black_before) = self.attacks_once_ready();
+        let (white_after, black_after) = rewound.attacks_once_ready();
+        if white_after > white_before || black_after > black_before {
+            return Err(RewindError::ExposesKing(coord));
+        }
+
+        Ok(restored)
+    }
+
+    /// The attacks on both kings once every piece is off cooldown, so that a restored piece
+    /// counts as an attacker even before it is ready to move.
+    fn attacks_once_ready(&self) -> (u8, u8) {
+        let mut ready = self.clone();
+        ready.decay_cooldowns(Duration::MAX);
+        ready.king_check_count()
+    }
+}
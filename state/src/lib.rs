@@ -1,8 +1,18 @@
 pub mod board;
+pub mod cards;
+pub mod clock;
 pub mod cooldowns;
 pub mod coordinate;
+pub mod delta;
+pub mod energy;
+pub mod eval;
+pub mod history;
+pub mod perft;
 pub mod piece;
+pub mod piece_state;
+pub mod rules;
 pub mod square;
+pub mod zobrist;
 
 pub mod movegen;
 mod tests;
@@ -7,22 +7,64 @@ use threadpool::ThreadPool;
 
 use super::{
     board::Board,
-    cooldowns::{COOLDOWN_BISHOP, COOLDOWN_KING, COOLDOWN_KNIGHT, COOLDOWN_QUEEN, COOLDOWN_ROOK},
+    cooldowns::{COOLDOWN_KING, COOLDOWN_ROOK},
     coordinate::Coord,
     piece::{Move, Piece},
 };
 use crate::piece::Color;
 use colored::Colorize;
 
+/// Settings for what a [`MoveGen`] considers possible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MoveGenOptions {
+    /// Treat every piece as ready, including pieces that are on cooldown or frozen.
+    pub ignore_cooldowns: bool,
+}
+
+/// A move and how long until the moving piece is ready to make it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvailableMove {
+    pub mv: Move,
+    pub available_in: Duration,
+}
+
 #[derive(Debug)]
 pub struct MoveGen<'board> {
     board: &'board Board,
+    options: MoveGenOptions,
 }
 
 impl<'board> MoveGen<'board> {
     #[must_use]
     pub fn new(board: &'board Board) -> Self {
-        Self { board }
+        Self::with_options(board, MoveGenOptions::default())
+    }
+
+    #[must_use]
+    pub fn with_options(board: &'board Board, options: MoveGenOptions) -> Self {
+        Self { board, options }
+    }
+
+    /**
+    Every move `color` could make if all of its pieces were ready, with the time until the
+    moving piece is ready. Castling waits for both the king and the rook.
+
+    Moves that are legal now may become illegal before they are available, and the other way
+    around, as the board can change in the meantime.
+    */
+    #[must_use]
+    pub fn get_available_moves_for_color(&self, color: Color) -> Vec<AvailableMove> {
+        let options = MoveGenOptions {
+            ignore_cooldowns: true,
+        };
+        MoveGen::with_options(self.board, options)
+            .get_possible_moves_for_color(color)
+            .into_iter()
+            .map(|mv| AvailableMove {
+                mv,
+                available_in: self.available_in(mv),
+            })
+            .collect()
     }
 
     #[must_use]
@@ -39,8 +81,9 @@ impl<'board> MoveGen<'board> {
                     let coord = Coord(x as i8, y as i8);
                     let s = s.clone();
                     let board = board.clone();
+                    let options = self.options;
                     pool.execute(move || {
-                        let gen = MoveGen::new(&board);
+                        let gen = MoveGen::with_options(&board, options);
                         let moves = match piece {
                             Piece::Pawn(_, _) => gen.for_pawn(&piece, coord),
                             Piece::Knight(_, _) => gen.for_knight(&piece, coord),
@@ -70,10 +113,11 @@ impl<'board> MoveGen<'board> {
                 if let Some(piece) = self.board.pieces[y][x] {
                     let s = s.clone();
                     let board = board.clone();
+                    let options = self.options;
                     pool.execute(move || {
                         #[allow(clippy::cast_possible_truncation)]
                         let coord = Coord(x as i8, y as i8);
-                        let gen = MoveGen::new(&board);
+                        let gen = MoveGen::with_options(&board, options);
                         let moves = match (piece, color == piece.get_color()) {
                             (Piece::Pawn(_, _), true) => gen.for_pawn(&piece, coord),
                             (Piece::Knight(_, _), true) => gen.for_knight(&piece, coord),
@@ -94,7 +138,7 @@ impl<'board> MoveGen<'board> {
 
     #[must_use]
     pub fn for_pawn(&self, piece: &Piece, pos: Coord) -> Vec<Move> {
-        if !piece.get_cooldown().is_zero() {
+        if !self.is_ready(piece) {
             return vec![];
         }
         let mut moves = vec![];
@@ -117,7 +161,7 @@ impl<'board> MoveGen<'board> {
         ) {
             if target.get_color() != piece.get_color() {
                 if left_capture.1 == promotion_rank {
-                    moves.extend(MoveGen::all_promotions_at_pos(pos, left_capture, piece));
+                    moves.extend(self.all_promotions_at_pos(pos, left_capture, piece));
                 } else {
                     moves.push(Move::Piece(pos, left_capture));
                 }
@@ -130,7 +174,7 @@ impl<'board> MoveGen<'board> {
         ) {
             if target.get_color() != piece.get_color() {
                 if right_capture.1 == promotion_rank {
-                    moves.extend(MoveGen::all_promotions_at_pos(pos, right_capture, piece));
+                    moves.extend(self.all_promotions_at_pos(pos, right_capture, piece));
                 } else {
                     moves.push(Move::Piece(pos, right_capture));
                 }
@@ -141,7 +185,7 @@ impl<'board> MoveGen<'board> {
         if forward.is_valid() && self.board.get_piece_at(&forward).is_none() {
             can_move_one = true;
             if forward.1 == promotion_rank {
-                moves.extend(MoveGen::all_promotions_at_pos(pos, forward, piece));
+                moves.extend(self.all_promotions_at_pos(pos, forward, piece));
             } else {
                 moves.push(Move::Piece(pos, forward));
             }
@@ -158,7 +202,7 @@ impl<'board> MoveGen<'board> {
 
     #[must_use]
     pub fn for_knight(&self, piece: &Piece, pos: Coord) -> Vec<Move> {
-        if !piece.get_cooldown().is_zero() {
+        if !self.is_ready(piece) {
             return vec![];
         }
         let color = piece.get_color();
@@ -195,7 +239,7 @@ impl<'board> MoveGen<'board> {
 
     #[must_use]
     pub fn for_rook(&self, piece: &Piece, pos: Coord) -> Vec<Move> {
-        if !piece.get_cooldown().is_zero() {
+        if !self.is_ready(piece) {
             return vec![];
         }
 
@@ -224,7 +268,7 @@ impl<'board> MoveGen<'board> {
 
     #[must_use]
     pub fn for_bishop(&self, piece: &Piece, pos: Coord) -> Vec<Move> {
-        if !piece.get_cooldown().is_zero() {
+        if !self.is_ready(piece) {
             return vec![];
         }
         let mut moves = vec![];
@@ -260,17 +304,17 @@ impl<'board> MoveGen<'board> {
 
     #[must_use]
     pub fn for_king(&self, piece: &Piece, pos: Coord) -> Vec<Move> {
-        if !piece.get_cooldown().is_zero() {
+        if !self.is_ready(piece) {
             return vec![];
         }
         let mut natural_moves: Vec<Move> = (-1..=1)
             .flat_map(|i| (-1..=1).map(move |j| Coord(pos.0 + i, pos.1 + j)))
             .filter(|coord| coord.is_valid() && coord != &pos)
             .filter(|coord| {
-                if let Some(opposing_king_pos) = self
-                    .board
-                    .get_coord_for_piece(&Piece::King(piece.get_color().opposite(), COOLDOWN_KING))
-                {
+                if let Some(opposing_king_pos) = self.board.get_coord_for_piece(&Piece::King(
+                    piece.get_color().opposite(),
+                    COOLDOWN_KING.into(),
+                )) {
                     let file_distance = (opposing_king_pos.0 - coord.0).abs();
                     let rank_distance = (opposing_king_pos.1 - coord.1).abs();
                     if file_distance <= 1 && rank_distance <= 1 {
@@ -284,11 +328,15 @@ impl<'board> MoveGen<'board> {
                 true
             })
             .filter(|coord| {
-                let mut enemy_board = self.board.filter_by_color(piece.get_color().opposite());
+                // Own pieces stay on the board, as they block the enemy's lines just the same.
+                let mut enemy_board = self.board.clone();
+                enemy_board.set_piece_at(None, pos);
                 enemy_board.set_piece_at(Some(*piece), *coord);
-                enemy_board
-                    .remove_by_piece(&Piece::King(piece.get_color().opposite(), COOLDOWN_KING));
-                let movegen = MoveGen::new(&enemy_board);
+                enemy_board.remove_by_piece(&Piece::King(
+                    piece.get_color().opposite(),
+                    COOLDOWN_KING.into(),
+                ));
+                let movegen = MoveGen::with_options(&enemy_board, self.options);
                 let enemy_moves =
                     movegen.get_possible_moves_for_color(piece.get_color().opposite());
                 let enemy_can_capture_at_coord = enemy_moves
@@ -304,20 +352,18 @@ impl<'board> MoveGen<'board> {
             .map(|target| Move::Piece(pos, target))
             .collect();
 
-        let (can_castle_king_side, can_castle_queen_side, castle_rank) =
-            if piece.get_color() == Color::White {
-                (
-                    self.board.white_can_castle_kingside,
-                    self.board.white_can_castle_queenside,
-                    0,
-                )
-            } else {
-                (
-                    self.board.black_can_castle_kingside,
-                    self.board.black_can_castle_queenside,
-                    7,
-                )
-            };
+        let (can_castle_king_side, can_castle_queen_side) = if piece.get_color() == Color::White {
+            (
+                self.board.white_can_castle_kingside,
+                self.board.white_can_castle_queenside,
+            )
+        } else {
+            (
+                self.board.black_can_castle_kingside,
+                self.board.black_can_castle_queenside,
+            )
+        };
+        let castle_rank = Board::castle_rank(piece.get_color());
 
         if can_castle_king_side {
             let king_is_starting_position = pos == Coord(4, castle_rank);
@@ -330,7 +376,9 @@ impl<'board> MoveGen<'board> {
                 lane_is_open,
                 king_is_starting_position,
             ) {
-                if target == &Piece::Rook(piece.get_color(), COOLDOWN_ROOK) {
+                if target == &Piece::Rook(piece.get_color(), COOLDOWN_ROOK.into())
+                    && self.is_ready(target)
+                {
                     natural_moves.push(Move::KingSideCastle(piece.get_color()));
                 }
             }
@@ -347,7 +395,9 @@ impl<'board> MoveGen<'board> {
                 lane_is_open,
                 king_is_starting_position,
             ) {
-                if target == &Piece::Rook(piece.get_color(), COOLDOWN_ROOK) {
+                if target == &Piece::Rook(piece.get_color(), COOLDOWN_ROOK.into())
+                    && self.is_ready(target)
+                {
                     natural_moves.push(Move::QueenSideCastle(piece.get_color()));
                 }
             }
@@ -356,12 +406,36 @@ impl<'board> MoveGen<'board> {
         natural_moves
     }
 
-    fn all_promotions_at_pos(from: Coord, to: Coord, piece: &Piece) -> Vec<Move> {
+    fn is_ready(&self, piece: &Piece) -> bool {
+        self.options.ignore_cooldowns || piece.can_move()
+    }
+
+    fn available_in(&self, mv: Move) -> Duration {
+        let ready_in = |coord: Coord| {
+            self.board
+                .get_piece_at(&coord)
+                .map_or(Duration::ZERO, |piece| piece.ready_in())
+        };
+        match mv {
+            Move::Piece(from, _) | Move::Promotion(from, _, _) => ready_in(from),
+            Move::KingSideCastle(color) => {
+                let rank = Board::castle_rank(color);
+                ready_in(Coord(4, rank)).max(ready_in(Coord(7, rank)))
+            }
+            Move::QueenSideCastle(color) => {
+                let rank = Board::castle_rank(color);
+                ready_in(Coord(4, rank)).max(ready_in(Coord(0, rank)))
+            }
+        }
+    }
+
+    fn all_promotions_at_pos(&self, from: Coord, to: Coord, piece: &Piece) -> Vec<Move> {
+        let cooldown = self.board.rules.promotion_cooldown;
         [
-            Piece::Queen(piece.get_color(), COOLDOWN_QUEEN),
-            Piece::Rook(piece.get_color(), COOLDOWN_ROOK),
-            Piece::Bishop(piece.get_color(), COOLDOWN_BISHOP),
-            Piece::Knight(piece.get_color(), COOLDOWN_KNIGHT),
+            Piece::Queen(piece.get_color(), cooldown.into()),
+            Piece::Rook(piece.get_color(), cooldown.into()),
+            Piece::Bishop(piece.get_color(), cooldown.into()),
+            Piece::Knight(piece.get_color(), cooldown.into()),
         ]
         .into_iter()
         .map(|p| Move::Promotion(from, to, p))
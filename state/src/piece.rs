@@ -1,18 +1,18 @@
-use crate::coordinate::Coord;
-use std::{fmt::Display, time::Duration};
-
-use super::cooldowns::{
-    COOLDOWN_BISHOP, COOLDOWN_KING, COOLDOWN_KNIGHT, COOLDOWN_PAWN, COOLDOWN_QUEEN, COOLDOWN_ROOK,
+use crate::{
+    coordinate::Coord,
+    piece_state::{PieceId, PieceState, StatusEffect, HASTE_COOLDOWN_MULTIPLIER},
+    rules::PieceCooldowns,
 };
+use std::{fmt::Display, time::Duration};
 
 #[derive(Clone, Copy, Debug)]
 pub enum Piece {
-    Pawn(Color, Duration),
-    Knight(Color, Duration),
-    Bishop(Color, Duration),
-    Rook(Color, Duration),
-    Queen(Color, Duration),
-    King(Color, Duration),
+    Pawn(Color, PieceState),
+    Knight(Color, PieceState),
+    Bishop(Color, PieceState),
+    Rook(Color, PieceState),
+    Queen(Color, PieceState),
+    King(Color, PieceState),
 }
 
 impl Piece {
@@ -67,40 +67,91 @@ impl Piece {
 
     #[inline]
     #[must_use]
-    pub fn get_cooldown(&self) -> Duration {
+    pub fn get_state(&self) -> &PieceState {
+        match self {
+            Piece::Pawn(_, state)
+            | Piece::Knight(_, state)
+            | Piece::Bishop(_, state)
+            | Piece::Rook(_, state)
+            | Piece::Queen(_, state)
+            | Piece::King(_, state) => state,
+        }
+    }
+
+    #[inline]
+    pub fn get_state_mut(&mut self) -> &mut PieceState {
         match self {
-            Piece::Pawn(_, cd)
-            | Piece::Knight(_, cd)
-            | Piece::Bishop(_, cd)
-            | Piece::Rook(_, cd)
-            | Piece::Queen(_, cd)
-            | Piece::King(_, cd) => *cd,
+            Piece::Pawn(_, state)
+            | Piece::Knight(_, state)
+            | Piece::Bishop(_, state)
+            | Piece::Rook(_, state)
+            | Piece::Queen(_, state)
+            | Piece::King(_, state) => state,
         }
     }
 
+    #[inline]
+    #[must_use]
+    pub fn get_id(&self) -> PieceId {
+        self.get_state().id
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get_cooldown(&self) -> Duration {
+        self.get_state().cooldown
+    }
+
     #[inline]
     pub fn set_cooldown(&mut self, cooldown: Duration) {
-        *self = match self {
-            Piece::Pawn(c, _) => Piece::Pawn(*c, cooldown),
-            Piece::Knight(c, _) => Piece::Knight(*c, cooldown),
-            Piece::Bishop(c, _) => Piece::Bishop(*c, cooldown),
-            Piece::Rook(c, _) => Piece::Rook(*c, cooldown),
-            Piece::Queen(c, _) => Piece::Queen(*c, cooldown),
-            Piece::King(c, _) => Piece::King(*c, cooldown),
+        self.get_state_mut().cooldown = cooldown;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn has_effect(&self, effect: StatusEffect) -> bool {
+        self.get_state().has_effect(effect)
+    }
+
+    /// A piece can move once it is off cooldown, unless it is frozen.
+    #[inline]
+    #[must_use]
+    pub fn can_move(&self) -> bool {
+        self.get_cooldown().is_zero() && !self.has_effect(StatusEffect::Freeze)
+    }
+
+    /// How long until the piece can move: the longer of its cooldown and the time left frozen.
+    #[must_use]
+    pub fn ready_in(&self) -> Duration {
+        let frozen = self
+            .get_state()
+            .remaining(StatusEffect::Freeze)
+            .unwrap_or_default();
+        self.get_cooldown().max(frozen)
+    }
+
+    /// Puts the piece on the cooldown it goes on after moving, shortened if it is hasted.
+    pub fn restart_cooldown(&mut self, cooldowns: &PieceCooldowns) {
+        let cooldown = cooldowns.for_piece(self);
+        if self.has_effect(StatusEffect::Haste) {
+            self.set_cooldown(cooldown.mul_f64(HASTE_COOLDOWN_MULTIPLIER));
+        } else {
+            self.set_cooldown(cooldown);
         }
     }
 
+    /// Pawns may only promote to a queen, rook, bishop or knight of their own color.
+    #[must_use]
+    pub fn can_promote_to(&self, target: &Piece) -> bool {
+        self.is_pawn()
+            && self.get_color() == target.get_color()
+            && (target.is_queen() || target.is_rook() || target.is_bishop() || target.is_knight())
+    }
+
     #[inline]
     #[must_use]
     pub fn std_piece_cooldown(piece: &Piece) -> Duration {
-        match piece {
-            Piece::Pawn(_, _) => COOLDOWN_PAWN,
-            Piece::Knight(_, _) => COOLDOWN_KNIGHT,
-            Piece::Bishop(_, _) => COOLDOWN_BISHOP,
-            Piece::Rook(_, _) => COOLDOWN_ROOK,
-            Piece::Queen(_, _) => COOLDOWN_QUEEN,
-            Piece::King(_, _) => COOLDOWN_KING,
-        }
+        PieceCooldowns::default().for_piece(piece)
     }
 }
 
@@ -0,0 +1,123 @@
+use std::{fmt::Display, time::Duration};
+
+/// A hasted piece goes on this fraction of its normal cooldown after moving.
+pub const HASTE_COOLDOWN_MULTIPLIER: f64 = 0.5;
+
+/// A piece carries at most one effect of each kind.
+pub const MAX_STATUS_EFFECTS: usize = 3;
+
+/// Identifies one piece for the whole game, however it moves. Assigned by the board.
+pub type PieceId = u16;
+
+/// The id of a piece that has not been put on a board yet.
+pub const NO_PIECE_ID: PieceId = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusEffect {
+    /// The piece cannot move.
+    Freeze,
+    /// The next capture of the piece is blocked and uses up the shield.
+    Shield,
+    /// The piece's cooldown after moving is multiplied by [`HASTE_COOLDOWN_MULTIPLIER`].
+    Haste,
+}
+
+impl Display for StatusEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StatusEffect::Freeze => "Freeze",
+            StatusEffect::Shield => "Shield",
+            StatusEffect::Haste => "Haste",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedEffect {
+    pub effect: StatusEffect,
+    pub remaining: Duration,
+}
+
+/**
+Everything about a piece beyond its kind and color: its id, its cooldown and the status effects
+on it.
+
+The effects are kept in a fixed number of slots so that pieces stay `Copy`.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PieceState {
+    pub id: PieceId,
+    pub cooldown: Duration,
+    effects: [Option<TimedEffect>; MAX_STATUS_EFFECTS],
+}
+
+impl PieceState {
+    #[must_use]
+    pub const fn new(cooldown: Duration) -> Self {
+        Self {
+            id: NO_PIECE_ID,
+            cooldown,
+            effects: [None; MAX_STATUS_EFFECTS],
+        }
+    }
+
+    pub fn effects(&self) -> impl Iterator<Item = &TimedEffect> {
+        self.effects.iter().flatten()
+    }
+
+    #[must_use]
+    pub fn has_effect(&self, effect: StatusEffect) -> bool {
+        self.effects().any(|timed| timed.effect == effect)
+    }
+
+    /// The time left on `effect`, if the piece has it.
+    #[must_use]
+    pub fn remaining(&self, effect: StatusEffect) -> Option<Duration> {
+        self.effects()
+            .find(|timed| timed.effect == effect)
+            .map(|timed| timed.remaining)
+    }
+
+    /// Puts `effect` on the piece for `duration`, replacing the time left on the same effect.
+    pub fn add_effect(&mut self, effect: StatusEffect, duration: Duration) {
+        let timed = Some(TimedEffect {
+            effect,
+            remaining: duration,
+        });
+        let slot = self
+            .effects
+            .iter()
+            .position(|slot| slot.is_some_and(|existing| existing.effect == effect))
+            .or_else(|| self.effects.iter().position(Option::is_none));
+        if let Some(slot) = slot {
+            self.effects[slot] = timed;
+        }
+    }
+
+    pub fn remove_effect(&mut self, effect: StatusEffect) {
+        for slot in &mut self.effects {
+            if slot.is_some_and(|timed| timed.effect == effect) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Runs every effect down by `elapsed`, removing the ones that run out.
+    pub fn decay_effects(&mut self, elapsed: Duration) {
+        for slot in &mut self.effects {
+            if let Some(timed) = slot {
+                timed.remaining = timed.remaining.saturating_sub(elapsed);
+                if timed.remaining.is_zero() {
+                    *slot = None;
+                }
+            }
+        }
+    }
+}
+
+impl From<Duration> for PieceState {
+    fn from(cooldown: Duration) -> Self {
+        Self::new(cooldown)
+    }
+}
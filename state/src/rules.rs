@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use crate::{
+    board::Board,
+    cooldowns::{
+        COOLDOWN_BISHOP, COOLDOWN_KING, COOLDOWN_KNIGHT, COOLDOWN_PAWN, COOLDOWN_PROMOTION,
+        COOLDOWN_QUEEN, COOLDOWN_ROOK,
+    },
+    energy::EnergyRules,
+    piece::Piece,
+};
+
+/// The cooldown each kind of piece goes on after it moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PieceCooldowns {
+    pub pawn: Duration,
+    pub knight: Duration,
+    pub bishop: Duration,
+    pub rook: Duration,
+    pub queen: Duration,
+    pub king: Duration,
+}
+
+impl PieceCooldowns {
+    #[must_use]
+    pub fn for_piece(&self, piece: &Piece) -> Duration {
+        match piece {
+            Piece::Pawn(_, _) => self.pawn,
+            Piece::Knight(_, _) => self.knight,
+            Piece::Bishop(_, _) => self.bishop,
+            Piece::Rook(_, _) => self.rook,
+            Piece::Queen(_, _) => self.queen,
+            Piece::King(_, _) => self.king,
+        }
+    }
+}
+
+impl Default for PieceCooldowns {
+    fn default() -> Self {
+        Self {
+            pawn: COOLDOWN_PAWN,
+            knight: COOLDOWN_KNIGHT,
+            bishop: COOLDOWN_BISHOP,
+            rook: COOLDOWN_ROOK,
+            queen: COOLDOWN_QUEEN,
+            king: COOLDOWN_KING,
+        }
+    }
+}
+
+/// Tunable rules that vary between games.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// The cooldown each kind of piece goes on after it moves.
+    pub cooldowns: PieceCooldowns,
+    /// The cooldown a freshly promoted piece starts on.
+    pub promotion_cooldown: Duration,
+
+    /// How many cards each player draws when the game starts.
+    pub starting_hand: usize,
+    /// The most cards a player can hold. No cards are drawn while the hand is full.
+    pub hand_size: usize,
+    /// How often a card is drawn.
+    pub card_draw_interval: Duration,
+
+    /// How many changes each square remembers for rewinding.
+    pub square_history_depth: usize,
+
+    /// The energy economy, if the game has one.
+    pub energy: Option<EnergyRules>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            cooldowns: PieceCooldowns::default(),
+            promotion_cooldown: COOLDOWN_PROMOTION,
+            starting_hand: 3,
+            hand_size: 5,
+            card_draw_interval: Duration::from_secs(10),
+            square_history_depth: 8,
+            energy: None,
+        }
+    }
+}
+
+impl Board {
+    /// The standard position played under `rules`, with every piece on its cooldown from them.
+    #[must_use]
+    pub fn with_rules(rules: Rules) -> Self {
+        let mut board = Board::standard();
+        board.rules = rules;
+        if let Some(energy) = rules.energy {
+            board.enable_energy(energy);
+        }
+        for piece in board.pieces.iter_mut().flatten().flatten() {
+            piece.set_cooldown(rules.cooldowns.for_piece(piece));
+        }
+        board
+    }
+}
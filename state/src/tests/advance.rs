@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod elapsed {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        piece::{Color, Piece},
+        square::*,
+    };
+
+    fn white_king_under_attack() -> Board {
+        let mut board = Board::empty();
+        board.white_hp = 1000;
+        board.black_hp = 1000;
+        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO.into())), H8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
+        board.set_piece_at(
+            Some(Piece::Knight(
+                Color::White,
+                Duration::from_millis(700).into(),
+            )),
+            G1,
+        );
+        board.rehash();
+        board
+    }
+
+    #[test]
+    fn each_king_drains_its_own_hp() {
+        let mut board = Board::empty();
+        board.white_hp = 1000;
+        board.black_hp = 500;
+        board.set_piece_at(Some(Piece::Rook(Color::White, Duration::ZERO.into())), A1);
+        board.set_piece_at(Some(Piece::King(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), H1);
+
+        board.tick();
+
+        assert_eq!(board.white_hp, 1000);
+        assert_eq!(board.black_hp, 499);
+    }
+
+    #[test]
+    fn small_steps_end_like_one_step() {
+        let mut stepped = white_king_under_attack();
+        let mut once = stepped.clone();
+
+        for _ in 0..100 {
+            stepped.advance(Duration::from_millis(7));
+        }
+        once.advance(Duration::from_millis(700));
+
+        assert_eq!(stepped.white_hp, once.white_hp);
+        assert_eq!(stepped.white_hp, 1000 - 2 * 700 / 16);
+        assert_eq!(stepped.white_hp_drain, once.white_hp_drain);
+        assert_eq!(stepped.zobrist, once.zobrist);
+        let knight = once.get_piece_at(&G1).expect("knight on g1");
+        assert!(knight.can_move());
+    }
+
+    #[test]
+    fn attacks_counted_again_when_a_cooldown_runs_out() {
+        let mut board = Board::empty();
+        board.white_hp = 1000;
+        board.set_piece_at(
+            Some(Piece::Rook(Color::Black, Duration::from_millis(100).into())),
+            A8,
+        );
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
+
+        // The rook only attacks for the last 160ms.
+        board.advance(Duration::from_millis(260));
+
+        assert_eq!(board.white_hp, 1000 - 10);
+    }
+
+    #[test]
+    fn hash_follows_drained_hp() {
+        let mut board = white_king_under_attack();
+
+        board.advance(Duration::from_millis(333));
+        let hash = board.zobrist;
+        board.rehash();
+
+        assert_eq!(board.zobrist, hash);
+    }
+}
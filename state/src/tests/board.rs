@@ -7,9 +7,10 @@ mod tests {
         cooldowns::{COOLDOWN_KING, COOLDOWN_PAWN, COOLDOWN_QUEEN, COOLDOWN_ROOK},
         piece::{
             Color::{self, Black, White},
-            Piece,
+            Move, Piece,
         },
-        square::{self, A1, A8, H8},
+        square::{self, A1, A8, E1, E2, E4, H8},
+        zobrist::HP_BUCKET,
     };
 
     #[test]
@@ -30,16 +31,16 @@ mod tests {
     #[test]
     fn get_all_black_pawns() {
         let board = Board::standard();
-        let black_pawns = board.filter_by_piece(Piece::Pawn(Black, COOLDOWN_PAWN));
+        let black_pawns = board.filter_by_piece(Piece::Pawn(Black, COOLDOWN_PAWN.into()));
         assert_eq!(black_pawns.pieces.len(), 8);
     }
 
     #[test]
     fn get_number_of_checks_on_white_king() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO)), A8);
-        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO)), H8);
-        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO)), A1);
+        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO.into())), H8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
         let (attacks_on_white, attacks_on_black) = board.king_check_count();
         assert_eq!(attacks_on_white, 2);
         assert_eq!(attacks_on_black, 0);
@@ -49,9 +50,9 @@ mod tests {
     fn white_king_health_loss() {
         let mut board = Board::empty();
         board.white_hp = 1000;
-        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO)), A8);
-        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO)), H8);
-        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO)), A1);
+        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO.into())), H8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
 
         board.tick();
 
@@ -62,9 +63,9 @@ mod tests {
     fn white_king_defeat() {
         let mut board = Board::empty();
         board.white_hp = 1;
-        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO)), A8);
-        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO)), H8);
-        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO)), A1);
+        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO.into())), H8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
 
         board.tick();
 
@@ -89,19 +90,19 @@ mod tests {
         let board = Board::from("r1bk3r/p2pBpNp/n4n2/1p1NP2P/6P1/3P4/P1P1K3/q5b1 w qQ - 0 0");
         assert_eq!(
             board.get_piece_at(&square::A8),
-            &Some(Piece::Rook(Black, COOLDOWN_ROOK))
+            &Some(Piece::Rook(Black, COOLDOWN_ROOK.into()))
         );
         assert_eq!(
             board.get_piece_at(&square::A8),
-            &Some(Piece::Rook(Black, COOLDOWN_ROOK))
+            &Some(Piece::Rook(Black, COOLDOWN_ROOK.into()))
         );
         assert_eq!(
             board.get_piece_at(&square::A1),
-            &Some(Piece::Queen(Black, COOLDOWN_QUEEN))
+            &Some(Piece::Queen(Black, COOLDOWN_QUEEN.into()))
         );
         assert_eq!(
             board.get_piece_at(&square::E2),
-            &Some(Piece::King(White, COOLDOWN_KING))
+            &Some(Piece::King(White, COOLDOWN_KING.into()))
         );
         assert!(!board.white_can_castle_kingside);
         assert!(board.white_can_castle_queenside);
@@ -116,4 +117,60 @@ mod tests {
         assert_eq!(board.white_hp, 400);
         assert_eq!(board.black_hp, 1200);
     }
+
+    #[test]
+    fn piece_for_move() {
+        let board = Board::standard();
+        assert_eq!(
+            board.get_piece_for_move(&Move::Piece(E2, E4)),
+            Some(Piece::Pawn(White, COOLDOWN_PAWN.into()))
+        );
+        assert_eq!(
+            board.get_piece_for_move(&Move::KingSideCastle(Black)),
+            Some(Piece::King(Black, COOLDOWN_KING.into()))
+        );
+        assert_eq!(board.get_piece_for_move(&Move::Piece(E4, E1)), None);
+    }
+
+    #[test]
+    fn winner_by_hp_and_capture() {
+        let mut board = Board::standard();
+        assert_eq!(board.winner(), None);
+
+        board.black_hp = 0;
+        assert_eq!(board.winner(), Some(White));
+
+        board.black_hp = 100;
+        board.set_piece_at(None, E1);
+        assert_eq!(board.winner(), Some(Black));
+    }
+
+    #[test]
+    fn fen_round_trip() {
+        let board = Board::standard();
+        assert_eq!(
+            board.fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 5000 5000"
+        );
+
+        let fen = "r3k3/1P6/8/3q4/8/8/8/4K2R w Kq - 0 1 700 12";
+        assert_eq!(Board::from(fen).fen(), fen);
+    }
+
+    #[test]
+    fn sync_hash_rounds_hp_down_to_a_bucket() {
+        let mut board = Board::standard();
+        board.white_hp = 4_900;
+        board.rehash();
+        let mut drifted = board.clone();
+        drifted.white_hp -= 1;
+        drifted.rehash();
+        let mut damaged = board.clone();
+        damaged.white_hp -= HP_BUCKET;
+        damaged.rehash();
+
+        assert_ne!(board.zobrist, drifted.zobrist);
+        assert_eq!(board.sync_hash(), drifted.sync_hash());
+        assert_ne!(board.sync_hash(), damaged.sync_hash());
+    }
 }
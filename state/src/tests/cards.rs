@@ -0,0 +1,321 @@
+#[cfg(test)]
+mod rewind {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cards::{CardError, CardKind, CardPlay},
+        coordinate::Coord,
+        history::{GameEvent, RewindError},
+        piece::{Color::White, Move},
+        square::*,
+    };
+
+    fn play(targets: Vec<Coord>) -> CardPlay {
+        CardPlay {
+            kind: CardKind::Rewind,
+            color: White,
+            targets,
+        }
+    }
+
+    #[test]
+    fn undoes_the_last_change_to_the_square() {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.process_move(Move::Piece(E2, E4));
+
+        let rewind = play(vec![E2]);
+        assert_eq!(rewind.validate(&board), Ok(()));
+        board.process_card_play(&rewind);
+
+        assert!(board.get_piece_at(&E2).is_some_and(|p| p.is_pawn()));
+        assert!(board.get_piece_at(&E4).is_some_and(|p| p.is_pawn()));
+    }
+
+    #[test]
+    fn needs_history_and_one_target() {
+        let board = Board::standard();
+        assert_eq!(
+            play(vec![E4]).validate(&board),
+            Err(CardError::Rewind(RewindError::NotEnoughHistory {
+                requested: 1,
+                available: 0
+            }))
+        );
+        assert_eq!(
+            play(vec![D1, D2]).validate(&board),
+            Err(CardError::WrongTargetCount {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn recorded_as_a_rewind_event() {
+        assert_eq!(
+            play(vec![E4]).event(&Board::standard()),
+            GameEvent::Rewind {
+                color: White,
+                coord: E4,
+                steps: 1
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod duplicate {
+    use crate::{
+        board::Board,
+        cards::{CardError, CardKind, CardPlay},
+        cooldowns::COOLDOWN_KNIGHT,
+        coordinate::Coord,
+        piece::{Color::White, Piece},
+        square::*,
+    };
+
+    fn play(targets: Vec<Coord>) -> CardPlay {
+        CardPlay {
+            kind: CardKind::Duplicate,
+            color: White,
+            targets,
+        }
+    }
+
+    #[test]
+    fn copies_piece_to_neighbour() {
+        let mut board = Board::standard();
+        let duplicate = play(vec![G1, G2]);
+        board.set_piece_at(None, G2);
+
+        assert_eq!(duplicate.validate(&board), Ok(()));
+        board.process_card_play(&duplicate);
+
+        assert_eq!(
+            board.get_piece_at(&G2),
+            &Some(Piece::Knight(White, COOLDOWN_KNIGHT.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&G1),
+            &Some(Piece::Knight(White, COOLDOWN_KNIGHT.into()))
+        );
+        assert_eq!(board.get_piece_count(), 32);
+    }
+
+    #[test]
+    fn copy_starts_on_full_cooldown() {
+        let mut board = Board::from("4k3/8/8/8/8/8/8/4K1N1 w - - 0 1");
+        board.decay_cooldowns(COOLDOWN_KNIGHT);
+        board.process_card_play(&play(vec![G1, H2]));
+
+        let copy = board.get_piece_at(&H2).expect("copy on h2");
+        assert_eq!(copy.get_cooldown(), COOLDOWN_KNIGHT);
+    }
+
+    #[test]
+    fn invalid_targets() {
+        let board = Board::from("4k3/8/8/8/8/8/P7/4K1N1 w - - 0 1");
+        assert_eq!(
+            play(vec![G1, G3]).validate(&board),
+            Err(CardError::InvalidTarget(G3))
+        );
+        assert_eq!(
+            play(vec![G1, G1]).validate(&board),
+            Err(CardError::InvalidTarget(G1))
+        );
+        assert_eq!(
+            play(vec![E1, D2]).validate(&board),
+            Err(CardError::InvalidTarget(E1))
+        );
+        assert_eq!(
+            play(vec![E8, D7]).validate(&board),
+            Err(CardError::NotYourPiece(E8))
+        );
+        assert_eq!(
+            play(vec![A2, B1]).validate(&board),
+            Err(CardError::InvalidTarget(B1))
+        );
+        assert_eq!(play(vec![G1, F1]).validate(&board), Ok(()));
+        assert_eq!(play(vec![A2, B2]).validate(&board), Ok(()));
+    }
+
+    #[test]
+    fn cannot_copy_onto_occupied_square() {
+        let board = Board::standard();
+        assert_eq!(
+            play(vec![G1, G2]).validate(&board),
+            Err(CardError::OccupiedSquare(G2))
+        );
+    }
+}
+
+#[cfg(test)]
+mod transform {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cards::{CardError, CardKind, CardPlay},
+        cooldowns::COOLDOWN_QUEEN,
+        coordinate::Coord,
+        piece::{
+            Color::{Black, White},
+            Piece,
+        },
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    fn play(targets: Vec<Coord>) -> CardPlay {
+        CardPlay {
+            kind: CardKind::Transform,
+            color: White,
+            targets,
+        }
+    }
+
+    #[test]
+    fn takes_kind_of_enemy_piece() {
+        let mut board = Board::standard();
+        let transform = play(vec![E2, D8]);
+
+        assert_eq!(transform.validate(&board), Ok(()));
+        board.process_card_play(&transform);
+
+        assert_eq!(
+            board.get_piece_at(&E2),
+            &Some(Piece::Queen(White, COOLDOWN_QUEEN.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&D8),
+            &Some(Piece::Queen(Black, COOLDOWN_QUEEN.into()))
+        );
+        let queen = board.get_piece_at(&E2).expect("queen on e2");
+        assert_eq!(queen.get_cooldown(), COOLDOWN_QUEEN);
+    }
+
+    #[test]
+    fn keeps_status_effects() {
+        let mut board = Board::standard();
+        board.add_effect(E2, StatusEffect::Freeze, Duration::from_secs(2));
+        board.process_card_play(&play(vec![E2, D8]));
+
+        let queen = board.get_piece_at(&E2).expect("queen on e2");
+        assert!(queen.is_queen());
+        assert!(queen.has_effect(StatusEffect::Freeze));
+    }
+
+    #[test]
+    fn follows_promotion_rules() {
+        let board = Board::standard();
+        assert_eq!(
+            play(vec![E2, E8]).validate(&board),
+            Err(CardError::InvalidTarget(E8))
+        );
+        assert_eq!(
+            play(vec![B1, A2]).validate(&board),
+            Err(CardError::InvalidTarget(A2))
+        );
+        assert_eq!(
+            play(vec![E1, D1]).validate(&board),
+            Err(CardError::InvalidTarget(E1))
+        );
+        assert_eq!(
+            play(vec![B1, E4]).validate(&board),
+            Err(CardError::EmptySquare(E4))
+        );
+        assert_eq!(
+            play(vec![B8, D1]).validate(&board),
+            Err(CardError::NotYourPiece(B8))
+        );
+        assert_eq!(play(vec![B1, A1]).validate(&board), Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod deck {
+    use std::time::Duration;
+
+    use crate::{
+        cards::{deck::Deck, CardError, CardKind},
+        cooldowns::COST_REWIND,
+        rules::Rules,
+    };
+
+    fn deck() -> Deck {
+        let cards = [
+            CardKind::Rewind,
+            CardKind::Duplicate,
+            CardKind::Transform,
+            CardKind::Rewind,
+            CardKind::Duplicate,
+            CardKind::Transform,
+            CardKind::Rewind,
+        ];
+        Deck::new(&cards, Rules::default())
+    }
+
+    #[test]
+    fn draws_starting_hand_in_order() {
+        let deck = deck();
+        let ids: Vec<u8> = deck.hand().iter().map(|card| card.id).collect();
+
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert_eq!(deck.draw_pile_len(), 4);
+    }
+
+    #[test]
+    fn draws_on_the_game_clock() {
+        let mut deck = deck();
+
+        assert!(deck.advance(Duration::from_secs(9)).is_empty());
+        let drawn = deck.advance(Duration::from_secs(1));
+        assert_eq!(drawn.len(), 1);
+        assert_eq!(drawn[0].id, 3);
+        assert_eq!(deck.until_next_draw(), Duration::from_secs(10));
+
+        let drawn = deck.advance(Duration::from_secs(25));
+        assert_eq!(drawn.len(), 1);
+        assert_eq!(deck.hand().len(), 5);
+        assert_eq!(deck.until_next_draw(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn played_cards_are_reshuffled_when_the_pile_runs_out() {
+        let mut deck = deck();
+        let _ = deck.advance(Duration::from_secs(20));
+        let rewind = deck.play(0).expect("card 0 in hand");
+
+        assert_eq!(rewind.kind, CardKind::Rewind);
+        assert!(deck.get_card_in_hand(0).is_none());
+        assert!(deck.play(0).is_none());
+        assert_eq!(deck.discard_len(), 1);
+
+        for _ in 0..2 {
+            let _ = deck.draw();
+            let played = deck.hand()[0].id;
+            deck.play(played);
+        }
+        assert_eq!(deck.draw_pile_len(), 0);
+
+        let drawn = deck.draw().expect("reshuffled discard");
+        assert_eq!(drawn.id, 0);
+        assert_eq!(deck.discard_len(), 0);
+    }
+
+    #[test]
+    fn playing_a_card_costs_cooldown() {
+        let mut deck = deck();
+        assert!(deck.check_play(0).is_ok());
+        deck.play(0);
+
+        assert_eq!(deck.cooldown(), COST_REWIND);
+        assert_eq!(deck.check_play(1), Err(CardError::Cooldown(COST_REWIND)));
+        assert_eq!(deck.check_play(0), Err(CardError::NotInHand(0)));
+
+        let _ = deck.advance(COST_REWIND);
+        assert!(deck.check_play(1).is_ok());
+    }
+}
@@ -0,0 +1,253 @@
+#[cfg(test)]
+mod placement {
+    use crate::{
+        board::Board,
+        cooldowns::{COOLDOWN_KING, COOLDOWN_ROOK},
+        piece::{
+            Color::{Black, White},
+            Move, Piece,
+        },
+        square::*,
+    };
+
+    fn castling_board() -> Board {
+        Board::from("r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1")
+    }
+
+    #[test]
+    fn white_king_side() {
+        let mut board = castling_board();
+        board.process_move(Move::KingSideCastle(White));
+
+        assert_eq!(
+            board.get_piece_at(&G1),
+            &Some(Piece::King(White, COOLDOWN_KING.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&F1),
+            &Some(Piece::Rook(White, COOLDOWN_ROOK.into()))
+        );
+        assert_eq!(board.get_piece_at(&E1), &None);
+        assert_eq!(board.get_piece_at(&H1), &None);
+        assert_eq!(
+            board.get_piece_at(&A1),
+            &Some(Piece::Rook(White, COOLDOWN_ROOK.into()))
+        );
+    }
+
+    #[test]
+    fn white_queen_side() {
+        let mut board = castling_board();
+        board.process_move(Move::QueenSideCastle(White));
+
+        assert_eq!(
+            board.get_piece_at(&C1),
+            &Some(Piece::King(White, COOLDOWN_KING.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&D1),
+            &Some(Piece::Rook(White, COOLDOWN_ROOK.into()))
+        );
+        assert_eq!(board.get_piece_at(&E1), &None);
+        assert_eq!(board.get_piece_at(&A1), &None);
+        assert_eq!(
+            board.get_piece_at(&H1),
+            &Some(Piece::Rook(White, COOLDOWN_ROOK.into()))
+        );
+    }
+
+    #[test]
+    fn black_king_side() {
+        let mut board = castling_board();
+        board.process_move(Move::KingSideCastle(Black));
+
+        assert_eq!(
+            board.get_piece_at(&G8),
+            &Some(Piece::King(Black, COOLDOWN_KING.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&F8),
+            &Some(Piece::Rook(Black, COOLDOWN_ROOK.into()))
+        );
+        assert_eq!(board.get_piece_at(&E8), &None);
+        assert_eq!(board.get_piece_at(&H8), &None);
+    }
+
+    #[test]
+    fn black_queen_side() {
+        let mut board = castling_board();
+        board.process_move(Move::QueenSideCastle(Black));
+
+        assert_eq!(
+            board.get_piece_at(&C8),
+            &Some(Piece::King(Black, COOLDOWN_KING.into()))
+        );
+        assert_eq!(
+            board.get_piece_at(&D8),
+            &Some(Piece::Rook(Black, COOLDOWN_ROOK.into()))
+        );
+        assert_eq!(board.get_piece_at(&E8), &None);
+        assert_eq!(board.get_piece_at(&A8), &None);
+    }
+
+    #[test]
+    fn piece_count_is_unchanged() {
+        for mv in [
+            Move::KingSideCastle(White),
+            Move::QueenSideCastle(White),
+            Move::KingSideCastle(Black),
+            Move::QueenSideCastle(Black),
+        ] {
+            let mut board = castling_board();
+            board.process_move(mv);
+            assert_eq!(board.get_piece_count(), 22, "{mv}");
+        }
+    }
+
+    #[test]
+    fn both_pieces_get_cooldowns() {
+        let mut board = castling_board();
+        board.decay_cooldowns(COOLDOWN_ROOK);
+        board.process_move(Move::QueenSideCastle(White));
+
+        let king = board.get_piece_at(&C1).expect("king on c1");
+        let rook = board.get_piece_at(&D1).expect("rook on d1");
+        assert_eq!(king.get_cooldown(), COOLDOWN_KING);
+        assert_eq!(rook.get_cooldown(), COOLDOWN_ROOK);
+    }
+}
+
+#[cfg(test)]
+mod rights {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        piece::{
+            Color::{Black, White},
+            Move, Piece,
+        },
+        square::*,
+    };
+
+    fn castling_board() -> Board {
+        Board::from("r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1")
+    }
+
+    fn rights(board: &Board) -> [bool; 4] {
+        [
+            board.white_can_castle_kingside,
+            board.white_can_castle_queenside,
+            board.black_can_castle_kingside,
+            board.black_can_castle_queenside,
+        ]
+    }
+
+    #[test]
+    fn castling_revokes_both_sides() {
+        let mut board = castling_board();
+        board.process_move(Move::KingSideCastle(White));
+        assert_eq!(rights(&board), [false, false, true, true]);
+
+        board.process_move(Move::QueenSideCastle(Black));
+        assert_eq!(rights(&board), [false, false, false, false]);
+    }
+
+    #[test]
+    fn queen_side_castling_revokes_both_sides() {
+        let mut board = castling_board();
+        board.process_move(Move::QueenSideCastle(White));
+        assert_eq!(rights(&board), [false, false, true, true]);
+    }
+
+    #[test]
+    fn king_move_revokes_both_sides() {
+        let mut board = castling_board();
+        board.process_move(Move::Piece(E1, F1));
+        assert_eq!(rights(&board), [false, false, true, true]);
+
+        board.process_move(Move::Piece(E8, D8));
+        assert_eq!(rights(&board), [false, false, false, false]);
+    }
+
+    #[test]
+    fn king_returning_home_does_not_restore_rights() {
+        let mut board = castling_board();
+        board.process_move(Move::Piece(E1, F1));
+        board.process_move(Move::Piece(F1, E1));
+        assert_eq!(rights(&board), [false, false, true, true]);
+    }
+
+    #[test]
+    fn king_side_rook_move_revokes_king_side() {
+        let mut board = castling_board();
+        board.process_move(Move::Piece(H1, G1));
+        assert_eq!(rights(&board), [false, true, true, true]);
+
+        board.process_move(Move::Piece(H8, G8));
+        assert_eq!(rights(&board), [false, true, false, true]);
+    }
+
+    #[test]
+    fn queen_side_rook_move_revokes_queen_side() {
+        let mut board = castling_board();
+        board.process_move(Move::Piece(A1, B1));
+        assert_eq!(rights(&board), [true, false, true, true]);
+
+        board.process_move(Move::Piece(A8, B8));
+        assert_eq!(rights(&board), [true, false, true, false]);
+    }
+
+    #[test]
+    fn rook_capture_revokes_that_side() {
+        let mut board = Board::from("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        board.process_move(Move::Piece(A1, A8));
+        assert_eq!(rights(&board), [true, false, true, false]);
+
+        board.process_move(Move::Piece(H8, H1));
+        assert_eq!(rights(&board), [false, false, false, false]);
+    }
+
+    #[test]
+    fn promotion_capture_revokes_that_side() {
+        let mut board = Board::from("r3k2r/1P6/8/8/8/8/8/4K3 w kq - 0 1");
+        board.process_move(Move::Promotion(
+            B7,
+            A8,
+            Piece::Queen(White, Duration::ZERO.into()),
+        ));
+        assert_eq!(rights(&board), [false, false, true, false]);
+    }
+
+    #[test]
+    fn unrelated_moves_keep_rights() {
+        let mut board = castling_board();
+        board.process_move(Move::Piece(E2, E4));
+        board.process_move(Move::Piece(D7, D5));
+        assert_eq!(rights(&board), [true, true, true, true]);
+    }
+}
+
+#[cfg(test)]
+mod movegen {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        movegen::MoveGen,
+        piece::{Color::White, Move, Piece},
+        square::*,
+    };
+
+    #[test]
+    fn no_castling_after_rook_moves_away_and_back() {
+        let mut board = Board::from("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        board.process_move(Move::Piece(H1, H2));
+        board.process_move(Move::Piece(H2, H1));
+        board.decay_cooldowns(Duration::from_secs(10));
+
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E1);
+        assert!(!moves.contains(&Move::KingSideCastle(White)));
+        assert!(moves.contains(&Move::QueenSideCastle(White)));
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod time {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        clock::{Clock, ManualClock},
+        cooldowns::COOLDOWN_KNIGHT,
+        piece::{Color, Piece},
+        square::*,
+    };
+
+    /// Moves `clock` forward and the board along with it, the way the client does.
+    fn run(board: &mut Board, clock: &ManualClock, elapsed: Duration) {
+        let before = clock.now();
+        clock.advance(elapsed);
+        board.advance(clock.now().saturating_sub(before));
+    }
+
+    #[test]
+    fn manual_clock_moves_only_when_told() {
+        let clock = ManualClock::new();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(250));
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(clock.now(), Duration::from_millis(300));
+
+        clock.set(Duration::from_secs(2));
+        assert_eq!(clock.now(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn manual_clock_clones_share_time() {
+        let clock = ManualClock::new();
+        let shared = clock.clone();
+
+        clock.advance(Duration::from_millis(40));
+
+        assert_eq!(shared.now(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn sleeping_on_a_manual_clock_moves_it_forward() {
+        let clock = ManualClock::new();
+
+        clock.sleep(Duration::from_millis(16));
+        clock.sleep(Duration::from_millis(16));
+
+        assert_eq!(clock.now(), Duration::from_millis(32));
+    }
+
+    #[test]
+    fn cooldowns_decay_exactly() {
+        let clock = ManualClock::new();
+        let mut board = Board::standard();
+
+        run(&mut board, &clock, Duration::from_millis(480));
+
+        let knight = board.get_piece_at(&B1).expect("knight on b1");
+        assert_eq!(
+            knight.get_cooldown(),
+            COOLDOWN_KNIGHT.saturating_sub(Duration::from_millis(480))
+        );
+
+        run(&mut board, &clock, COOLDOWN_KNIGHT);
+
+        let knight = board.get_piece_at(&B1).expect("knight on b1");
+        assert!(knight.can_move());
+    }
+
+    #[test]
+    fn king_hp_drains_per_tick_under_attack() {
+        let clock = ManualClock::new();
+        let mut board = Board::empty();
+        board.white_hp = 1000;
+        board.set_piece_at(Some(Piece::Rook(Color::Black, Duration::ZERO.into())), A8);
+        board.set_piece_at(Some(Piece::Bishop(Color::Black, Duration::ZERO.into())), H8);
+        board.set_piece_at(Some(Piece::King(Color::White, Duration::ZERO.into())), A1);
+
+        // Two attackers for 170ms is 21.25 ticks worth, the quarter is carried over.
+        run(&mut board, &clock, Duration::from_millis(170));
+        assert_eq!(board.white_hp, 1000 - 21);
+
+        run(&mut board, &clock, Duration::from_millis(6));
+        assert_eq!(board.white_hp, 1000 - 22);
+    }
+}
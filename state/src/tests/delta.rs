@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod board_delta {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        delta::BoardDelta,
+        piece::{Color, Move},
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn identical_boards_have_no_delta() {
+        let board = Board::standard();
+        assert!(board.diff(&board.clone()).is_empty());
+    }
+
+    #[test]
+    fn move_changes_squares_and_castling() {
+        let before = ready_board();
+        let mut after = before.clone();
+        after.process_move(Move::Piece(E2, E4));
+        after.process_move(Move::Piece(E1, E2));
+
+        let delta = before.diff(&after);
+        let squares = delta
+            .squares
+            .iter()
+            .map(|(coord, _)| *coord)
+            .collect::<Vec<_>>();
+        assert_eq!(squares, vec![E1, E2, E4]);
+        assert!(delta.cooldowns.is_empty());
+        assert_eq!(delta.castling, Some([false, false, true, true]));
+        assert_eq!(delta.hp, None);
+    }
+
+    #[test]
+    fn cooldowns_and_effects_are_told_apart() {
+        let before = Board::standard();
+        let mut after = before.clone();
+        after.decay_cooldowns(Duration::from_millis(100));
+        after.add_effect(G1, StatusEffect::Shield, Duration::from_secs(1));
+
+        let delta = before.diff(&after);
+        assert_eq!(delta.cooldowns.len(), 31);
+        assert!(delta.cooldowns.iter().all(|(coord, _)| *coord != G1));
+        assert_eq!(delta.squares.len(), 1);
+        assert_eq!(delta.squares[0].0, G1);
+    }
+
+    #[test]
+    fn applying_a_delta_reaches_the_other_board() {
+        let mut before = ready_board();
+        let mut after = before.clone();
+        after.process_move(Move::Piece(G1, F3));
+        after.process_move(Move::KingSideCastle(Color::Black));
+        after.decay_cooldowns(Duration::from_millis(250));
+        after.white_hp -= 10;
+
+        let delta = before.diff(&after);
+        before.apply_delta(&delta);
+
+        assert!(before.diff(&after).is_empty());
+        let knight = before.get_piece_at(&F3).expect("knight on f3");
+        assert_eq!(
+            Some(knight.get_id()),
+            after.get_piece_at(&F3).map(|piece| piece.get_id())
+        );
+        assert_eq!(before.white_hp, after.white_hp);
+    }
+
+    #[test]
+    fn empty_delta_changes_nothing() {
+        let mut board = Board::standard();
+        let original = board.clone();
+        board.apply_delta(&BoardDelta::default());
+        assert!(original.diff(&board).is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod economy {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cards::{CardError, CardKind, CardPlay},
+        energy::{EnergyRules, ENERGY_DUPLICATE},
+        piece::{Color, Move, Piece},
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    fn energy_board(move_cost: usize) -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.enable_energy(EnergyRules {
+            move_cost,
+            ..EnergyRules::default()
+        });
+        board
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.process_move(Move::Piece(E2, E4));
+
+        assert_eq!(board.rules.energy, None);
+        assert!(board.can_afford(Color::White, 100));
+        assert!(!format!("{board}").contains("Energy"));
+    }
+
+    #[test]
+    fn regenerates_on_tick_up_to_max() {
+        let mut board = energy_board(0);
+        board.spend_energy(Color::White, 5);
+        assert_eq!(board.get_energy(Color::White), 0);
+
+        for _ in 0..63 {
+            board.tick();
+        }
+        assert_eq!(board.get_energy(Color::White), 1);
+        assert_eq!(board.get_energy(Color::Black), 6);
+
+        board.regenerate_energy(Duration::from_secs(30));
+        assert_eq!(board.get_energy(Color::White), 10);
+        assert_eq!(board.get_energy(Color::Black), 10);
+        assert!(format!("{board}").contains("Energy: 10/10"));
+    }
+
+    #[test]
+    fn long_gaps_regenerate_without_overflowing() {
+        let mut board = energy_board(0);
+        board.spend_energy(Color::White, 5);
+
+        board.regenerate_energy(Duration::from_millis(2_500));
+        assert_eq!(board.get_energy(Color::White), 2);
+        assert_eq!(board.energy_regen_progress, Duration::from_millis(500));
+
+        board.regenerate_energy(Duration::MAX);
+        assert_eq!(board.get_energy(Color::White), 10);
+        assert_eq!(board.get_energy(Color::Black), 10);
+    }
+
+    #[test]
+    fn moves_cost_energy() {
+        let mut board = energy_board(2);
+        board.process_move(Move::Piece(E2, E4));
+        assert_eq!(board.get_energy(Color::White), 3);
+        assert_eq!(board.get_energy(Color::Black), 5);
+
+        board.spend_energy(Color::White, 2);
+        assert!(!board.is_valid_move(Move::Piece(D2, D4)));
+        assert!(board.is_valid_move(Move::Piece(D7, D5)));
+    }
+
+    #[test]
+    fn refused_promotions_cost_nothing() {
+        let mut board = Board::from("4k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.enable_energy(EnergyRules {
+            move_cost: 2,
+            ..EnergyRules::default()
+        });
+        let hash = board.zobrist;
+
+        board.process_move(Move::Promotion(
+            A7,
+            A8,
+            Piece::King(Color::White, Duration::ZERO.into()),
+        ));
+
+        assert_eq!(board.get_energy(Color::White), 5);
+        assert_eq!(board.zobrist, hash);
+    }
+
+    #[test]
+    fn breaking_a_shield_costs_a_move() {
+        let mut board = energy_board(2);
+        board.add_effect(D7, StatusEffect::Shield, Duration::from_secs(5));
+        board.set_piece_at(Some(Piece::Knight(Color::White, Duration::ZERO.into())), C5);
+
+        board.process_move(Move::Piece(C5, D7));
+
+        assert!(board.get_piece_at(&D7).is_some_and(|piece| piece.is_pawn()));
+        assert_eq!(board.get_energy(Color::White), 3);
+    }
+
+    #[test]
+    fn cards_cost_energy() {
+        let mut board = energy_board(0);
+        let play = CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::White,
+            targets: vec![E2, E3],
+        };
+
+        assert_eq!(play.validate(&board), Ok(()));
+        board.process_card_play(&play);
+        assert_eq!(board.get_energy(Color::White), 5 - ENERGY_DUPLICATE);
+
+        assert_eq!(
+            play.validate(&board),
+            Err(CardError::NotEnoughEnergy {
+                required: ENERGY_DUPLICATE,
+                available: 0
+            })
+        );
+    }
+}
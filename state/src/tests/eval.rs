@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod terms {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        eval::{Evaluation, HP_PER_POINT, MOBILITY_WEIGHT, TEMPO_WEIGHT},
+        piece::{Color, Move},
+        square::*,
+    };
+
+    fn ready(fen: &str) -> Board {
+        let mut board = Board::from(fen);
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn standard_position_is_even() {
+        let mut board = Board::standard();
+        assert_eq!(board.evaluate(), Evaluation::default());
+
+        board.decay_cooldowns(Duration::from_secs(10));
+        assert_eq!(board.evaluate(), Evaluation::default());
+    }
+
+    #[test]
+    fn material_is_counted_from_whites_side() {
+        let board = ready("4k3/8/8/8/8/8/8/3QK3 w - - 0 1");
+        let evaluation = board.evaluate();
+        assert_eq!(evaluation.material, 900);
+        assert!(evaluation.for_color(Color::White) > 0);
+        assert_eq!(
+            evaluation.for_color(Color::Black),
+            -evaluation.for_color(Color::White)
+        );
+    }
+
+    #[test]
+    fn central_pieces_are_better_placed() {
+        let corner = ready("4k3/8/8/8/8/8/8/N3K3 w - - 0 1").evaluate();
+        let center = ready("4k3/8/8/8/3N4/8/8/4K3 w - - 0 1").evaluate();
+        assert!(center.placement > corner.placement);
+        assert!(center.mobility > corner.mobility);
+    }
+
+    #[test]
+    fn placement_is_mirrored_for_black() {
+        let white = ready("4k3/8/8/8/8/2N5/8/4K3 w - - 0 1").evaluate();
+        let black = ready("4k3/8/2n5/8/8/8/8/4K3 w - - 0 1").evaluate();
+        assert_eq!(white.placement, -black.placement);
+    }
+
+    #[test]
+    fn mobility_counts_moves_on_cooldown() {
+        let board = ready("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+        let mut moved = board.clone();
+        moved.process_move(Move::Piece(A1, A2));
+        moved.process_move(Move::Piece(A2, A1));
+
+        assert_eq!(board.evaluate().mobility, moved.evaluate().mobility);
+        assert_eq!(board.evaluate().mobility % MOBILITY_WEIGHT, 0);
+        assert!(board.evaluate().mobility > 0);
+    }
+
+    #[test]
+    fn king_hp_and_tempo() {
+        let mut board = ready("4k3/8/8/8/8/8/8/R3K3 w - - 0 1 4000 5000");
+        let evaluation = board.evaluate();
+        assert_eq!(
+            evaluation.king_hp,
+            -1000 / i32::try_from(HP_PER_POINT).unwrap()
+        );
+        assert_eq!(evaluation.tempo, TEMPO_WEIGHT);
+
+        board.process_move(Move::Piece(A1, A2));
+        assert_eq!(board.evaluate().tempo, 0);
+    }
+
+    #[test]
+    fn breakdown_lists_every_term() {
+        let shown = ready("4k3/8/8/8/8/8/8/3QK3 w - - 0 1")
+            .evaluate()
+            .to_string();
+        for term in [
+            "material",
+            "placement",
+            "mobility",
+            "king hp",
+            "tempo",
+            "total",
+        ] {
+            assert!(shown.contains(term), "{term} missing from {shown}");
+        }
+    }
+}
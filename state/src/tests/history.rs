@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod square_history {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cards::{CardKind, CardPlay, CardTarget},
+        energy::EnergyRules,
+        history::{GameEvent, RewindError},
+        piece::{Color, Move},
+        square::*,
+    };
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn moves_record_both_squares() {
+        let mut board = ready_board();
+        board.process_move(Move::Piece(G1, F3));
+
+        assert_eq!(board.history.depth(G1), 1);
+        assert_eq!(board.history.depth(F3), 1);
+        assert_eq!(board.history.depth(E2), 0);
+
+        assert_eq!(board.rewind_square(F3, 1), Ok(()));
+        assert!(board.get_piece_at(&F3).is_none());
+        assert_eq!(board.history.depth(F3), 0);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut board = ready_board();
+        board.rules.square_history_depth = 2;
+        for _ in 0..3 {
+            board.process_move(Move::Piece(G1, F3));
+            board.process_move(Move::Piece(F3, G1));
+        }
+
+        assert_eq!(board.history.depth(G1), 2);
+        assert_eq!(
+            board.rewind_square(G1, 3),
+            Err(RewindError::NotEnoughHistory {
+                requested: 3,
+                available: 2
+            })
+        );
+    }
+
+    #[test]
+    fn restores_a_captured_piece_on_cooldown() {
+        let mut board = Board::from("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1");
+        board.process_move(Move::Piece(E4, D5));
+        board.decay_cooldowns(Duration::from_secs(10));
+
+        assert_eq!(board.rewind_square(D5, 1), Ok(()));
+        let pawn = board.get_piece_at(&D5).expect("black pawn is back");
+        assert_eq!(pawn.get_color(), Color::Black);
+        assert!(!pawn.get_cooldown().is_zero());
+        assert!(board.get_piece_at(&E4).is_none());
+    }
+
+    #[test]
+    fn kings_cannot_be_rewound() {
+        let mut board = Board::from("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        board.process_move(Move::Piece(E1, E2));
+
+        assert_eq!(board.rewind_square(E2, 1), Err(RewindError::King(E2)));
+        assert_eq!(board.rewind_square(E1, 1), Err(RewindError::King(E1)));
+        assert!(board.get_piece_at(&E2).is_some_and(|p| p.is_king()));
+    }
+
+    #[test]
+    fn cannot_put_a_king_in_check() {
+        let mut board = Board::from("4k3/8/8/4r3/8/3N4/8/4K3 w - - 0 1");
+        board.process_move(Move::Piece(D3, E5));
+
+        assert_eq!(board.king_check_count(), (0, 0));
+        assert_eq!(
+            board.rewind_square(E5, 1),
+            Err(RewindError::ExposesKing(E5))
+        );
+        assert!(board.get_piece_at(&E5).is_some_and(|p| p.is_knight()));
+        assert_eq!(board.history.depth(E5), 1);
+    }
+
+    #[test]
+    fn replaying_events_is_deterministic() {
+        let start = ready_board();
+        let id = |coord| start.get_piece_at(&coord).expect("a piece").get_id();
+        let events = [
+            GameEvent::Move {
+                mv: Move::Piece(E2, E4),
+                piece: id(E2),
+            },
+            GameEvent::Move {
+                mv: Move::Piece(D7, D5),
+                piece: id(D7),
+            },
+            GameEvent::Move {
+                mv: Move::Piece(E4, D5),
+                piece: id(E2),
+            },
+            GameEvent::Card {
+                kind: CardKind::Duplicate,
+                color: Color::Black,
+                targets: vec![CardTarget::Piece(id(E7)), CardTarget::Square(E6)],
+            },
+            GameEvent::Rewind {
+                color: Color::White,
+                coord: D5,
+                steps: 1,
+            },
+        ];
+
+        let mut first = ready_board();
+        let mut second = ready_board();
+        for event in &events {
+            first.process_event(event);
+            second.process_event(event);
+        }
+
+        assert_eq!(first.pieces, second.pieces);
+        assert!(first
+            .get_piece_at(&D5)
+            .is_some_and(|p| p.get_color() == Color::Black));
+        assert!(first.get_piece_at(&E6).is_some());
+    }
+    #[test]
+    fn replaying_a_game_with_energy_matches_the_live_board() {
+        let energy_board = || {
+            let mut board = ready_board();
+            board.enable_energy(EnergyRules {
+                move_cost: 1,
+                ..EnergyRules::default()
+            });
+            board
+        };
+        let mut live = energy_board();
+        let mut events = vec![];
+
+        let mv = Move::Piece(E2, E4);
+        events.push(GameEvent::Move {
+            mv,
+            piece: live.get_piece_at(&E2).expect("a pawn").get_id(),
+        });
+        live.process_move(mv);
+        for (kind, targets) in [
+            (CardKind::Rewind, vec![E4]),
+            (CardKind::Duplicate, vec![B1, C3]),
+        ] {
+            let play = CardPlay {
+                kind,
+                color: Color::White,
+                targets,
+            };
+            events.push(play.event(&live));
+            live.process_card_play(&play);
+        }
+
+        let mut replayed = energy_board();
+        for event in &events {
+            replayed.process_event(event);
+        }
+
+        assert!(live.get_energy(Color::White) < EnergyRules::default().starting);
+        assert_eq!(replayed.pieces, live.pieces);
+        assert_eq!(
+            replayed.get_energy(Color::White),
+            live.get_energy(Color::White)
+        );
+        assert_eq!(replayed.zobrist, live.zobrist);
+    }
+}
@@ -1,2 +1,15 @@
+mod advance;
 mod board;
+mod cards;
+mod castling;
+mod clock;
+mod delta;
+mod energy;
+mod eval;
+mod history;
 mod movegen;
+mod perft;
+mod piece_ids;
+mod promotion;
+mod status_effects;
+mod zobrist;
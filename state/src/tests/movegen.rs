@@ -1,30 +1,10 @@
-use std::time::Duration;
-
-use crate::board::Board;
-
-impl Board {
-    #[must_use]
-    pub fn standard_without_cooldowns() -> Self {
-        let mut board = Board::standard();
-        for y in 0..8 {
-            for x in 0..8 {
-                if let Some(mut piece) = board.pieces[y][x] {
-                    piece.set_cooldown(Duration::ZERO);
-                    board.pieces[y][x] = Some(piece);
-                }
-            }
-        }
-        board
-    }
-}
-
 #[cfg(test)]
 mod moves {
     use std::time::Duration;
 
     use crate::{
         board::Board,
-        movegen::MoveGen,
+        movegen::{MoveGen, MoveGenOptions},
         piece::{
             Color::{Black, White},
             Piece,
@@ -32,25 +12,30 @@ mod moves {
         square::*,
     };
 
+    const IGNORE_COOLDOWNS: MoveGenOptions = MoveGenOptions {
+        ignore_cooldowns: true,
+    };
+
     #[test]
     fn all_possible_moves() {
-        let board = Board::standard_without_cooldowns();
-        let moves = MoveGen::new(&board).get_possible_moves();
+        let board = Board::standard();
+        let moves = MoveGen::with_options(&board, IGNORE_COOLDOWNS).get_possible_moves();
         assert_eq!(moves.len(), 40);
+        assert!(MoveGen::new(&board).get_possible_moves().is_empty());
     }
 
     #[test]
     fn all_black_starting_moves() {
-        let board = Board::standard_without_cooldowns().filter_by_color(Black);
-        let moves = MoveGen::new(&board).get_possible_moves();
+        let board = Board::standard().filter_by_color(Black);
+        let moves = MoveGen::with_options(&board, IGNORE_COOLDOWNS).get_possible_moves();
         assert_eq!(moves.len(), 20);
     }
 
     #[test]
     fn all_black_starting_moves_with_capture() {
-        let mut board = Board::standard_without_cooldowns().filter_by_color(Black);
-        board.set_piece_at(Some(Piece::Pawn(White, Duration::ZERO)), D6);
-        let moves = MoveGen::new(&board).get_possible_moves();
+        let mut board = Board::standard().filter_by_color(Black);
+        board.set_piece_at(Some(Piece::Pawn(White, Duration::ZERO.into())), D6);
+        let moves = MoveGen::with_options(&board, IGNORE_COOLDOWNS).get_possible_moves();
 
         assert_eq!(moves.len(), 22);
     }
@@ -58,9 +43,9 @@ mod moves {
     #[test]
     fn cooldown_on_piece() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(Black, Duration::ZERO)), F3);
-        let moves =
-            MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::from_millis(50)), A3);
+        board.set_piece_at(Some(Piece::Rook(Black, Duration::ZERO.into())), F3);
+        let moves = MoveGen::new(&board)
+            .for_rook(&Piece::Rook(White, Duration::from_millis(50).into()), A3);
         assert!(moves.is_empty());
     }
 }
@@ -80,7 +65,8 @@ mod bishop_moves {
     #[test]
     fn bishop_on_empty() {
         let board = Board::empty();
-        let moves = MoveGen::new(&board).for_bishop(&Piece::Bishop(White, Duration::ZERO), E5);
+        let moves =
+            MoveGen::new(&board).for_bishop(&Piece::Bishop(White, Duration::ZERO.into()), E5);
         let expected_moves = vec![D4, C3, B2, A1, F6, G7, H8, F4, G3, H2, D6, C7, B8];
 
         assert!(move_lists_has_all_targets(E5, &expected_moves, &moves));
@@ -89,7 +75,8 @@ mod bishop_moves {
     #[test]
     fn bishop_on_standard() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_bishop(&Piece::Bishop(White, Duration::ZERO), E5);
+        let moves =
+            MoveGen::new(&board).for_bishop(&Piece::Bishop(White, Duration::ZERO.into()), E5);
         let expected_moves = vec![D4, C3, F6, G7, F4, G3, D6, C7];
 
         assert!(move_lists_has_all_targets(E5, &expected_moves, &moves));
@@ -122,7 +109,8 @@ mod knight_moves {
     #[test]
     fn knight_in_starting_position() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_knight(&Piece::Knight(White, Duration::ZERO), G1);
+        let moves =
+            MoveGen::new(&board).for_knight(&Piece::Knight(White, Duration::ZERO.into()), G1);
         let expected_moves = vec![H3, F3];
 
         assert!(move_lists_has_all_targets(G1, &expected_moves, &moves));
@@ -131,7 +119,8 @@ mod knight_moves {
     #[test]
     fn knight_on_standard_board() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_knight(&Piece::Knight(White, Duration::ZERO), E4);
+        let moves =
+            MoveGen::new(&board).for_knight(&Piece::Knight(White, Duration::ZERO.into()), E4);
 
         assert_eq!(moves.len(), 6);
     }
@@ -165,7 +154,7 @@ mod king_moves {
     #[test]
     fn king_cant_move_into_pawn_check() {
         let mut board = Board::standard();
-        board.set_piece_at(Some(Piece::King(White, Duration::ZERO)), E5);
+        board.set_piece_at(Some(Piece::King(White, Duration::ZERO.into())), E5);
         for y in 0..8 {
             for x in 0..8 {
                 if let Some(mut piece) = board.pieces[x][y] {
@@ -174,7 +163,7 @@ mod king_moves {
                 }
             }
         }
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E5);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E5);
         let expected_moves = vec![D5, F5, D4, E4, F4];
 
         assert!(move_lists_has_all_targets(E5, &expected_moves, &moves));
@@ -184,7 +173,7 @@ mod king_moves {
     #[test]
     fn king_on_empty_board() {
         let board = Board::empty();
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E5);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E5);
 
         assert_eq!(moves.len(), 8);
 
@@ -198,18 +187,30 @@ mod king_moves {
     #[test]
     fn king_cant_move_into_check() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(Black, Duration::ZERO)), A1);
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), B2);
+        board.set_piece_at(Some(Piece::Rook(Black, Duration::ZERO.into())), A1);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), B2);
 
         assert!(move_lists_has_all_targets(B2, &[A1, B3, C2, C3], &moves));
         assert_eq!(moves.len(), 4);
     }
 
+    #[test]
+    fn king_can_move_behind_own_piece() {
+        let mut board = Board::empty();
+        board.set_piece_at(Some(Piece::Rook(Black, Duration::ZERO.into())), A1);
+        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO.into())), C1);
+        board.set_piece_at(Some(Piece::King(White, Duration::ZERO.into())), E2);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E2);
+
+        assert!(move_lists_has_all_targets(E2, &[D1, E1, F1], &moves));
+        assert_eq!(moves.len(), 8);
+    }
+
     #[test]
     fn king_cant_move_into_opposing_king() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::King(Black, Duration::ZERO)), D5);
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), D3);
+        board.set_piece_at(Some(Piece::King(Black, Duration::ZERO.into())), D5);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), D3);
         let expected_moves = vec![C3, E3, C2, D2, E2];
         assert_eq!(moves.len(), expected_moves.len());
         assert!(move_lists_has_all_targets(D3, &expected_moves, &moves));
@@ -229,7 +230,7 @@ mod king_moves {
     #[test]
     fn check_in_standard() {
         let mut board = Board::standard();
-        board.set_piece_at(Some(Piece::Pawn(White, Duration::ZERO)), D7);
+        board.set_piece_at(Some(Piece::Pawn(White, Duration::ZERO.into())), D7);
         let (_, attacks_on_black) = board.king_check_count();
 
         assert_eq!(attacks_on_black, 1);
@@ -254,10 +255,10 @@ mod pawn_moves {
     #[test]
     fn pawn_capture() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Pawn(Black, Duration::ZERO)), D3);
-        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO)), C3);
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), E3);
-        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO), D4);
+        board.set_piece_at(Some(Piece::Pawn(Black, Duration::ZERO.into())), D3);
+        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO.into())), C3);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), E3);
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO.into()), D4);
         let expected_moves = vec![C3, E3];
 
         assert!(move_lists_has_all_targets(D4, &expected_moves, &moves));
@@ -266,7 +267,7 @@ mod pawn_moves {
     #[test]
     fn white_pawn_at_start() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(White, Duration::ZERO), A2);
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(White, Duration::ZERO.into()), A2);
         let expected_moves = vec![A3, A4];
 
         assert!(move_lists_has_all_targets(A2, &expected_moves, &moves));
@@ -275,8 +276,8 @@ mod pawn_moves {
     #[test]
     fn black_pawn_at_start_with_capture() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO)), A6);
-        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO), B7);
+        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO.into())), A6);
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO.into()), B7);
         let expected_moves = vec![A6, B6, B5];
 
         assert!(move_lists_has_all_targets(B7, &expected_moves, &moves));
@@ -285,8 +286,8 @@ mod pawn_moves {
     #[test]
     fn black_pawn_at_start() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO)), D5);
-        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO), D7);
+        board.set_piece_at(Some(Piece::Bishop(White, Duration::ZERO.into())), D5);
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO.into()), D7);
         let expected_moves = vec![D6];
 
         assert!(move_lists_has_all_targets(D7, &expected_moves, &moves));
@@ -295,7 +296,7 @@ mod pawn_moves {
     #[test]
     fn promote_white_pawn() {
         let board = Board::empty();
-        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO), B2);
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(Black, Duration::ZERO.into()), B2);
         assert_eq!(moves.len(), 4);
     }
 
@@ -325,21 +326,21 @@ mod castling {
     #[test]
     fn king_castle_kingside() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), H1);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), H1);
         board.white_can_castle_kingside = true;
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E1);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E1);
         assert!(moves.contains(&Move::KingSideCastle(White)));
     }
 
     #[test]
     fn blocked_queenside_castle() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Queen(White, Duration::ZERO)), D1);
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), A1);
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), H1);
+        board.set_piece_at(Some(Piece::Queen(White, Duration::ZERO.into())), D1);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), A1);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), H1);
         board.white_can_castle_kingside = true;
         board.white_can_castle_queenside = true;
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E1);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E1);
         assert!(moves.contains(&Move::KingSideCastle(White)));
         assert!(!moves.contains(&Move::QueenSideCastle(White)));
     }
@@ -347,20 +348,32 @@ mod castling {
     #[test]
     fn moved_king_castle_kingside() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), H1);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), H1);
         board.white_can_castle_kingside = true;
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E2);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E2);
         assert!(!moves.contains(&Move::KingSideCastle(White)));
     }
 
     #[test]
     fn king_castle_queenside() {
         let mut board = Board::empty();
-        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO)), A1);
+        board.set_piece_at(Some(Piece::Rook(White, Duration::ZERO.into())), A1);
         board.white_can_castle_queenside = true;
-        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO), E1);
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E1);
         assert!(moves.contains(&Move::QueenSideCastle(White)));
     }
+
+    #[test]
+    fn rook_on_cooldown_cannot_castle() {
+        let mut board = Board::empty();
+        board.set_piece_at(
+            Some(Piece::Rook(White, Duration::from_millis(10).into())),
+            H1,
+        );
+        board.white_can_castle_kingside = true;
+        let moves = MoveGen::new(&board).for_king(&Piece::King(White, Duration::ZERO.into()), E1);
+        assert!(!moves.contains(&Move::KingSideCastle(White)));
+    }
 }
 
 #[cfg(test)]
@@ -381,7 +394,7 @@ mod rook_moves {
     #[test]
     fn rook_on_empty_board() {
         let board = Board::empty();
-        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO), E4);
+        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO.into()), E4);
 
         assert_eq!(moves.len(), 14);
     }
@@ -389,7 +402,7 @@ mod rook_moves {
     #[test]
     fn rook_on_standard_board() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO), A5);
+        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO.into()), A5);
         let expected_moves = vec![A6, A7, B5, C5, D5, E5, F5, G5, H5, A4, A3];
 
         assert!(move_lists_has_all_targets(A5, &expected_moves, &moves));
@@ -398,7 +411,7 @@ mod rook_moves {
     #[test]
     fn starting_rook() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO), A1);
+        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(White, Duration::ZERO.into()), A1);
 
         assert_eq!(moves.len(), 0);
     }
@@ -406,7 +419,7 @@ mod rook_moves {
     #[test]
     fn infiltrated_starting_rook() {
         let board = Board::standard();
-        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(Black, Duration::ZERO), A1);
+        let moves = MoveGen::new(&board).for_rook(&Piece::Rook(Black, Duration::ZERO.into()), A1);
 
         assert_eq!(vec![Move::Piece(A1, A2), Move::Piece(A1, B1)], moves);
     }
@@ -422,3 +435,70 @@ mod rook_moves {
             .all(|m| moves.contains(&m))
     }
 }
+
+#[cfg(test)]
+mod availability {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cooldowns::{COOLDOWN_KNIGHT, COOLDOWN_PAWN, COOLDOWN_ROOK},
+        movegen::{AvailableMove, MoveGen},
+        piece::{Color::White, Move},
+        piece_state::StatusEffect,
+        square::*,
+    };
+
+    fn available_in(moves: &[AvailableMove], mv: Move) -> Option<Duration> {
+        moves
+            .iter()
+            .find(|available| available.mv == mv)
+            .map(|available| available.available_in)
+    }
+
+    #[test]
+    fn moves_wait_for_cooldowns() {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_millis(200));
+        let moves = MoveGen::new(&board).get_available_moves_for_color(White);
+
+        assert_eq!(moves.len(), 20);
+        assert_eq!(
+            available_in(&moves, Move::Piece(E2, E4)),
+            Some(COOLDOWN_PAWN.saturating_sub(Duration::from_millis(200)))
+        );
+        assert_eq!(
+            available_in(&moves, Move::Piece(G1, F3)),
+            Some(COOLDOWN_KNIGHT.saturating_sub(Duration::from_millis(200)))
+        );
+    }
+
+    #[test]
+    fn frozen_pieces_wait_for_the_thaw() {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.add_effect(G1, StatusEffect::Freeze, Duration::from_secs(3));
+        let moves = MoveGen::new(&board).get_available_moves_for_color(White);
+
+        assert_eq!(
+            available_in(&moves, Move::Piece(G1, F3)),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            available_in(&moves, Move::Piece(E2, E4)),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn castling_waits_for_the_rook() {
+        let mut board = Board::from("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        board.decay_cooldowns(Duration::from_secs(1));
+        let moves = MoveGen::new(&board).get_available_moves_for_color(White);
+
+        assert_eq!(
+            available_in(&moves, Move::KingSideCastle(White)),
+            Some(COOLDOWN_ROOK.saturating_sub(Duration::from_secs(1)))
+        );
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod counts {
+    use crate::{
+        board::Board,
+        perft::{divide_mismatches, uci, DivideMismatch},
+        piece::{Color, Move, Piece},
+        square::*,
+    };
+
+    const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+    #[test]
+    fn standard_position_matches_published_counts() {
+        let board = Board::standard();
+        assert_eq!(board.perft(Color::White, 0), 1);
+        assert_eq!(board.perft(Color::White, 1), 20);
+        assert_eq!(board.perft(Color::White, 2), 400);
+        assert_eq!(board.perft(Color::White, 3), 8902);
+    }
+
+    #[test]
+    fn king_may_not_step_onto_squares_attacked_through_own_pieces() {
+        // The bishop on a6 only reaches f1 through the bishop on e2.
+        let board = Board::from(KIWIPETE);
+        assert_eq!(board.perft(Color::White, 1), 48);
+    }
+
+    #[test]
+    fn pinned_pieces_can_move() {
+        // 14 in standard chess, where the pawn on b5 is pinned by the rook on h5.
+        let board = Board::from("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1");
+        let moves = board.divide(Color::White, 1);
+        assert_eq!(moves.len(), 15);
+        assert!(moves.iter().any(|(mv, _)| *mv == Move::Piece(B5, B6)));
+    }
+
+    #[test]
+    fn capturing_the_king_ends_the_tree() {
+        let board = Board::from("4k3/8/8/8/8/8/8/R3K2q w - - 0 1");
+        let after_capture = board
+            .divide(Color::Black, 2)
+            .into_iter()
+            .find(|(mv, _)| *mv == Move::Piece(H1, E1))
+            .map(|(_, count)| count);
+        assert_eq!(after_capture, Some(0));
+    }
+
+    #[test]
+    fn divide_adds_up_to_perft() {
+        let board = Board::from(KIWIPETE);
+        let total = board
+            .divide(Color::White, 2)
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<u64>();
+        assert_eq!(total, board.perft(Color::White, 2));
+    }
+
+    #[test]
+    fn mismatches_name_the_disagreeing_moves() {
+        let divide = vec![
+            (Move::Piece(E2, E4), 20),
+            (Move::Piece(D2, D4), 20),
+            (Move::KingSideCastle(Color::White), 5),
+        ];
+        let reference = vec![
+            ("e2e4".to_string(), 20),
+            ("d2d4".to_string(), 21),
+            ("e7e8q".to_string(), 3),
+        ];
+
+        assert_eq!(
+            divide_mismatches(&divide, &reference),
+            vec![
+                DivideMismatch {
+                    mv: "d2d4".into(),
+                    ours: Some(20),
+                    reference: Some(21),
+                },
+                DivideMismatch {
+                    mv: "e1g1".into(),
+                    ours: Some(5),
+                    reference: None,
+                },
+                DivideMismatch {
+                    mv: "e7e8q".into(),
+                    ours: None,
+                    reference: Some(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn moves_print_in_uci_notation() {
+        let knight = Piece::Knight(Color::Black, std::time::Duration::ZERO.into());
+        assert_eq!(uci(&Move::Piece(G1, F3)), "g1f3");
+        assert_eq!(uci(&Move::Promotion(B2, B1, knight)), "b2b1n");
+        assert_eq!(uci(&Move::QueenSideCastle(Color::Black)), "e8c8");
+    }
+}
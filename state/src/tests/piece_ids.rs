@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod ids {
+    use std::{collections::HashSet, time::Duration};
+
+    use crate::{
+        board::Board,
+        cards::{CardError, CardKind, CardPlay, CardTarget},
+        coordinate::Coord,
+        history::GameEvent,
+        piece::{Color, Move, Piece},
+        piece_state::{PieceId, NO_PIECE_ID},
+        square::*,
+    };
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    fn id_at(board: &Board, coord: Coord) -> PieceId {
+        board
+            .get_piece_at(&coord)
+            .expect("piece on square")
+            .get_id()
+    }
+
+    #[test]
+    fn standard_board_numbers_every_piece() {
+        let board = Board::standard();
+        let ids = board
+            .pieces
+            .iter()
+            .flatten()
+            .flatten()
+            .map(Piece::get_id)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(ids.len(), 32);
+        assert!(!ids.contains(&NO_PIECE_ID));
+        assert_eq!(id_at(&board, A1), 1);
+        assert_eq!(id_at(&board, H8), 32);
+        assert_eq!(board.next_piece_id, 33);
+    }
+
+    #[test]
+    fn ids_follow_moves() {
+        let mut board = ready_board();
+        let knight = id_at(&board, G1);
+        board.process_move(Move::Piece(G1, F3));
+        assert_eq!(board.get_coord_for_id(knight), Some(F3));
+
+        let rook = id_at(&board, H1);
+        board.process_move(Move::KingSideCastle(Color::White));
+        assert_eq!(board.get_coord_for_id(rook), Some(F1));
+    }
+
+    #[test]
+    fn remove_by_id_removes_one_piece() {
+        let mut board = ready_board();
+        let rook = id_at(&board, H1);
+
+        assert_eq!(board.remove_by_id(rook), Some(H1));
+        assert!(board.get_piece_at(&A1).is_some());
+        assert_eq!(board.get_piece_by_id(rook), None);
+        assert_eq!(board.remove_by_id(rook), None);
+    }
+
+    #[test]
+    fn new_pieces_get_new_ids() {
+        let mut board = Board::from("4k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        let pawn = id_at(&board, A7);
+        let next = board.next_piece_id;
+
+        let queen = Piece::Queen(Color::White, Duration::ZERO.into());
+        board.process_move(Move::Promotion(A7, A8, queen));
+        assert_eq!(id_at(&board, A8), next);
+        assert_eq!(board.get_coord_for_id(pawn), None);
+
+        board.set_piece_at(Some(queen), D4);
+        assert_eq!(id_at(&board, D4), next + 1);
+    }
+
+    #[test]
+    fn ids_start_over_without_reusing_ones_on_the_board() {
+        let mut board = Board::standard();
+        board.next_piece_id = PieceId::MAX;
+        let queen = Piece::Queen(Color::White, Duration::ZERO.into());
+
+        board.set_piece_at(Some(queen), D4);
+        board.set_piece_at(Some(queen), D5);
+        assert_eq!(id_at(&board, D4), PieceId::MAX);
+        assert_eq!(id_at(&board, D5), 33);
+        assert_eq!(board.next_piece_id, 34);
+    }
+
+    #[test]
+    fn cards_copy_or_keep_ids() {
+        let mut board = ready_board();
+        let pawn = id_at(&board, E2);
+        board.process_card_play(&CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::White,
+            targets: vec![E2, E3],
+        });
+        assert_eq!(id_at(&board, E2), pawn);
+        assert_ne!(id_at(&board, E3), pawn);
+
+        let knight = id_at(&board, B1);
+        board.process_card_play(&CardPlay {
+            kind: CardKind::Transform,
+            color: Color::White,
+            targets: vec![B1, F1],
+        });
+        assert!(board
+            .get_piece_at(&B1)
+            .is_some_and(|piece| piece.is_bishop()));
+        assert_eq!(id_at(&board, B1), knight);
+    }
+
+    #[test]
+    fn rewound_copies_get_new_ids() {
+        let mut board = ready_board();
+        let pawn = id_at(&board, E2);
+        board.process_move(Move::Piece(E2, E4));
+        board.rewind_square(E2, 1).expect("rewind e2");
+
+        assert_eq!(board.get_coord_for_id(pawn), Some(E4));
+        assert_ne!(id_at(&board, E2), pawn);
+    }
+
+    #[test]
+    fn replays_number_pieces_the_same_way() {
+        let board = ready_board();
+        let events = [
+            GameEvent::Move {
+                mv: Move::Piece(E2, E4),
+                piece: id_at(&board, E2),
+            },
+            GameEvent::Card {
+                kind: CardKind::Duplicate,
+                color: Color::White,
+                targets: vec![CardTarget::Piece(id_at(&board, G1)), CardTarget::Square(H3)],
+            },
+            GameEvent::Rewind {
+                color: Color::White,
+                coord: E2,
+                steps: 1,
+            },
+        ];
+        let mut first = ready_board();
+        let mut second = ready_board();
+        for event in &events {
+            first.process_event(event);
+            second.process_event(event);
+        }
+
+        for coord in [E2, E4, G1, H3] {
+            assert_eq!(id_at(&first, coord), id_at(&second, coord));
+        }
+        assert_eq!(first.next_piece_id, second.next_piece_id);
+    }
+
+    #[test]
+    fn card_events_record_targeted_pieces_by_id() {
+        let mut board = ready_board();
+        let knight = id_at(&board, G1);
+        let play = CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::White,
+            targets: vec![G1, H3],
+        };
+
+        let event = play.event(&board);
+        assert_eq!(
+            event,
+            GameEvent::Card {
+                kind: CardKind::Duplicate,
+                color: Color::White,
+                targets: vec![CardTarget::Piece(knight), CardTarget::Square(H3)],
+            }
+        );
+
+        board.process_move(Move::Piece(G1, F3));
+        board.process_event(&event);
+        assert!(board
+            .get_piece_at(&H3)
+            .is_some_and(|piece| piece.is_knight()));
+        assert_eq!(id_at(&board, F3), knight);
+    }
+
+    #[test]
+    fn card_events_with_a_missing_piece_change_nothing() {
+        let mut board = ready_board();
+        let event = GameEvent::Card {
+            kind: CardKind::Duplicate,
+            color: Color::White,
+            targets: vec![CardTarget::Piece(999), CardTarget::Square(H3)],
+        };
+
+        board.process_event(&event);
+        assert!(board.get_piece_at(&H3).is_none());
+    }
+
+    #[test]
+    fn card_targets_resolve_piece_ids() {
+        let mut board = ready_board();
+        let knight = id_at(&board, G1);
+        board.process_move(Move::Piece(G1, F3));
+
+        assert_eq!(CardTarget::Piece(knight).resolve(&board), Ok(F3));
+        assert_eq!(CardTarget::Square(E4).resolve(&board), Ok(E4));
+        assert_eq!(
+            CardTarget::Piece(999).resolve(&board),
+            Err(CardError::UnknownPiece(999))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod rules {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cooldowns::{COOLDOWN_PAWN, COOLDOWN_PROMOTION},
+        piece::{
+            Color::{Black, White},
+            Move, Piece,
+        },
+        square::*,
+    };
+
+    fn promotion_board() -> Board {
+        Board::from("4k3/P7/8/8/8/8/7p/4K3 w - - 0 1")
+    }
+
+    #[test]
+    fn pawn_can_promote_to_minor_and_major_pieces() {
+        let pawn = Piece::Pawn(White, Duration::ZERO.into());
+        assert!(pawn.can_promote_to(&Piece::Queen(White, Duration::ZERO.into())));
+        assert!(pawn.can_promote_to(&Piece::Rook(White, Duration::ZERO.into())));
+        assert!(pawn.can_promote_to(&Piece::Bishop(White, Duration::ZERO.into())));
+        assert!(pawn.can_promote_to(&Piece::Knight(White, Duration::ZERO.into())));
+    }
+
+    #[test]
+    fn pawn_cannot_promote_to_king_pawn_or_enemy() {
+        let pawn = Piece::Pawn(White, Duration::ZERO.into());
+        assert!(!pawn.can_promote_to(&Piece::King(White, Duration::ZERO.into())));
+        assert!(!pawn.can_promote_to(&Piece::Pawn(White, Duration::ZERO.into())));
+        assert!(!pawn.can_promote_to(&Piece::Queen(Black, Duration::ZERO.into())));
+        assert!(!Piece::Rook(White, Duration::ZERO.into())
+            .can_promote_to(&Piece::Queen(White, Duration::ZERO.into())));
+    }
+
+    #[test]
+    fn promoted_piece_inherits_pawn_cooldown() {
+        let mut board = promotion_board();
+        board.process_move(Move::Promotion(
+            A7,
+            A8,
+            Piece::Queen(White, Duration::from_secs(9).into()),
+        ));
+
+        let queen = board.get_piece_at(&A8).expect("queen on a8");
+        assert_eq!(queen, Piece::Queen(White, Duration::ZERO.into()));
+        assert_eq!(queen.get_cooldown(), COOLDOWN_PROMOTION);
+        assert_eq!(COOLDOWN_PROMOTION, COOLDOWN_PAWN);
+        assert!(board.get_piece_at(&A7).is_none());
+    }
+
+    #[test]
+    fn promotion_cooldown_is_configurable() {
+        let mut board = promotion_board();
+        board.rules.promotion_cooldown = Duration::from_secs(3);
+        board.process_move(Move::Promotion(
+            H2,
+            H1,
+            Piece::Knight(Black, Duration::ZERO.into()),
+        ));
+
+        let knight = board.get_piece_at(&H1).expect("knight on h1");
+        assert_eq!(knight.get_cooldown(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn invalid_promotions_leave_board_untouched() {
+        for piece in [
+            Piece::King(White, Duration::ZERO.into()),
+            Piece::Pawn(White, Duration::ZERO.into()),
+            Piece::Queen(Black, Duration::ZERO.into()),
+        ] {
+            let mut board = promotion_board();
+            board.process_move(Move::Promotion(A7, A8, piece));
+            assert!(board.get_piece_at(&A8).is_none(), "{piece}");
+            assert!(board.get_piece_at(&A7).is_some(), "{piece}");
+        }
+    }
+
+    #[test]
+    fn promotion_from_non_pawn_is_ignored() {
+        let mut board = promotion_board();
+        board.process_move(Move::Promotion(
+            E1,
+            E2,
+            Piece::Queen(White, Duration::ZERO.into()),
+        ));
+        assert_eq!(
+            board.get_piece_at(&E1),
+            &Some(Piece::King(White, Duration::ZERO.into()))
+        );
+        assert!(board.get_piece_at(&E2).is_none());
+    }
+}
+
+#[cfg(test)]
+mod movegen {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cooldowns::COOLDOWN_PROMOTION,
+        movegen::MoveGen,
+        piece::{Color::White, Move, Piece},
+        square::*,
+    };
+
+    #[test]
+    fn only_valid_promotions_are_generated() {
+        let board = Board::from("4k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        let moves = MoveGen::new(&board).for_pawn(&Piece::Pawn(White, Duration::ZERO.into()), A7);
+
+        assert_eq!(moves.len(), 4);
+        for mv in &moves {
+            let Move::Promotion(A7, A8, piece) = mv else {
+                panic!("expected a promotion, got {mv}");
+            };
+            assert!(Piece::Pawn(White, Duration::ZERO.into()).can_promote_to(piece));
+            assert_eq!(piece.get_cooldown(), COOLDOWN_PROMOTION);
+        }
+    }
+
+    #[test]
+    fn king_promotion_is_not_valid() {
+        let mut board = Board::from("4k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+
+        assert!(board.is_valid_move(Move::Promotion(
+            A7,
+            A8,
+            Piece::Queen(White, Duration::ZERO.into())
+        )));
+        assert!(!board.is_valid_move(Move::Promotion(
+            A7,
+            A8,
+            Piece::King(White, Duration::ZERO.into())
+        )));
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod effects {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cooldowns::{COOLDOWN_KNIGHT, COOLDOWN_ROOK},
+        movegen::MoveGen,
+        piece::{Color, Move, Piece},
+        piece_state::{PieceState, StatusEffect, HASTE_COOLDOWN_MULTIPLIER},
+        square::*,
+    };
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    #[test]
+    fn effects_refresh_instead_of_stacking() {
+        let mut state = PieceState::new(Duration::ZERO);
+        state.add_effect(StatusEffect::Haste, Duration::from_secs(1));
+        state.add_effect(StatusEffect::Haste, Duration::from_secs(3));
+        state.add_effect(StatusEffect::Freeze, Duration::from_secs(2));
+
+        assert_eq!(state.effects().count(), 2);
+        state.decay_effects(Duration::from_secs(2));
+        assert!(state.has_effect(StatusEffect::Haste));
+        assert!(!state.has_effect(StatusEffect::Freeze));
+    }
+
+    #[test]
+    fn frozen_pieces_cannot_move() {
+        let mut board = ready_board();
+        assert!(board.add_effect(G1, StatusEffect::Freeze, Duration::from_secs(1)));
+        assert!(!board.add_effect(E4, StatusEffect::Freeze, Duration::from_secs(1)));
+
+        let knight = board.get_piece_at(&G1).expect("knight on g1");
+        assert!(MoveGen::new(&board).for_knight(&knight, G1).is_empty());
+        assert!(!board.is_valid_move(Move::Piece(G1, F3)));
+    }
+
+    #[test]
+    fn frozen_rook_cannot_castle() {
+        let mut board = Board::from("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.add_effect(H1, StatusEffect::Freeze, Duration::from_secs(1));
+
+        assert!(!board.is_valid_move(Move::KingSideCastle(Color::White)));
+    }
+
+    #[test]
+    fn effects_wear_off_on_tick() {
+        let mut board = ready_board();
+        board.add_effect(G1, StatusEffect::Freeze, Duration::from_millis(40));
+        for _ in 0..3 {
+            board.tick();
+        }
+
+        assert!(board.is_valid_move(Move::Piece(G1, F3)));
+    }
+
+    #[test]
+    fn shield_blocks_one_capture() {
+        let mut board = Board::from("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.add_effect(D5, StatusEffect::Shield, Duration::from_secs(10));
+
+        assert!(board.is_valid_move(Move::Piece(E4, D5)));
+        board.process_move(Move::Piece(E4, D5));
+        let pawn = board.get_piece_at(&D5).expect("shielded pawn survives");
+        assert_eq!(pawn.get_color(), Color::Black);
+        assert!(!pawn.has_effect(StatusEffect::Shield));
+        let attacker = board.get_piece_at(&E4).expect("attacker stays put");
+        assert!(!attacker.get_cooldown().is_zero());
+
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.process_move(Move::Piece(E4, D5));
+        assert_eq!(
+            board.get_piece_at(&D5).map(|piece| piece.get_color()),
+            Some(Color::White)
+        );
+    }
+
+    #[test]
+    fn haste_shortens_cooldown() {
+        let mut board = ready_board();
+        board.add_effect(G1, StatusEffect::Haste, Duration::from_secs(10));
+        board.process_move(Move::Piece(G1, F3));
+
+        let knight = board.get_piece_at(&F3).expect("knight on f3");
+        assert_eq!(
+            knight.get_cooldown(),
+            COOLDOWN_KNIGHT.mul_f64(HASTE_COOLDOWN_MULTIPLIER)
+        );
+    }
+
+    #[test]
+    fn castling_keeps_effects() {
+        let mut board = Board::from("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        board.add_effect(H1, StatusEffect::Haste, Duration::from_secs(10));
+        board.process_move(Move::KingSideCastle(Color::White));
+
+        let rook = board.get_piece_at(&F1).expect("rook on f1");
+        assert_eq!(rook, Piece::Rook(Color::White, PieceState::default()));
+        assert!(rook.has_effect(StatusEffect::Haste));
+        assert!(rook.get_cooldown() < COOLDOWN_ROOK);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod hashing {
+    use std::time::Duration;
+
+    use crate::{
+        board::Board,
+        cards::{CardKind, CardPlay},
+        energy::EnergyRules,
+        piece::{Color, Move, Piece},
+        square::*,
+        zobrist::COOLDOWN_BUCKET,
+    };
+
+    fn ready(fen: &str) -> Board {
+        let mut board = Board::from(fen);
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    fn assert_incremental(board: &Board) {
+        assert_eq!(board.zobrist, board.compute_hash());
+    }
+
+    #[test]
+    fn hash_follows_moves_and_cards() {
+        let mut board = ready("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        board.enable_energy(EnergyRules {
+            move_cost: 1,
+            ..EnergyRules::default()
+        });
+        assert_incremental(&board);
+
+        let queen = Piece::Queen(Color::White, Duration::ZERO.into());
+        for mv in [
+            Move::KingSideCastle(Color::White),
+            Move::Promotion(B7, A8, queen),
+            Move::Piece(H8, H1),
+            Move::QueenSideCastle(Color::Black),
+        ] {
+            board.decay_cooldowns(Duration::from_secs(10));
+            board.process_move(mv);
+            assert_incremental(&board);
+        }
+
+        board.process_card_play(&CardPlay {
+            kind: CardKind::Duplicate,
+            color: Color::Black,
+            targets: vec![H1, H2],
+        });
+        assert_incremental(&board);
+        board.rewind_square(H2, 1).expect("rewind h2");
+        assert_incremental(&board);
+        board.remove_by_piece(&queen);
+        assert_incremental(&board);
+    }
+
+    #[test]
+    fn hash_follows_hp_and_energy() {
+        let mut board = ready("4k3/8/8/8/8/8/8/R3K3 w - - 0 1");
+        board.enable_energy(EnergyRules::default());
+        board.spend_energy(Color::White, 4);
+        board.set_piece_at(Some(Piece::Rook(Color::White, Duration::ZERO.into())), E2);
+        for _ in 0..60 {
+            board.tick();
+        }
+
+        assert!(board.black_hp < 1000);
+        assert_incremental(&board);
+    }
+
+    #[test]
+    fn transpositions_hash_the_same() {
+        let mut first = ready("4k3/8/8/8/8/8/8/1N2K1N1 w - - 0 1");
+        let mut second = first.clone();
+        first.process_move(Move::Piece(B1, C3));
+        first.process_move(Move::Piece(G1, F3));
+        second.process_move(Move::Piece(G1, F3));
+        second.process_move(Move::Piece(B1, C3));
+
+        assert_eq!(first.zobrist, second.zobrist);
+        assert_ne!(
+            first.zobrist,
+            Board::from("4k3/8/8/8/8/8/8/1N2K1N1 w - - 0 1").zobrist
+        );
+    }
+
+    #[test]
+    fn castling_rights_change_the_hash() {
+        let with_rights = Board::from("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        let without = Board::from("4k3/8/8/8/8/8/8/4K2R w - - 0 1");
+        assert_ne!(with_rights.zobrist, without.zobrist);
+    }
+
+    #[test]
+    fn cooldown_hash_buckets_cooldowns() {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_millis(10));
+        let mut nearly = board.clone();
+        nearly.decay_cooldowns(Duration::from_millis(80));
+        let mut later = board.clone();
+        later.decay_cooldowns(COOLDOWN_BUCKET);
+
+        assert_eq!(board.zobrist, later.zobrist);
+        assert_eq!(board.cooldown_hash(), nearly.cooldown_hash());
+        assert_ne!(board.cooldown_hash(), later.cooldown_hash());
+    }
+}
@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use crate::{
+    board::Board,
+    coordinate::Coord,
+    piece::{Color, Piece},
+};
+
+/// Cooldowns are rounded down to a multiple of this before they are hashed by
+/// [`Board::cooldown_hash`], so that boards a few milliseconds apart still hash the same.
+pub const COOLDOWN_BUCKET: Duration = Duration::from_millis(100);
+
+/**
+HP is rounded down to a multiple of this before [`Board::sync_hash`] hashes it. It is a few
+seconds of drain from one attacker, far more than the server and a client drift apart over a
+round trip, but little enough that a king losing HP the other side never saw is caught.
+*/
+pub const HP_BUCKET: usize = 250;
+
+const SEED: u64 = 0x5EED_C0DE_AC71_0C4E;
+
+/**
+The random keys combined with XOR to hash a board. They are generated at compile time from a fixed
+seed, so the server and every client hash the same board the same way.
+*/
+struct ZobristKeys {
+    /// Indexed by piece kind and color, then by square from a1 to h8.
+    pieces: [[u64; 64]; 12],
+    /// In the order of [`Board::castling_rights`].
+    castling: [u64; 4],
+    /// White and Black HP.
+    hp: [u64; 2],
+    /// White and Black energy.
+    energy: [u64; 2],
+    cooldown: u64,
+}
+
+const KEYS: ZobristKeys = ZobristKeys::generate();
+
+impl ZobristKeys {
+    const fn generate() -> Self {
+        let mut state = SEED;
+        let mut pieces = [[0; 64]; 12];
+        let mut piece = 0;
+        while piece < 12 {
+            let mut square = 0;
+            while square < 64 {
+                state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                pieces[piece][square] = mix(state);
+                square += 1;
+            }
+            piece += 1;
+        }
+
+        let mut extras = [0; 9];
+        let mut index = 0;
+        while index < extras.len() {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            extras[index] = mix(state);
+            index += 1;
+        }
+
+        Self {
+            pieces,
+            castling: [extras[0], extras[1], extras[2], extras[3]],
+            hp: [extras[4], extras[5]],
+            energy: [extras[6], extras[7]],
+            cooldown: extras[8],
+        }
+    }
+}
+
+/// The splitmix64 finalizer, used both to generate keys and to hash counters under a key.
+const fn mix(value: u64) -> u64 {
+    let mut z = value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Board {
+    /**
+    Hashes piece placement, castling rights and each side's HP and energy. Cooldowns, status
+    effects, piece ids and square history are left out, see [`Board::cooldown_hash`].
+
+    The hash is kept up to date in `self.zobrist` by the board's own methods, so this is only
+    needed after writing to the board's fields directly, see [`Board::rehash`].
+    */
+    #[must_use]
+    pub fn compute_hash(&self) -> u64 {
+        let mut hash = self.extras_hash();
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                if let Some(piece) = self.get_piece_at(&coord) {
+                    hash ^= Board::piece_key(piece, coord);
+                }
+            }
+        }
+        for (allowed, key) in self.castling_rights().into_iter().zip(KEYS.castling) {
+            if allowed {
+                hash ^= key;
+            }
+        }
+        hash
+    }
+
+    /// Recomputes `self.zobrist` after the board's fields were written to directly.
+    pub fn rehash(&mut self) {
+        self.zobrist = self.compute_hash();
+    }
+
+    /**
+    The hash the server and clients compare to detect a desync: piece placement, castling
+    rights and each side's HP rounded down to a [`HP_BUCKET`]. Both sides drain HP on their own
+    clocks, so exact HP would only ever agree by coincidence, while a board that is one bucket
+    off has missed or made up an attack. HP sitting right on a bucket edge can still be read
+    either side of it, which only costs a needless resync. Energy is left out.
+    */
+    #[must_use]
+    pub fn sync_hash(&self) -> u64 {
+        // Keyed apart from the exact HP in `extras_hash`, so that the two never cancel out.
+        let bucket = |key: u64, hp: usize| mix(key ^ KEYS.cooldown ^ (hp / HP_BUCKET) as u64);
+        self.zobrist
+            ^ self.extras_hash()
+            ^ bucket(KEYS.hp[0], self.white_hp)
+            ^ bucket(KEYS.hp[1], self.black_hp)
+    }
+
+    /**
+    The position hash with every piece's cooldown folded in, rounded down to a
+    [`COOLDOWN_BUCKET`]. Two boards with the same cooldown hash are the same position with the
+    same pieces about to become ready, which is what a client and the server should agree on.
+
+    Cooldowns change on every tick, so unlike the position hash this is computed on demand.
+    */
+    #[must_use]
+    pub fn cooldown_hash(&self) -> u64 {
+        let bucket = COOLDOWN_BUCKET.as_millis();
+        let mut hash = self.zobrist;
+        for y in 0..8 {
+            for x in 0..8 {
+                let coord = Coord(x, y);
+                if let Some(piece) = self.get_piece_at(&coord) {
+                    let buckets = u64::try_from(piece.get_cooldown().as_millis() / bucket)
+                        .unwrap_or(u64::MAX);
+                    hash ^= mix(Board::piece_key(piece, coord) ^ KEYS.cooldown ^ buckets);
+                }
+            }
+        }
+        hash
+    }
+
+    /// Updates `self.zobrist` for `previous` being replaced by `piece` on `coord`.
+    pub(crate) fn hash_square(
+        &mut self,
+        coord: Coord,
+        previous: Option<Piece>,
+        piece: Option<Piece>,
+    ) {
+        for piece in [previous, piece].into_iter().flatten() {
+            self.zobrist ^= Board::piece_key(&piece, coord);
+        }
+    }
+
+    /// Updates `self.zobrist` for the castling rights changing from `previous`.
+    pub(crate) fn hash_castling(&mut self, previous: [bool; 4]) {
+        let current = self.castling_rights();
+        for ((before, after), key) in previous.into_iter().zip(current).zip(KEYS.castling) {
+            if before != after {
+                self.zobrist ^= key;
+            }
+        }
+    }
+
+    /// The part of the hash covering each side's HP and energy. XOR it out before changing
+    /// either and back in afterwards.
+    pub(crate) fn extras_hash(&self) -> u64 {
+        let value = |key: u64, value: usize| mix(key ^ value as u64);
+        value(KEYS.hp[0], self.white_hp)
+            ^ value(KEYS.hp[1], self.black_hp)
+            ^ value(KEYS.energy[0], self.white_energy)
+            ^ value(KEYS.energy[1], self.black_energy)
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn piece_key(piece: &Piece, coord: Coord) -> u64 {
+        let kind = match piece {
+            Piece::Pawn(_, _) => 0,
+            Piece::Knight(_, _) => 1,
+            Piece::Bishop(_, _) => 2,
+            Piece::Rook(_, _) => 3,
+            Piece::Queen(_, _) => 4,
+            Piece::King(_, _) => 5,
+        };
+        let color = match piece.get_color() {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+        KEYS.pieces[kind * 2 + color][(coord.1 * 8 + coord.0) as usize]
+    }
+}
@@ -1,17 +1,45 @@
 use std::{
-    sync::{mpsc::Receiver, Arc, Mutex},
+    sync::{mpsc::Receiver, Arc},
     thread::{self, JoinHandle},
 };
 
-use state::{board::Board, piece::Move};
+use chess_client::{ChessClient, ServerEvent};
 
-pub(crate) fn listen(board: &Arc<Mutex<Board>>, move_listener: Receiver<Move>) -> JoinHandle<()> {
-    let board = Arc::clone(board);
+pub(crate) fn listen(
+    client: &Arc<ChessClient>,
+    event_listener: Receiver<ServerEvent>,
+) -> JoinHandle<()> {
+    let client = Arc::clone(client);
     thread::spawn(move || {
-        for mv in move_listener {
-            if let Ok(mut board) = board.lock() {
-                board.process_move(mv);
-                println!("{board}");
+        for event in event_listener {
+            match event {
+                ServerEvent::Move(_) | ServerEvent::Snapshot => println!("{}", client.board()),
+                ServerEvent::CardDrawn(card) => println!("drew {} ({})", card.kind, card.id),
+                ServerEvent::Hand(update) => {
+                    let hand: Vec<String> = update
+                        .hand
+                        .iter()
+                        .map(|card| format!("{} ({})", card.kind, card.id))
+                        .collect();
+                    println!("hand: {}", hand.join(", "));
+                    println!("card cooldown: {}ms", update.cooldown.as_millis());
+                }
+                ServerEvent::CardPlayed(play) => {
+                    println!("{:?} played {}", play.color, play.kind);
+                    println!("{}", client.board());
+                }
+                ServerEvent::Desync(desync) => {
+                    println!("out of sync with the server, resynced");
+                    println!("local:  {}", desync.local_fen);
+                    println!("server: {}", desync.server_fen);
+                }
+                ServerEvent::MoveRejected(rejection) => {
+                    println!("{rejection}");
+                    println!("{}", client.board());
+                }
+                ServerEvent::CardRejected(rejection) => println!("{rejection}"),
+                ServerEvent::Seated(Some(color)) => println!("playing {color:?}"),
+                ServerEvent::Seated(None) => println!("spectating"),
             }
         }
     })
@@ -1,17 +1,17 @@
-use std::{
-    sync::{Arc, Mutex},
-    thread,
-};
+use std::{sync::Arc, thread};
 
-use state::{board::Board, cooldowns::BOARD_TICK_RATE};
+use chess_client::ChessClient;
+use state::cooldowns::BOARD_TICK_RATE;
 
 #[allow(unused)]
-pub(crate) fn game_loop(board: &Arc<Mutex<Board>>) -> std::thread::JoinHandle<()> {
-    let board = Arc::clone(board);
+pub(crate) fn game_loop(client: &Arc<ChessClient>) -> std::thread::JoinHandle<()> {
+    let client = Arc::clone(client);
+    let clock = client.clock();
     thread::spawn(move || loop {
-        if let Ok(mut board) = board.lock() {
-            board.tick();
+        for mv in client.tick() {
+            println!("premove {mv}");
+            println!("{}", client.board());
         }
-        thread::sleep(BOARD_TICK_RATE);
+        clock.sleep(BOARD_TICK_RATE);
     })
 }
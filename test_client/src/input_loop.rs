@@ -1,33 +1,68 @@
 use std::{
     io::stdin,
-    sync::{Arc, Mutex},
+    sync::Arc,
     thread::{self, JoinHandle},
 };
 
 use chess_client::ChessClient;
-use state::board::Board;
+use state::coordinate::Coord;
 
-use crate::parse_input::parse_move;
+use crate::parse_input::{parse_card, parse_move};
 
-pub(crate) fn input_loop(
-    client: Arc<ChessClient>,
-    board: Arc<Mutex<Board>>,
-) -> JoinHandle<anyhow::Result<()>> {
+pub(crate) fn input_loop(client: Arc<ChessClient>) -> JoinHandle<anyhow::Result<()>> {
     thread::spawn(move || -> anyhow::Result<()> {
         loop {
             let mut input = String::new();
             stdin().read_line(&mut input)?;
 
             if input.trim() == "debug" {
-                if let Ok(board) = board.lock() {
-                    let (attacks_on_white, attacks_on_black) = board.king_check_count();
-                    println!("Attacks on White: {attacks_on_white}");
-                    println!("Attacks on Black: {attacks_on_black}");
-                    println!("White HP: {}", board.white_hp);
-                    println!("Black HP: {}", board.black_hp);
+                let board = client.board();
+                let (attacks_on_white, attacks_on_black) = board.king_check_count();
+                println!("Attacks on White: {attacks_on_white}");
+                println!("Attacks on Black: {attacks_on_black}");
+                println!("White HP: {}", board.white_hp);
+                println!("Black HP: {}", board.black_hp);
+                match (client.rtt(), client.server_time()) {
+                    (Some(rtt), Some(server_time)) => {
+                        println!("RTT: {}ms", rtt.as_millis());
+                        println!("Server time: {:.3}s", server_time.as_secs_f64());
+                        println!("Clock samples: {}", client.clock_samples());
+                    }
+                    _ => println!("Latency: waiting for pong"),
                 }
+                print_premoves(&client);
+            } else if input.trim() == "eval" {
+                println!("{}", client.board().evaluate());
+            } else if input.trim() == "premoves" {
+                print_premoves(&client);
+            } else if input.trim() == "clear" {
+                client.clear_premoves();
+                println!("premoves cleared");
+            } else if let Some(mv) = input.trim().strip_prefix("pre ").and_then(parse_move) {
+                client.premove(mv);
+                print_premoves(&client);
+            } else if input.trim() == "cards" {
+                for card in client.hand() {
+                    println!("{}: {}", card.id, card.kind);
+                }
+            } else if input.trim() == "pieces" {
+                let board = client.board();
+                for y in 0..8 {
+                    for x in 0..8 {
+                        let coord = Coord(x, y);
+                        if let Some(piece) = board.get_piece_at(&coord) {
+                            println!("#{}: {piece} on {coord}", piece.get_id());
+                        }
+                    }
+                }
+            } else if let Some((card_id, targets)) = parse_card(&input) {
+                client.play_card(card_id, targets);
             } else if let Some(mv) = parse_move(&input) {
-                client.make_move(mv);
+                if client.make_move(mv) {
+                    println!("{}", client.board());
+                } else {
+                    println!("{mv} is not a legal move right now");
+                }
             } else if !input.trim().is_empty() {
                 println!(
                     "could not parse {} into move, board state unchanged",
@@ -37,3 +72,12 @@ pub(crate) fn input_loop(
         }
     })
 }
+
+fn print_premoves(client: &ChessClient) {
+    let premoves: Vec<String> = client.premoves().iter().map(ToString::to_string).collect();
+    if premoves.is_empty() {
+        println!("Premoves: none");
+    } else {
+        println!("Premoves: {}", premoves.join(", "));
+    }
+}
@@ -2,14 +2,17 @@ mod commands;
 mod game_loop;
 mod input_loop;
 mod parse_input;
+mod ping_loop;
+mod tests;
+mod tui;
 
-use crate::game_loop::game_loop;
 #[allow(unused)]
 use crate::{commands::listen, input_loop::input_loop};
+use crate::{game_loop::game_loop, ping_loop::ping_loop};
 use chess_client::ChessClient;
 use clap::Parser;
 use state::board::Board;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -22,27 +25,37 @@ struct Args {
 
     #[arg(short, long)]
     game_id: String,
+
+    /// Play in a full-screen board instead of typing moves.
+    #[arg(long)]
+    tui: bool,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     let client = Arc::new(ChessClient::new(&args.port, &args.host)?);
-    let board = Arc::new(Mutex::new(Board::standard()));
 
     client.join_game(&args.game_id);
 
+    if args.tui {
+        let _ping_handle = ping_loop(&client);
+        return tui::run(&client, &client.listen());
+    }
+
     println!("Welcome to game {}", args.game_id);
     println!("{}", Board::standard());
 
-    let incoming_commands = listen(&board, client.listen());
-    let tick_handle = game_loop(&board);
-    let input_handle = input_loop(client, board);
+    let incoming_commands = listen(&client, client.listen());
+    let tick_handle = game_loop(&client);
+    let ping_handle = ping_loop(&client);
+    let input_handle = input_loop(client);
 
     let _ = (
         incoming_commands.join(),
         input_handle.join(),
         tick_handle.join(),
+        ping_handle.join(),
     );
 
     Ok(())
@@ -1,4 +1,5 @@
 use state::{
+    cards::{CardId, CardTarget},
     cooldowns::{
         COOLDOWN_BISHOP, COOLDOWN_KING, COOLDOWN_KNIGHT, COOLDOWN_PAWN, COOLDOWN_QUEEN,
         COOLDOWN_ROOK,
@@ -39,6 +40,24 @@ pub(crate) fn parse_move(move_str: &str) -> Option<Move> {
     }
 }
 
+/// Parses `play <card id> <target>...`, where a target is a square such as `e2` or a piece id
+/// such as `#12`.
+pub(crate) fn parse_card(input: &str) -> Option<(CardId, Vec<CardTarget>)> {
+    let mut bits = input.split_whitespace();
+    if bits.next()? != "play" {
+        return None;
+    }
+    let card_id = bits.next()?.parse().ok()?;
+    let targets = bits
+        .map(|bit| match bit.strip_prefix('#') {
+            Some(id) => id.parse().ok().map(CardTarget::Piece),
+            None => str_to_coord(bit).map(CardTarget::Square),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((card_id, targets))
+}
+
 pub(crate) fn str_to_coord(str: &str) -> Option<Coord> {
     let mut chars = str.chars();
     let f = chars.next()?;
@@ -66,12 +85,12 @@ pub(crate) fn str_to_piece(str: &str) -> Option<Piece> {
     };
 
     match str.to_lowercase().trim() {
-        "p" => Some(Piece::Pawn(color, COOLDOWN_PAWN)),
-        "n" => Some(Piece::Knight(color, COOLDOWN_KNIGHT)),
-        "b" => Some(Piece::Bishop(color, COOLDOWN_BISHOP)),
-        "r" => Some(Piece::Rook(color, COOLDOWN_ROOK)),
-        "q" => Some(Piece::Queen(color, COOLDOWN_QUEEN)),
-        "k" => Some(Piece::King(color, COOLDOWN_KING)),
+        "p" => Some(Piece::Pawn(color, COOLDOWN_PAWN.into())),
+        "n" => Some(Piece::Knight(color, COOLDOWN_KNIGHT.into())),
+        "b" => Some(Piece::Bishop(color, COOLDOWN_BISHOP.into())),
+        "r" => Some(Piece::Rook(color, COOLDOWN_ROOK.into())),
+        "q" => Some(Piece::Queen(color, COOLDOWN_QUEEN.into())),
+        "k" => Some(Piece::King(color, COOLDOWN_KING.into())),
         _ => None,
     }
 }
@@ -0,0 +1,13 @@
+use std::{sync::Arc, thread, time::Duration};
+
+use chess_client::ChessClient;
+
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) fn ping_loop(client: &Arc<ChessClient>) -> std::thread::JoinHandle<()> {
+    let client = Arc::clone(client);
+    thread::spawn(move || loop {
+        client.ping();
+        thread::sleep(PING_INTERVAL);
+    })
+}
@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod board_view {
+    use std::time::Duration;
+
+    use ratatui::layout::Rect;
+    use state::{
+        board::Board,
+        cooldowns::COOLDOWN_KNIGHT,
+        piece::{Color, Move, Piece},
+        square::*,
+    };
+
+    use crate::tui::{
+        app::{App, SQUARE_HEIGHT, SQUARE_WIDTH},
+        view::{cooldown_bar, cooldown_fraction},
+    };
+
+    fn ready_board() -> Board {
+        let mut board = Board::standard();
+        board.decay_cooldowns(Duration::from_secs(10));
+        board
+    }
+
+    fn app_at(x: u16, y: u16) -> App {
+        let mut app = App::new();
+        app.board_area = Rect::new(x, y, 8 * SQUARE_WIDTH, 8 * SQUARE_HEIGHT);
+        app
+    }
+
+    #[test]
+    fn clicks_map_to_squares() {
+        let mut app = app_at(3, 1);
+
+        assert_eq!(app.square_at(3, 1), Some(A8));
+        assert_eq!(
+            app.square_at(3 + 8 * SQUARE_WIDTH - 1, 1 + 8 * SQUARE_HEIGHT - 1),
+            Some(H1)
+        );
+        assert_eq!(app.square_at(2, 1), None);
+        assert_eq!(app.square_at(3 + 8 * SQUARE_WIDTH, 1), None);
+
+        app.flipped = true;
+        assert_eq!(app.square_at(3, 1), Some(H1));
+        for square in [A1, E4, H8] {
+            let (column, row) = app.cell_of(square);
+            assert_eq!(app.square_at(column, row), Some(square));
+        }
+    }
+
+    #[test]
+    fn cursor_stops_at_the_edge_and_follows_the_flip() {
+        let mut app = App::new();
+        app.cursor = A1;
+        app.move_cursor(-1, -1);
+        assert_eq!(app.cursor, A1);
+
+        app.move_cursor(0, 1);
+        assert_eq!(app.cursor, A2);
+
+        app.flipped = true;
+        app.move_cursor(0, 1);
+        assert_eq!(app.cursor, A1);
+    }
+
+    #[test]
+    fn selecting_a_piece_shows_its_destinations() {
+        let board = ready_board();
+        let mut app = App::new();
+        app.cursor = G1;
+
+        assert_eq!(app.activate(&board), None);
+        assert_eq!(app.selected, Some(G1));
+
+        let mut destinations = app.destinations(&board);
+        destinations.sort_by_key(ToString::to_string);
+        assert_eq!(destinations, vec![Move::Piece(G1, F3), Move::Piece(G1, H3)]);
+    }
+
+    #[test]
+    fn destinations_include_pieces_on_cooldown() {
+        let board = Board::standard();
+        let mut app = App::new();
+        app.cursor = E2;
+        app.activate(&board);
+
+        assert_eq!(app.destinations(&board).len(), 2);
+    }
+
+    #[test]
+    fn clicking_a_destination_makes_the_move() {
+        let board = ready_board();
+        let mut app = app_at(0, 0);
+
+        let (column, row) = app.cell_of(E2);
+        assert_eq!(app.click(&board, column, row), None);
+        let (column, row) = app.cell_of(E4);
+        assert_eq!(app.click(&board, column, row), Some(Move::Piece(E2, E4)));
+        assert_eq!(app.selected, None);
+    }
+
+    #[test]
+    fn clicking_elsewhere_changes_the_selection() {
+        let board = ready_board();
+        let mut app = app_at(0, 0);
+
+        let (column, row) = app.cell_of(E2);
+        app.click(&board, column, row);
+        let (column, row) = app.cell_of(D2);
+        assert_eq!(app.click(&board, column, row), None);
+        assert_eq!(app.selected, Some(D2));
+
+        let (column, row) = app.cell_of(D5);
+        assert_eq!(app.click(&board, column, row), None);
+        assert_eq!(app.selected, None);
+    }
+
+    #[test]
+    fn promotions_default_to_a_queen() {
+        let mut board = Board::from("4k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+        board.decay_cooldowns(Duration::from_secs(10));
+        let mut app = App::new();
+        app.cursor = A7;
+        app.activate(&board);
+        app.cursor = A8;
+
+        assert_eq!(
+            app.activate(&board),
+            Some(Move::Promotion(
+                A7,
+                A8,
+                Piece::Queen(Color::White, Duration::ZERO.into())
+            ))
+        );
+    }
+
+    #[test]
+    fn castling_is_a_king_destination() {
+        let board = Board::from("r3k2r/8/8/8/8/8/8/R3K2R w qkQK - 0 1");
+        let mut app = App::new();
+        app.cursor = E1;
+        app.activate(&board);
+        app.cursor = G1;
+
+        assert_eq!(
+            app.activate(&board),
+            Some(Move::KingSideCastle(Color::White))
+        );
+    }
+
+    #[test]
+    fn cooldown_bars_fill_in_eighths() {
+        assert_eq!(cooldown_bar(0.0, 5), "     ");
+        assert_eq!(cooldown_bar(1.0, 5), "█████");
+        assert_eq!(cooldown_bar(0.5, 5), "██▌  ");
+        assert_eq!(cooldown_bar(0.01, 5), "▏    ");
+    }
+
+    #[test]
+    fn cooldown_fraction_runs_from_full_to_empty() {
+        let mut board = Board::standard();
+        let knight = board.get_piece_at(&G1).expect("knight on g1");
+        assert!((cooldown_fraction(&board, &knight) - 1.0).abs() < f64::EPSILON);
+
+        board.decay_cooldowns(COOLDOWN_KNIGHT / 2);
+        let knight = board.get_piece_at(&G1).expect("knight on g1");
+        assert!((cooldown_fraction(&board, &knight) - 0.5).abs() < 1e-9);
+
+        board.decay_cooldowns(COOLDOWN_KNIGHT);
+        let knight = board.get_piece_at(&G1).expect("knight on g1");
+        assert!(cooldown_fraction(&board, &knight).abs() < f64::EPSILON);
+    }
+}
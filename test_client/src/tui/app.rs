@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use chess_client::ServerEvent;
+use ratatui::layout::Rect;
+use state::{
+    board::Board,
+    coordinate::Coord,
+    movegen::{MoveGen, MoveGenOptions},
+    piece::{Move, Piece},
+};
+
+/// Terminal columns and rows each square takes up.
+pub(crate) const SQUARE_WIDTH: u16 = 5;
+pub(crate) const SQUARE_HEIGHT: u16 = 2;
+
+/// How many lines the move log keeps.
+const LOG_LENGTH: usize = 100;
+
+/// What the TUI remembers between frames: the cursor, the selected piece and the move log.
+#[derive(Debug)]
+pub(crate) struct App {
+    pub(crate) cursor: Coord,
+    pub(crate) selected: Option<Coord>,
+    /// Draws the board with Black at the bottom.
+    pub(crate) flipped: bool,
+    /// Where the squares were last drawn, for turning mouse clicks into squares.
+    pub(crate) board_area: Rect,
+    log: VecDeque<String>,
+}
+
+impl App {
+    pub(crate) fn new() -> Self {
+        Self {
+            cursor: Coord(4, 1),
+            selected: None,
+            flipped: false,
+            board_area: Rect::default(),
+            log: VecDeque::new(),
+        }
+    }
+
+    /// Moves the cursor by `columns` and `rows` as seen on screen, with up being away from
+    /// the player at the bottom. The cursor stops at the edge of the board.
+    pub(crate) fn move_cursor(&mut self, columns: i8, rows: i8) {
+        let (columns, rows) = if self.flipped {
+            (-columns, -rows)
+        } else {
+            (columns, rows)
+        };
+        self.cursor = Coord(
+            (self.cursor.0 + columns).clamp(0, 7),
+            (self.cursor.1 + rows).clamp(0, 7),
+        );
+    }
+
+    /**
+    Acts on the square under the cursor. With a piece selected and the cursor on one of its
+    destinations, returns the move to make. Otherwise selects the piece under the cursor, or
+    clears the selection if there is none.
+    */
+    pub(crate) fn activate(&mut self, board: &Board) -> Option<Move> {
+        if let Some(mv) = self.move_to(board, self.cursor) {
+            self.selected = None;
+            return Some(mv);
+        }
+        self.selected = board.get_piece_at(&self.cursor).map(|_| self.cursor);
+        None
+    }
+
+    /// Moves the cursor to the clicked square and activates it, see [`App::activate`].
+    pub(crate) fn click(&mut self, board: &Board, column: u16, row: u16) -> Option<Move> {
+        self.cursor = self.square_at(column, row)?;
+        self.activate(board)
+    }
+
+    /// The square drawn at a terminal cell, if it is on the board.
+    pub(crate) fn square_at(&self, column: u16, row: u16) -> Option<Coord> {
+        let area = self.board_area;
+        if column < area.x || row < area.y {
+            return None;
+        }
+        let file = (column - area.x) / SQUARE_WIDTH;
+        let rank_from_top = (row - area.y) / SQUARE_HEIGHT;
+        if file > 7 || rank_from_top > 7 {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let (file, rank_from_top) = (file as i8, rank_from_top as i8);
+        Some(if self.flipped {
+            Coord(7 - file, rank_from_top)
+        } else {
+            Coord(file, 7 - rank_from_top)
+        })
+    }
+
+    /// The terminal cell at the top left of `coord`'s square, the inverse of [`App::square_at`].
+    pub(crate) fn cell_of(&self, coord: Coord) -> (u16, u16) {
+        let (file, rank_from_top) = if self.flipped {
+            (7 - coord.0, coord.1)
+        } else {
+            (coord.0, 7 - coord.1)
+        };
+        #[allow(clippy::cast_sign_loss)]
+        let (file, rank_from_top) = (file as u16, rank_from_top as u16);
+        (
+            self.board_area.x + file * SQUARE_WIDTH,
+            self.board_area.y + rank_from_top * SQUARE_HEIGHT,
+        )
+    }
+
+    /**
+    The moves the selected piece has, whether or not it is ready. Moves for a piece on
+    cooldown are sent as premoves.
+    */
+    pub(crate) fn destinations(&self, board: &Board) -> Vec<Move> {
+        let Some(selected) = self.selected else {
+            return vec![];
+        };
+        let Some(piece) = board.get_piece_at(&selected) else {
+            return vec![];
+        };
+        let options = MoveGenOptions {
+            ignore_cooldowns: true,
+        };
+        MoveGen::with_options(board, options)
+            .get_possible_moves_for_color(piece.get_color())
+            .into_iter()
+            .filter(|mv| origin(mv) == selected)
+            .collect()
+    }
+
+    /// The selected piece's move to `target`, promoting to a queen if there is a choice.
+    fn move_to(&self, board: &Board, target: Coord) -> Option<Move> {
+        let moves = self
+            .destinations(board)
+            .into_iter()
+            .filter(|mv| destination(mv) == target)
+            .collect::<Vec<_>>();
+        moves
+            .iter()
+            .find(|mv| matches!(mv, Move::Promotion(_, _, Piece::Queen(_, _))))
+            .or(moves.first())
+            .copied()
+    }
+
+    pub(crate) fn push_log(&mut self, line: String) {
+        if self.log.len() == LOG_LENGTH {
+            self.log.pop_front();
+        }
+        self.log.push_back(line);
+    }
+
+    /// The log, newest line first.
+    pub(crate) fn log(&self) -> impl Iterator<Item = &String> {
+        self.log.iter().rev()
+    }
+}
+
+/// The square a move starts from, the king's square for castling.
+pub(crate) fn origin(mv: &Move) -> Coord {
+    match mv {
+        Move::Piece(from, _) | Move::Promotion(from, _, _) => *from,
+        Move::KingSideCastle(color) | Move::QueenSideCastle(color) => {
+            Coord(4, Board::castle_rank(*color))
+        }
+    }
+}
+
+/// The square a move ends on, the king's destination for castling.
+pub(crate) fn destination(mv: &Move) -> Coord {
+    match mv {
+        Move::Piece(_, to) | Move::Promotion(_, to, _) => *to,
+        Move::KingSideCastle(color) => Coord(6, Board::castle_rank(*color)),
+        Move::QueenSideCastle(color) => Coord(2, Board::castle_rank(*color)),
+    }
+}
+
+/// A line for the move log describing a server event.
+pub(crate) fn describe(event: &ServerEvent) -> String {
+    match event {
+        ServerEvent::Move(mv) => mv.to_string(),
+        ServerEvent::MoveRejected(rejection) => rejection.to_string(),
+        ServerEvent::Snapshot => "board synced with the server".into(),
+        ServerEvent::CardDrawn(card) => format!("drew {} ({})", card.kind, card.id),
+        ServerEvent::Hand(update) => format!("{} cards in hand", update.hand.len()),
+        ServerEvent::CardPlayed(play) => format!("{:?} played {}", play.color, play.kind),
+        ServerEvent::Desync(_) => "out of sync with the server, resynced".into(),
+        ServerEvent::CardRejected(rejection) => rejection.to_string(),
+        ServerEvent::Seated(Some(color)) => format!("playing {color:?}"),
+        ServerEvent::Seated(None) => "spectating".into(),
+    }
+}
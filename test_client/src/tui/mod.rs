@@ -0,0 +1,102 @@
+pub(crate) mod app;
+pub(crate) mod view;
+
+use std::{io::stdout, sync::mpsc::Receiver};
+
+use chess_client::{ChessClient, ServerEvent};
+use crossterm::{
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyModifiers, MouseButton, MouseEventKind,
+    },
+    execute,
+};
+use ratatui::DefaultTerminal;
+use state::{board::Board, cooldowns::BOARD_TICK_RATE, piece::Move};
+
+use app::App;
+
+/**
+Runs the full-screen client until the player quits. The board is redrawn in place every
+[`BOARD_TICK_RATE`], so this also advances the client and fires premoves in place of the
+game loop.
+*/
+pub(crate) fn run(client: &ChessClient, events: &Receiver<ServerEvent>) -> anyhow::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = execute!(stdout(), EnableMouseCapture)
+        .map_err(anyhow::Error::from)
+        .and_then(|()| run_app(&mut terminal, client, events));
+    let _ = execute!(stdout(), DisableMouseCapture);
+    ratatui::restore();
+    result
+}
+
+fn run_app(
+    terminal: &mut DefaultTerminal,
+    client: &ChessClient,
+    events: &Receiver<ServerEvent>,
+) -> anyhow::Result<()> {
+    let mut app = App::new();
+    loop {
+        for event in events.try_iter() {
+            app.push_log(app::describe(&event));
+        }
+        for mv in client.tick() {
+            app.push_log(format!("premove {mv}"));
+        }
+
+        let board = client.board();
+        terminal.draw(|frame| view::draw(frame, &mut app, &board, client))?;
+
+        if !event::poll(BOARD_TICK_RATE)? {
+            continue;
+        }
+        let chosen = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if quits(&app, key) {
+                    return Ok(());
+                }
+                handle_key(&mut app, &board, key)
+            }
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                app.click(&board, mouse.column, mouse.row)
+            }
+            _ => None,
+        };
+        if let Some(mv) = chosen {
+            send(&mut app, client, mv);
+        }
+    }
+}
+
+/// `q`, ctrl-c, or escape with nothing selected.
+fn quits(app: &App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('q') => true,
+        KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+        KeyCode::Esc => app.selected.is_none(),
+        _ => false,
+    }
+}
+
+fn handle_key(app: &mut App, board: &Board, key: KeyEvent) -> Option<Move> {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => app.move_cursor(0, 1),
+        KeyCode::Down | KeyCode::Char('j') => app.move_cursor(0, -1),
+        KeyCode::Left | KeyCode::Char('h') => app.move_cursor(-1, 0),
+        KeyCode::Right | KeyCode::Char('l') => app.move_cursor(1, 0),
+        KeyCode::Enter | KeyCode::Char(' ') => return app.activate(board),
+        KeyCode::Esc => app.selected = None,
+        KeyCode::Char('f') => app.flipped = !app.flipped,
+        _ => {}
+    }
+    None
+}
+
+/// Makes the move if the piece is ready, otherwise queues it as a premove.
+fn send(app: &mut App, client: &ChessClient, mv: Move) {
+    if !client.make_move(mv) {
+        client.premove(mv);
+        app.push_log(format!("premove {mv} queued"));
+    }
+}
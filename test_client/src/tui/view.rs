@@ -0,0 +1,241 @@
+use chess_client::ChessClient;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Gauge, List, Paragraph},
+    Frame,
+};
+use state::{
+    board::{Board, STARTING_HP},
+    coordinate::Coord,
+    piece::{self, Move, Piece},
+};
+
+use super::app::{destination, App, SQUARE_HEIGHT, SQUARE_WIDTH};
+
+const LIGHT_SQUARE: Color = Color::Rgb(240, 217, 181);
+const DARK_SQUARE: Color = Color::Rgb(181, 136, 99);
+const DESTINATION_LIGHT: Color = Color::Rgb(205, 210, 106);
+const DESTINATION_DARK: Color = Color::Rgb(170, 162, 58);
+const SELECTED_SQUARE: Color = Color::Rgb(246, 246, 105);
+const CURSOR_SQUARE: Color = Color::Rgb(106, 159, 210);
+const COOLDOWN_BAR: Color = Color::Rgb(200, 40, 40);
+
+/// Columns taken by the rank labels left of the board.
+const LABEL_WIDTH: u16 = 2;
+
+/// Left partial blocks from one to seven eighths of a cell, for bars finer than a cell.
+const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+const HELP: &str =
+    "arrows/hjkl move · enter/space/click select and move · esc deselect · f flip · q quit";
+
+pub(crate) fn draw(frame: &mut Frame, app: &mut App, board: &Board, client: &ChessClient) {
+    let [main, help] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let board_width = 8 * SQUARE_WIDTH + 2 * LABEL_WIDTH + 2;
+    let [board_area, side] =
+        Layout::horizontal([Constraint::Length(board_width), Constraint::Min(24)]).areas(main);
+
+    draw_board(frame, app, board, board_area);
+    draw_side(frame, app, board, client, side);
+    frame.render_widget(
+        Paragraph::new(HELP).style(Style::default().fg(Color::DarkGray)),
+        help,
+    );
+}
+
+fn draw_board(frame: &mut Frame, app: &mut App, board: &Board, area: Rect) {
+    let block = Block::bordered().title(" Action Chess ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let needed = (8 * SQUARE_WIDTH + 2 * LABEL_WIDTH, 8 * SQUARE_HEIGHT + 1);
+    if inner.width < needed.0 || inner.height < needed.1 {
+        app.board_area = Rect::default();
+        frame.render_widget(Paragraph::new("terminal too small for the board"), inner);
+        return;
+    }
+    app.board_area = Rect::new(
+        inner.x + LABEL_WIDTH,
+        inner.y,
+        8 * SQUARE_WIDTH,
+        8 * SQUARE_HEIGHT,
+    );
+
+    let destinations = app.destinations(board);
+    let buffer = frame.buffer_mut();
+    for y in 0..8 {
+        for x in 0..8 {
+            let coord = Coord(x, y);
+            let background = square_color(app, &destinations, coord);
+            draw_square(buffer, app, board, coord, background);
+        }
+    }
+    draw_labels(buffer, app);
+}
+
+fn square_color(app: &App, destinations: &[Move], coord: Coord) -> Color {
+    let light = (coord.0 + coord.1) % 2 == 1;
+    if coord == app.cursor {
+        CURSOR_SQUARE
+    } else if app.selected == Some(coord) {
+        SELECTED_SQUARE
+    } else if destinations.iter().any(|mv| destination(mv) == coord) {
+        if light {
+            DESTINATION_LIGHT
+        } else {
+            DESTINATION_DARK
+        }
+    } else if light {
+        LIGHT_SQUARE
+    } else {
+        DARK_SQUARE
+    }
+}
+
+/// A square is a row with the piece and a row with a bar of how much of its cooldown is left.
+fn draw_square(buffer: &mut Buffer, app: &App, board: &Board, coord: Coord, background: Color) {
+    let (column, row) = app.cell_of(coord);
+    let area = Rect::new(column, row, SQUARE_WIDTH, SQUARE_HEIGHT);
+    buffer.set_style(area, Style::default().bg(background));
+
+    let Some(piece) = board.get_piece_at(&coord) else {
+        return;
+    };
+    let style = Style::default()
+        .fg(Color::Black)
+        .bg(background)
+        .add_modifier(Modifier::BOLD);
+    // The black pawn asks for text presentation, which some terminals draw a cell wide.
+    let glyph = piece.to_string().replace('\u{fe0e}', "");
+    buffer.set_string(column + SQUARE_WIDTH / 2, row, glyph, style);
+
+    let fraction = cooldown_fraction(board, piece);
+    if fraction > 0.0 {
+        buffer.set_string(
+            column,
+            row + 1,
+            cooldown_bar(fraction, usize::from(SQUARE_WIDTH)),
+            Style::default().fg(COOLDOWN_BAR).bg(background),
+        );
+    }
+}
+
+/// Ranks down the left and files along the bottom.
+fn draw_labels(buffer: &mut Buffer, app: &App) {
+    let style = Style::default().fg(Color::DarkGray);
+    for i in 0..8 {
+        let (_, row) = app.cell_of(Coord(0, i));
+        buffer.set_string(
+            app.board_area.x - LABEL_WIDTH,
+            row,
+            (i + 1).to_string(),
+            style,
+        );
+
+        let (column, _) = app.cell_of(Coord(i, 0));
+        let file = char::from(b'a' + u8::try_from(i).unwrap_or_default());
+        buffer.set_string(
+            column + SQUARE_WIDTH / 2,
+            app.board_area.bottom(),
+            file.to_string(),
+            style,
+        );
+    }
+}
+
+fn draw_side(frame: &mut Frame, app: &App, board: &Board, client: &ChessClient, area: Rect) {
+    let [black, white, status, log] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(4),
+        Constraint::Min(3),
+    ])
+    .areas(area);
+
+    let (top, bottom) = if app.flipped {
+        (piece::Color::White, piece::Color::Black)
+    } else {
+        (piece::Color::Black, piece::Color::White)
+    };
+    frame.render_widget(hp_gauge(board, top), black);
+    frame.render_widget(hp_gauge(board, bottom), white);
+
+    let mut lines = vec![];
+    if let Some(energy) = board.rules.energy {
+        lines.push(Line::from(format!(
+            "Energy  White {}/{}  Black {}/{}",
+            board.white_energy, energy.max, board.black_energy, energy.max
+        )));
+    }
+    lines.push(Line::from(match client.rtt() {
+        Some(rtt) => format!("RTT {}ms", rtt.as_millis()),
+        None => "RTT waiting for pong".into(),
+    }));
+    let premoves = client
+        .premoves()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if !premoves.is_empty() {
+        lines.push(Line::from(format!("Premoves {}", premoves.join(", "))));
+    }
+    frame.render_widget(Paragraph::new(lines), status);
+
+    let entries = app.log().map(String::as_str).collect::<Vec<_>>();
+    frame.render_widget(
+        List::new(entries).block(Block::bordered().title(" Moves ")),
+        log,
+    );
+}
+
+fn hp_gauge(board: &Board, color: piece::Color) -> Gauge<'static> {
+    let hp = match color {
+        piece::Color::White => board.white_hp,
+        piece::Color::Black => board.black_hp,
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = (hp as f64 / STARTING_HP as f64).clamp(0.0, 1.0);
+    let bar = if ratio > 0.5 {
+        Color::Green
+    } else if ratio > 0.25 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+    Gauge::default()
+        .block(Block::bordered().title(format!(" {color:?} ")))
+        .gauge_style(Style::default().fg(bar))
+        .ratio(ratio)
+        .label(format!("{hp}/{STARTING_HP} HP"))
+}
+
+/// How much of the piece's cooldown is left, from 1 just after moving to 0 when ready.
+/// Frozen pieces count as fully cooling down.
+pub(crate) fn cooldown_fraction(board: &Board, piece: &Piece) -> f64 {
+    let full = board.rules.cooldowns.for_piece(piece);
+    if full.is_zero() {
+        return if piece.can_move() { 0.0 } else { 1.0 };
+    }
+    (piece.ready_in().as_secs_f64() / full.as_secs_f64()).clamp(0.0, 1.0)
+}
+
+/// A bar `width` cells wide filled to `fraction`, in eighths of a cell.
+pub(crate) fn cooldown_bar(fraction: f64, width: usize) -> String {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let eighths = (fraction.clamp(0.0, 1.0) * (width * 8) as f64).ceil() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(EIGHTHS[eighths % 8 - 1]);
+    }
+    let filled = bar.chars().count();
+    bar.push_str(&" ".repeat(width - filled));
+    bar
+}